@@ -8,7 +8,11 @@ pub struct ExprWrapper(pub(crate) Expr);
 impl ExprWrapper {
     pub(crate) fn from_value(value: &syn::Lit) -> darling::Result<Self> {
         match value {
-            syn::Lit::Str(s) => Self::from_string(&s.value()),
+            // Parsed with the span of the literal, so that errors in the expression point at it.
+            syn::Lit::Str(s) => s
+                .parse::<Expr>()
+                .map(ExprWrapper)
+                .map_err(|e| darling::Error::custom(format!("Failed to parse expression: {}", e))),
             _ => Err(darling::Error::custom("Expected string literal")),
         }
     }
@@ -29,3 +33,23 @@ impl FromMeta for ExprWrapper {
         ExprWrapper::from_string(value)
     }
 }
+
+/// Custom wrapper type for parsing a list of expressions from attributes, like
+/// `name("First", "Second")`.
+#[derive(Debug, Clone)]
+pub struct ExprListWrapper(pub(crate) Vec<Expr>);
+
+impl FromMeta for ExprListWrapper {
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                darling::ast::NestedMeta::Lit(lit) => ExprWrapper::from_value(lit).map(|it| it.0),
+                darling::ast::NestedMeta::Meta(meta) => {
+                    Err(darling::Error::custom("Expected string literal").with_span(meta))
+                }
+            })
+            .collect::<darling::Result<Vec<_>>>()
+            .map(ExprListWrapper)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::path::{PathSegment, PathSegments};
+use crate::util::{sanitize_identifier, to_pascal_case};
+use proc_macro_error2::abort;
+use quote::{format_ident, quote};
+use syn::{Item, ItemMod};
+
+/// Expands an `#[external_routes]` module: every nested module declaring `#[url("...")]` gets a
+/// struct in its surrounding module, materializing the URL like the structs of routes do.
+pub fn expand(mut module: ItemMod) -> proc_macro2::TokenStream {
+    if module.content.is_none() {
+        abort!(module.ident, "external_routes macro requires a module with a body");
+    }
+    expand_module(&mut module);
+    quote! { #module }
+}
+
+/// Generates the structs of all modules declaring a `#[url("...")]` nested in the given module,
+/// descending into modules without one.
+fn expand_module(module: &mut ItemMod) {
+    let Some((_, items)) = &mut module.content else {
+        return;
+    };
+    let mut structs = Vec::new();
+    for item in items.iter_mut() {
+        let Item::Mod(child) = item else {
+            continue;
+        };
+        let url_attr = child.attrs.iter().position(|attr| attr.path().is_ident("url"));
+        match url_attr {
+            Some(position) => {
+                let attr = child.attrs.remove(position);
+                let url = attr.parse_args::<syn::LitStr>().unwrap_or_else(|_| {
+                    abort!(attr, "Declare the URL as a string, like #[url(\"https://docs.example.com/:page\")].")
+                });
+                structs.push(generate_external_struct(child, &url));
+            }
+            None => expand_module(child),
+        }
+    }
+    items.extend(structs.into_iter().flatten());
+}
+
+/// Generates the struct of the given module declaring the given URL, like `Page` for a module
+/// `page` declaring "https://docs.example.com/:page".
+fn generate_external_struct(module: &ItemMod, url: &syn::LitStr) -> [Item; 2] {
+    let template = url.value();
+    let Some((scheme, rest)) = template.split_once("://") else {
+        abort!(url, "An external URL must be absolute, like \"https://docs.example.com/:page\".");
+    };
+    let declares_query = rest
+        .split('/')
+        .any(|segment| segment.contains('?') && !(segment.starts_with(':') && segment.ends_with('?')));
+    if rest.contains('#') || declares_query {
+        abort!(url, "An external URL declares no query string or fragment. Append them to the materialized URL instead.");
+    }
+    let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    if host.is_empty() {
+        abort!(url, "An external URL requires a host, like \"https://docs.example.com/:page\".");
+    }
+    let origin = format!("{scheme}://{host}");
+    let segments = PathSegments::parse(path);
+
+    let mut format_str = String::from("{}");
+    let mut format_args = vec![quote!(#origin)];
+    let mut params = Vec::new();
+    for segment in &segments.segments {
+        match segment {
+            PathSegment::Static(s) => {
+                format_str.push('/');
+                format_str.push_str(&s.replace('{', "{{").replace('}', "}}"));
+            }
+            PathSegment::Param(name) | PathSegment::Wildcard(name) => {
+                let ident = format_ident!("{}", sanitize_identifier(name));
+                format_str.push_str("/{}");
+                format_args.push(quote!(#ident));
+                params.push(quote!(#ident: impl ::core::fmt::Display));
+            }
+            PathSegment::OptionalParam(name) => {
+                let ident = format_ident!("{}", sanitize_identifier(name));
+                format_str.push_str("{}");
+                format_args.push(quote! {
+                    #ident.map(|value| format!("/{value}")).unwrap_or_default()
+                });
+                params.push(quote!(#ident: Option<&str>));
+            }
+        }
+    }
+    // A trailing slash of the declared URL is kept, as external servers may distinguish it.
+    if (path.len() > 1 && path.ends_with('/')) || (path == "/" && segments.segments.is_empty()) {
+        format_str.push('/');
+    }
+
+    let vis = &module.vis;
+    let struct_name = format_ident!("{}", to_pascal_case(&module.ident.to_string()));
+    let doc = format!("The external URL `{template}`.");
+    let struct_def = syn::parse_quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis struct #struct_name;
+    };
+    let struct_impl = syn::parse_quote! {
+        impl #struct_name {
+            /// The URL template this struct was declared with.
+            pub const TEMPLATE: &'static str = #template;
+
+            /// The scheme and host of this URL, like "https://docs.example.com".
+            pub fn origin(&self) -> &'static str {
+                #origin
+            }
+
+            /// Materializes this URL from the values of all of its parameters. Optional parameters
+            /// given as `None` omit their segment.
+            pub fn materialize(&self, #(#params),*) -> String {
+                format!(#format_str, #(#format_args),*)
+            }
+        }
+    };
+    [struct_def, struct_impl]
+}
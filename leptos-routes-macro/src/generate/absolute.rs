@@ -0,0 +1,34 @@
+use crate::path::ParamInfo;
+use crate::route_def::RouteDef;
+use quote::quote;
+
+/// Generates the `absolute` function of the given route, if the `url` feature is enabled.
+pub fn generate_absolute_impl(
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    if !cfg!(feature = "url") {
+        return quote! {};
+    }
+    let struct_name = &route_def.name;
+    let depth = route_def.found_in_module_path.without_first().len();
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    let param_idents = params.iter().map(|p| p.ident()).collect::<Vec<_>>();
+    let param_types = params.iter().map(|p| p.signature_type(depth));
+    let deprecated = route_def.deprecated_attr();
+
+    quote! {
+        impl #struct_name {
+            /// Materializes the absolute URL of this route below the path of `base`, e.g. for
+            /// links in emails, webhooks or QR codes.
+            #deprecated
+            pub fn absolute(
+                &self,
+                base: &::leptos_routes::absolute::Url,
+                #(#param_idents: #param_types),*
+            ) -> ::leptos_routes::absolute::Url {
+                ::leptos_routes::absolute::absolute_url(base, &self.materialize(#(#param_idents),*))
+            }
+        }
+    }
+}
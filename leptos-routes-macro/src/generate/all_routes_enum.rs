@@ -1,41 +1,197 @@
-use crate::route_def::{flatten, RouteDef};
-use crate::util::to_pascal_case;
-use quote::{format_ident, quote};
+use crate::path::{collect_static_segments, OptionalParams, PathSegments};
+use crate::route_def::{flatten, sort_by_match_order, RouteDef};
+use crate::RoutesMacroArgs;
+use quote::quote;
 
-pub fn generate_route_enum(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+pub fn generate_route_enum(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let match_path = args.match_path_fn();
     let mut all_routes_variants = Vec::new();
+    let mut from_path_checks = Vec::new();
+    let mut route_params_variants = Vec::new();
+    let mut parse_path_checks = Vec::new();
+    let mut conversions = Vec::new();
+    // Variants are declared in declaration order, matching happens in match order.
     for route_def in flatten(route_defs) {
-        let struct_name = &route_def.name;
-
-        let paths = &route_def.found_in_module_path.without_first();
-
-        let mut variant_name = paths
-            .iter()
-            .next()
-            .cloned()
-            .map(|it| format_ident!("{}", to_pascal_case(&it.to_string())));
-        if variant_name.is_some() {
-            for next in paths.iter().skip(1) {
-                variant_name = Some(format_ident!(
-                    "{}{}",
-                    variant_name.unwrap(),
-                    to_pascal_case(&next.to_string())
-                ));
-            }
-        }
-        let variant_name = variant_name
-            .map(|it| format_ident!("{it}{struct_name}"))
-            .unwrap_or(struct_name.clone());
-        let path = quote! { #(#paths::)*#struct_name };
-
+        let variant_name = route_def.variant_name();
+        let path = route_def.full_module_path_to_struct_def();
+        let params_path = route_def.full_module_path_to_params_struct();
         all_routes_variants.push(quote! {
             #variant_name(#path),
-        })
+        });
+        route_params_variants.push(quote! {
+            #variant_name(#params_path),
+        });
+        conversions.push(quote! {
+            impl From<#path> for Route {
+                fn from(route: #path) -> Self {
+                    Route::#variant_name(route)
+                }
+            }
+
+            impl TryFrom<Route> for #path {
+                /// The route, if it is not this route.
+                type Error = Route;
+
+                fn try_from(route: Route) -> Result<Self, Self::Error> {
+                    match route {
+                        Route::#variant_name(route) => Ok(route),
+                        other => Err(other),
+                    }
+                }
+            }
+        });
+    }
+
+    let static_segments = collect_static_segments(route_defs);
+    let mut ordered = flatten(route_defs).collect::<Vec<_>>();
+    sort_by_match_order(&mut ordered);
+    // Groups render no page of their own, sharing the path of their parent.
+    for route_def in ordered.into_iter().filter(|route_def| !route_def.is_group) {
+        let variant_name = route_def.variant_name();
+        let path = route_def.full_module_path_to_struct_def();
+        let params_path = route_def.full_module_path_to_params_struct();
+        let pattern = PathSegments::parse(&route_def.full_path).generate_runtime_pattern(&static_segments, 0);
+
+        from_path_checks.push(quote! {
+            if #path.matches(path) {
+                return Some(Route::#variant_name(#path));
+            }
+        });
+
+        // Query variants additionally match the query parameters distinguishing them.
+        let query_check = (!route_def.query_variant.is_empty()).then(|| {
+            let check = route_def.query_variant_check(quote!(&normalized));
+            quote!(.filter(|_| #check))
+        });
+        parse_path_checks.push(quote! {
+            if let Some(params) = #match_path(#pattern, &normalized)#query_check {
+                match #params_path::from_params(|name| params.get(name)) {
+                    Ok(params) => {
+                        return Ok((Route::#variant_name(#path), RouteParams::#variant_name(params)));
+                    }
+                    Err(err) => {
+                        error.get_or_insert(RouteError::from_param_error(
+                            Route::#variant_name(#path),
+                            err,
+                        ));
+                    }
+                }
+            }
+        });
     }
+    // Materialized paths keep the trailing slash of omitted optional segments with this policy.
+    let keep_trailing_slash = args.optional_params == OptionalParams::KeepTrailingSlash;
     let all_routes_enum = quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
         pub enum Route {
             #(#all_routes_variants)*
         }
+
+        impl Route {
+            /// Normalizes a messy incoming path, decoding percent-encoded characters, collapsing
+            /// duplicate slashes and removing a trailing slash unless the routes keep it using
+            /// `optional_params = "keep_trailing_slash"`. `from_path` and `parse_path` match
+            /// normalized paths.
+            pub fn normalize(path: &str) -> String {
+                ::leptos_routes::matching::normalize_path(path, #keep_trailing_slash)
+            }
+
+            /// Finds the first route matching the given path. Routes are tried by descending
+            /// priority and specificity, then in declaration order.
+            ///
+            /// The path is normalized first. Any query string or hash fragment of it is ignored.
+            pub fn from_path(path: &str) -> Option<Route> {
+                let path = &Self::normalize(path);
+                #(#from_path_checks)*
+                None
+            }
+
+            /// Finds the first route matching the given path, like `from_path`, together with the
+            /// typed values of its parameters.
+            ///
+            /// Any query string or hash fragment of the path is ignored.
+            pub fn from_path_with_params(path: &str) -> Option<(Route, RouteParams)> {
+                Self::parse_path(path).ok()
+            }
+
+            /// Finds the first route matching the given path, like `from_path_with_params`, but
+            /// reports why no route matched. When routes match the path but reject the value of a
+            /// parameter, the error of the first of them is returned.
+            ///
+            /// The path is normalized first. Any query string or hash fragment of it is ignored.
+            pub fn parse_path(path: &str) -> Result<(Route, RouteParams), RouteError> {
+                let normalized = Self::normalize(path);
+                let mut error = None;
+                #(#parse_path_checks)*
+                Err(error.unwrap_or_else(|| RouteError::NoMatch { path: path.to_owned() }))
+            }
+
+            /// Finds the first route matching the path of the given location, like `from_path`,
+            /// together with the typed values of its parameters. Tracks the location's path and
+            /// query string, matching query variants, when called in a reactive context.
+            pub fn try_from_location(
+                location: &::leptos_router::location::Location,
+            ) -> Option<(Route, RouteParams)> {
+                use ::leptos::prelude::With;
+                location.pathname.with(|path| {
+                    location.search.with(|search| match search.is_empty() {
+                        true => Self::from_path_with_params(path),
+                        false => Self::from_path_with_params(&format!("{path}?{search}")),
+                    })
+                })
+            }
+        }
+
+        #(#conversions)*
+
+        /// An error finding the route of a path, returned by `Route::parse_path`.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum RouteError {
+            /// No route matches the path.
+            NoMatch { path: String },
+            /// A route matches the path, but a required parameter has no value.
+            MissingParam { route: Route, name: &'static str },
+            /// A route matches the path, but the value of a parameter does not satisfy its
+            /// declared constraint or could not be parsed into its declared type.
+            InvalidParam { route: Route, name: &'static str, value: String },
+        }
+
+        impl RouteError {
+            fn from_param_error(route: Route, err: ParamError) -> Self {
+                match err {
+                    ParamError::Missing { name } => RouteError::MissingParam { route, name },
+                    ParamError::Invalid { name, value } => {
+                        RouteError::InvalidParam { route, name, value }
+                    }
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for RouteError {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    RouteError::NoMatch { path } => write!(f, "no route matches \"{path}\""),
+                    RouteError::MissingParam { route, name } => {
+                        write!(f, "missing parameter \"{name}\" of route {route:?}")
+                    }
+                    RouteError::InvalidParam { route, name, value } => write!(
+                        f,
+                        "invalid value \"{value}\" for parameter \"{name}\" of route {route:?}"
+                    ),
+                }
+            }
+        }
+
+        impl ::core::error::Error for RouteError {}
+
+        /// The typed parameters of a route, holding its params struct.
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum RouteParams {
+            #(#route_params_variants)*
+        }
     };
     all_routes_enum
 }
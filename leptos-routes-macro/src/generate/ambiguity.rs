@@ -0,0 +1,63 @@
+use crate::generate::warnings::generate_warnings;
+use crate::path::{PathSegment, PathSegments};
+use crate::route_def::{routing_roots, RouteDef};
+use syn::ItemMod;
+
+/// Generates a warning for every route whose path is shadowed by a sibling route declared after it,
+/// as a static segment of the sibling is matched before a parameter at the same position, like
+/// "/new" being matched before "/:id". Routes declaring a `priority` state their order explicitly
+/// and are not warned about.
+pub fn generate_ambiguity_warnings(
+    root_mod: &ItemMod,
+    route_defs: &[RouteDef],
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let mut warnings = Vec::new();
+    collect_warnings(&routing_roots(route_defs), &mut warnings);
+    let warnings = warnings
+        .into_iter()
+        .map(|(route_def, shadowing)| {
+            let note = format!(
+                "The route \"{}\" does not match the paths of its sibling route \"{}\" declared after it, as static segments are matched before parameters. Declare \"{}\" first or declare a `priority` on either route.",
+                route_def.full_path, shadowing.full_path, shadowing.full_path
+            );
+            (route_def.route_ident_span, note)
+        })
+        .collect();
+    generate_warnings(root_mod, "AMBIGUOUS_ROUTE", warnings)
+}
+
+/// Collects every route among the given siblings, and recursively among their children, that is
+/// shadowed by a sibling declared after it, paired with that sibling.
+fn collect_warnings<'a>(siblings: &[&'a RouteDef], warnings: &mut Vec<(&'a RouteDef, &'a RouteDef)>) {
+    for (i, route_def) in siblings.iter().enumerate() {
+        let shadowing = siblings[i + 1..].iter().find(|sibling| {
+            route_def.priority == 0
+                && sibling.priority == 0
+                && shadows(&sibling.path_segments, &route_def.path_segments)
+        });
+        if let Some(shadowing) = shadowing {
+            warnings.push((route_def, shadowing));
+        }
+        collect_warnings(&route_def.nested_children(), warnings);
+    }
+}
+
+/// Whether the given `shadowing` path matches some paths of the `shadowed` path first: both consist
+/// of the same number of static segments and parameters, agree on all static segments present in
+/// both, and `shadowing` has a static segment where `shadowed` has a parameter, but never the other
+/// way around.
+fn shadows(shadowing: &PathSegments, shadowed: &PathSegments) -> bool {
+    if shadowing.segments.len() != shadowed.segments.len() {
+        return false;
+    }
+    let mut shadows = false;
+    for pair in shadowing.segments.iter().zip(&shadowed.segments) {
+        match pair {
+            (PathSegment::Static(a), PathSegment::Static(b)) if a == b => {}
+            (PathSegment::Param(_), PathSegment::Param(_)) => {}
+            (PathSegment::Static(_), PathSegment::Param(_)) => shadows = true,
+            _ => return false,
+        }
+    }
+    shadows
+}
@@ -0,0 +1,54 @@
+use crate::path::{ParamInfo, ParamType};
+use crate::route_def::RouteDef;
+use quote::{format_ident, quote};
+
+/// Generates the named arguments struct of a route, like `DetailsArgs`, holding the values to
+/// materialize the route with, and the `materialize_args` function taking it. The `route_path!`
+/// macro expands to a call of `materialize_args`, letting the compiler check the argument names.
+pub fn generate_args_struct(
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let struct_name = &route_def.name;
+    let args_struct_name = format_ident!("{}Args", struct_name);
+    let vis = &route_def.vis;
+    let depth = route_def.found_in_module_path.without_first().len();
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+
+    // The lifetime of borrowed `&str` values, only declared if any parameter is a plain string.
+    let lifetime = params
+        .iter()
+        .any(|p| matches!(p.ty, ParamType::Str))
+        .then(|| quote!(<'a>));
+    let fields = params.iter().map(|p| {
+        let name = p.ident();
+        let ty = match &p.ty {
+            ParamType::Str => quote!(&'a str),
+            ty => ty.to_tokens(depth),
+        };
+        let ty = if p.is_optional { quote!(Option<#ty>) } else { ty };
+        quote! { pub #name: #ty }
+    });
+    let param_idents = params.iter().map(|p| p.ident());
+    let doc = format!(
+        "The named arguments materializing the `{}` route, as passed to `route_path!`.",
+        route_def.full_path
+    );
+    let deprecated = route_def.deprecated_attr();
+
+    quote! {
+        #[doc = #doc]
+        #vis struct #args_struct_name #lifetime {
+            #(#fields,)*
+        }
+
+        impl #struct_name {
+            /// Materializes the path of this route from named arguments.
+            #deprecated
+            #[track_caller]
+            pub fn materialize_args(&self, args: #args_struct_name) -> String {
+                self.materialize(#(args.#param_idents),*)
+            }
+        }
+    }
+}
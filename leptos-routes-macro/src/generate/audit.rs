@@ -0,0 +1,25 @@
+use crate::generate::warnings::generate_warnings;
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use syn::ItemMod;
+
+/// Generates a warning for every leaf route declaring neither `roles` nor `public`, on the route
+/// itself or on any of its parents, when using `audit_access`.
+pub fn generate_access_audit(
+    args: &RoutesMacroArgs,
+    root_mod: &ItemMod,
+    route_defs: &[RouteDef],
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let warnings = flatten(route_defs)
+        .filter(|route_def| args.audit_access && route_def.children.is_empty())
+        .filter(|route_def| route_def.roles.is_empty() && !route_def.public)
+        .map(|route_def| {
+            let note = format!(
+                "The route \"{}\" declares no access decision. Declare the roles permitted to access it using `roles(\"...\")`, or declare it `public`.",
+                route_def.full_path
+            );
+            (route_def.route_ident_span, note)
+        })
+        .collect();
+    generate_warnings(root_mod, "UNAUDITED_ROUTE", warnings)
+}
@@ -0,0 +1,51 @@
+use crate::path::ParamInfo;
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use quote::quote;
+
+/// Generates the `back_or(fallback)` function, if the `back` feature is enabled and using
+/// `with_views`, whose generated routes track the previous location.
+pub fn generate_back_or(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    if !cfg!(feature = "back") || !args.with_views {
+        return quote! {};
+    }
+    let mut has_required_params = false;
+    let arms = flatten(route_defs)
+        .filter_map(|route_def| {
+            let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+            if params.iter().any(|p| !p.is_optional) {
+                has_required_params = true;
+                return None;
+            }
+            let variant_name = route_def.variant_name();
+            let struct_path = route_def.full_module_path_to_struct_def();
+            let nones = params.iter().map(|_| quote!(None));
+            Some(quote! { Route::#variant_name(_) => self::#struct_path.materialize(#(#nones),*), })
+        })
+        .collect::<Vec<_>>();
+    let other_arm = has_required_params.then(|| {
+        quote! {
+            route => panic!(
+                "The fallback of `back_or` is navigated to without parameter values, but \"{}\" has required parameters.",
+                route.full_path()
+            ),
+        }
+    });
+    quote! {
+        /// Goes back to the previous page if it belongs to this app, being matched by
+        /// `Route::from_path`, or navigates to the given fallback route otherwise, like when the
+        /// page was opened from a bookmark or another site. The fallback is navigated to without
+        /// parameter values and must not have required parameters. Must be called inside a
+        /// `<Router>` rendering the `generated_routes()`.
+        pub fn back_or(fallback: Route) {
+            let fallback = match fallback {
+                #(#arms)*
+                #other_arm
+            };
+            ::leptos_routes::back::back_or(|path| Route::from_path(path).is_some(), &fallback);
+        }
+    }
+}
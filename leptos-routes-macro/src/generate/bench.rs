@@ -0,0 +1,34 @@
+use crate::generate::route_index::sample_path;
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates the `BENCH_URLS` and the `bench_match_path` function, if the `bench` feature is
+/// enabled. Each route is represented by its path with the sample values of its parameters.
+/// Routes with required parameters lacking a sample value are left out.
+pub fn generate_bench(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    if !cfg!(feature = "bench") {
+        return quote! {};
+    }
+    let mut urls = Vec::new();
+    for url in flatten(route_defs).filter_map(sample_path) {
+        if !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+    quote! {
+        /// A URL of every route, with the sample values declared using `sample(...)` for its
+        /// parameters, as matched by `bench_match_path`.
+        pub const BENCH_URLS: &[&str] = &[#(#urls),*];
+
+        /// Benchmarks `Route::from_path` across the `BENCH_URLS`, for calling in a `criterion`
+        /// benchmark.
+        ///
+        /// ```ignore
+        /// criterion_group!(benches, routes::bench_match_path);
+        /// criterion_main!(benches);
+        /// ```
+        pub fn bench_match_path(c: &mut ::leptos_routes::bench::criterion::Criterion) {
+            ::leptos_routes::bench::bench_match_path(c, BENCH_URLS, Route::from_path);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+pub fn generate_cache_policy(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let arms = flatten(route_defs).map(|route_def| {
+        let variant_name = route_def.variant_name();
+        let policy = match route_def.cache {
+            Some(cache) => {
+                let max_age = option_tokens(cache.max_age);
+                let swr = option_tokens(cache.swr);
+                quote! {
+                    ::leptos_routes::cache::CachePolicy {
+                        max_age: #max_age,
+                        stale_while_revalidate: #swr,
+                    }
+                }
+            }
+            None => quote! { ::leptos_routes::cache::CachePolicy::NONE },
+        };
+        quote! {
+            Route::#variant_name(_) => #policy,
+        }
+    });
+
+    quote! {
+        /// The caching policy declared (or inherited from a parent route declaring it) using
+        /// `cache(...)` for the given route. Use its `cache_control()` to set the `Cache-Control`
+        /// header of responses rendering the route.
+        pub fn cache_policy(route: &Route) -> ::leptos_routes::cache::CachePolicy {
+            match *route {
+                #(#arms)*
+            }
+        }
+    }
+}
+
+fn option_tokens(value: Option<u32>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
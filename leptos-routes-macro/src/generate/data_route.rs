@@ -0,0 +1,48 @@
+use crate::path::{collect_patterns, pattern_ref};
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use proc_macro_error2::abort_call_site;
+use quote::quote;
+
+/// Generates the `DATA_ROUTE` constant of the given route, if requested with `data_route`.
+pub fn generate_data_route_const(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+) -> proc_macro2::TokenStream {
+    if !args.data_route {
+        return quote! {};
+    }
+    let struct_name = &route_def.name;
+    let id = route_def.data_route_id();
+    quote! {
+        impl #struct_name {
+            /// The value of the `data-route` attribute set on the views of this route.
+            pub const DATA_ROUTE: &'static str = #id;
+        }
+    }
+}
+
+/// Generates the `DATA_ROUTES` map of all `data-route` identifiers, if requested with `data_route`.
+pub fn generate_data_routes(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    if !args.data_route {
+        return quote! {};
+    }
+    if !args.with_views {
+        abort_call_site!("\"data_route\" sets attributes on the views of the generated routes and requires \"with_views\". Add \"with_views\" or remove the argument.");
+    }
+    let patterns = collect_patterns(route_defs);
+    let entries = flatten(route_defs).map(|route_def| {
+        let id = route_def.data_route_id();
+        let full_path = pattern_ref(&patterns, &route_def.full_path, 0);
+        quote! { (#id, #full_path) }
+    });
+    quote! {
+        /// The `data-route` identifier of every route, like "root.users.user.details", together
+        /// with the full path of the route. Use them to locate the views of routes in end-to-end
+        /// tests, e.g. using the selector `[data-route="root.users.user.details"]`.
+        pub const DATA_ROUTES: &[(&str, &str)] = &[#(#entries),*];
+    }
+}
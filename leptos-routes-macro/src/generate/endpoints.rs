@@ -0,0 +1,39 @@
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use proc_macro_error2::abort_call_site;
+use quote::quote;
+
+/// Generates `resource_endpoints()`, pairing every route declaring `crud`, and its generated child
+/// routes, with the template of the API endpoint serving its resource under the `api_prefix`.
+pub fn generate_resource_endpoints(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let Some(prefix) = &args.api_prefix else {
+        return quote! {};
+    };
+    if !prefix.starts_with('/') || (prefix.len() > 1 && prefix.ends_with('/')) {
+        abort_call_site!("An \"api_prefix\" is a path starting with a '/' and not ending with one, like \"/api\".");
+    }
+    let entries = flatten(route_defs)
+        .filter_map(|route_def| {
+            let endpoint = route_def.endpoint.as_ref()?;
+            let template = format!("{}{}", prefix.trim_end_matches('/'), endpoint);
+            let variant_name = route_def.variant_name();
+            let struct_path = route_def.full_module_path_to_struct_def();
+            Some(quote! { (Route::#variant_name(self::#struct_path), #template) })
+        })
+        .collect::<Vec<_>>();
+    if entries.is_empty() {
+        abort_call_site!("\"api_prefix\" relates the routes declaring \"crud\" to their API endpoints, but no route declares \"crud\". Declare \"crud\" on a route or remove the argument.");
+    }
+    quote! {
+        /// All routes declaring `crud` and their generated child routes, paired with the template
+        /// of the API endpoint serving their resource, in declaration order. The index and `New`
+        /// routes relate to the collection endpoint, like "/api/users", while the `Show` and
+        /// `Edit` routes relate to the endpoint of a single resource, like "/api/users/:id".
+        pub fn resource_endpoints() -> Vec<(Route, &'static str)> {
+            vec![#(#entries),*]
+        }
+    }
+}
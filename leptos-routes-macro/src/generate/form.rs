@@ -0,0 +1,48 @@
+use crate::path::ParamInfo;
+use crate::route_def::RouteDef;
+use crate::RoutesMacroArgs;
+use quote::quote;
+
+/// Generates the `form_action` function of the given route and, with `method_override` set, its
+/// `form_action_with_method` function.
+pub fn generate_form_action_impl(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let struct_name = &route_def.name;
+    let depth = route_def.found_in_module_path.without_first().len();
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    let param_idents = params.iter().map(|p| p.ident()).collect::<Vec<_>>();
+    let param_types = params
+        .iter()
+        .map(|p| p.signature_type(depth))
+        .collect::<Vec<_>>();
+    let deprecated = route_def.deprecated_attr();
+
+    let with_method = args.method_override.as_ref().map(|param| {
+        let doc = format!(
+            "The action of a form submitted to this route, passing the method to use in the \
+             \"{param}\" query parameter, like \"?{param}=PUT\"."
+        );
+        quote! {
+            #[doc = #doc]
+            #deprecated
+            pub fn form_action_with_method(&self, #(#param_idents: #param_types,)* method: &str) -> String {
+                ::leptos_routes::form::with_method_override(self.form_action(#(#param_idents),*), #param, method)
+            }
+        }
+    });
+
+    quote! {
+        impl #struct_name {
+            /// The action of a form submitted to this route, for use in `<form action=...>`.
+            #deprecated
+            pub fn form_action(&self, #(#param_idents: #param_types),*) -> String {
+                self.materialize(#(#param_idents),*)
+            }
+
+            #with_method
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use quote::quote;
+
+/// Generates `intended_destination()` and `resume_intended_navigation()`, reading the path a
+/// `guard` redirected away from, when using `with_views` and any route declares a `guard`.
+pub fn generate_intended_navigation(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    if !args.with_views || !flatten(route_defs).any(|route_def| route_def.guard.is_some()) {
+        return quote! {};
+    }
+    quote! {
+        /// The path and query string a `guard` redirected away from, passed in the `next` query
+        /// parameter of the current URL. Only paths of this app matched by a route are returned,
+        /// so that the parameter cannot redirect to other sites. Must be called inside a
+        /// `<Router>`.
+        pub fn intended_destination() -> Option<String> {
+            use ::leptos::prelude::WithUntracked;
+            let next = ::leptos_router::hooks::use_query_map()
+                .with_untracked(|query| query.get("next"))?;
+            let local = next.starts_with('/') && !next.starts_with("//") && !next.starts_with("/\\");
+            (local && Route::from_path(&next).is_some()).then_some(next)
+        }
+
+        /// Navigates to the `intended_destination()`, or to the given path if there is none, like
+        /// after signing in on the page a `guard` redirected to. Must be called inside a
+        /// `<Router>`.
+        pub fn resume_intended_navigation(default: &str) {
+            let destination = intended_destination().unwrap_or_else(|| default.to_string());
+            ::leptos_router::hooks::use_navigate()(&destination, Default::default());
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::path::ParamInfo;
+use crate::route_def::RouteDef;
+use crate::util::sanitize_identifier;
+use crate::RoutesMacroArgs;
+use proc_macro_error2::abort_call_site;
+use quote::{format_ident, quote};
+
+/// The identifier of the tenant parameter and the host serving the tenants, if the routes are
+/// host-aware.
+fn host_args(args: &RoutesMacroArgs) -> Option<(syn::Ident, &str)> {
+    match (&args.host_param, &args.host) {
+        (Some(host_param), Some(host)) => {
+            Some((format_ident!("{}", sanitize_identifier(host_param)), host.as_str()))
+        }
+        (Some(_), None) => abort_call_site!("\"host_param\" selects a subdomain of a \"host\". Add a \"host\" like host = \"example.com\"."),
+        (None, Some(_)) => abort_call_site!("\"host\" is only used together with \"host_param\". Add a \"host_param\" or remove the argument."),
+        (None, None) => None,
+    }
+}
+
+/// Generates the `materialize_absolute` function of the given route, if the routes are host-aware.
+pub fn generate_materialize_absolute_impl(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let Some((tenant, host)) = host_args(args) else {
+        return quote! {};
+    };
+    let struct_name = &route_def.name;
+    let depth = route_def.found_in_module_path.without_first().len();
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    let param_idents = params.iter().map(|p| p.ident()).collect::<Vec<_>>();
+    let param_types = params.iter().map(|p| p.signature_type(depth));
+    let deprecated = route_def.deprecated_attr();
+    let doc = format!(
+        "Materializes the absolute URL of this route on the subdomain of the given tenant, like \
+         \"https://{{{tenant}}}.{host}{}\".",
+        route_def.full_path
+    );
+
+    quote! {
+        impl #struct_name {
+            #[doc = #doc]
+            #deprecated
+            pub fn materialize_absolute(&self, #tenant: &str, #(#param_idents: #param_types),*) -> String {
+                ::leptos_routes::host::tenant_url(#tenant, #host, &self.materialize(#(#param_idents),*))
+            }
+        }
+    }
+}
+
+/// Generates `Route::from_url`, if the routes are host-aware.
+pub fn generate_from_url(args: &RoutesMacroArgs) -> proc_macro2::TokenStream {
+    let Some((_, host)) = host_args(args) else {
+        return quote! {};
+    };
+    let doc = format!(
+        "Finds the first route matching the path of the given absolute URL on a subdomain of \
+         \"{host}\", like `from_path`, together with the tenant selected by the subdomain."
+    );
+
+    quote! {
+        impl Route {
+            #[doc = #doc]
+            pub fn from_url(url: &str) -> Option<(String, Route)> {
+                let (tenant, path) = ::leptos_routes::host::split_tenant_url(url, #host)?;
+                Self::from_path(path).map(|route| (tenant.to_owned(), route))
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::route_def::{find_parent_of, flatten, RouteDef};
+use quote::quote;
+
+/// Generates `Route::layout_chain()`, listing the routes whose layouts wrap the view of a route,
+/// and `common_layout_depth(a, b)`, counting the layouts two routes share.
+pub fn generate_layout_chains(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let arms = flatten(route_defs).map(|route_def| {
+        let variant_name = route_def.variant_name();
+        let chain = layout_chain(route_defs, route_def).into_iter().map(|layout_def| {
+            let layout_variant = layout_def.variant_name();
+            let struct_path = layout_def.full_module_path_to_struct_def();
+            quote! { Route::#layout_variant(self::#struct_path) }
+        });
+        quote! { Route::#variant_name(_) => &[#(#chain),*], }
+    });
+
+    quote! {
+        impl Route {
+            /// This route and its parent routes declaring a `layout` or `layout_stack`, wrapping
+            /// its view, outermost first.
+            pub fn layout_chain(&self) -> &'static [Route] {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        /// The number of layouts shared by the views of both routes, being the length of the
+        /// common start of their `layout_chain`s. Navigating between both routes keeps this many
+        /// layouts rendered, so transition animations or preserved state may be keyed on it.
+        pub fn common_layout_depth(a: &Route, b: &Route) -> usize {
+            a.layout_chain()
+                .iter()
+                .zip(b.layout_chain())
+                .take_while(|(a, b)| a == b)
+                .count()
+        }
+    }
+}
+
+/// The given route and its parents declaring a layout, outermost first. Absolute routes are
+/// rendered outside of the layouts of their parents.
+fn layout_chain<'a>(route_defs: &'a [RouteDef], route_def: &'a RouteDef) -> Vec<&'a RouteDef> {
+    let mut chain = Vec::new();
+    let mut current = Some(route_def);
+    while let Some(route_def) = current {
+        if route_def.layout.is_some() || route_def.layout_stack.is_some() {
+            chain.push(route_def);
+        }
+        current = match route_def.is_absolute {
+            true => None,
+            false => find_parent_of(route_defs, route_def),
+        };
+    }
+    chain.reverse();
+    chain
+}
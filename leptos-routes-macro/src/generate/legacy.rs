@@ -0,0 +1,22 @@
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates `legacy_redirects()`, pairing every former path declared using `previously` with the
+/// route now serving it.
+pub fn generate_legacy_redirects(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let entries = flatten(route_defs).flat_map(|route_def| {
+        let variant_name = route_def.variant_name();
+        let struct_path = route_def.full_module_path_to_struct_def();
+        route_def.previously.iter().map(move |previously| {
+            quote! { (String::from(#previously), Route::#variant_name(self::#struct_path)) }
+        })
+    });
+    quote! {
+        /// The former paths of all routes declaring `previously = "..."`, paired with the route now
+        /// serving them, in declaration order. Servers mount these as permanent (301) redirects
+        /// to keep old links working after renaming routes.
+        pub fn legacy_redirects() -> Vec<(String, Route)> {
+            vec![#(#entries),*]
+        }
+    }
+}
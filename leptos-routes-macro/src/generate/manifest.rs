@@ -0,0 +1,20 @@
+use quote::quote;
+
+/// Generates `check_manifest_scope`, checking the `start_url` and `scope` of a web app manifest
+/// against the routes.
+pub fn generate_manifest_check() -> proc_macro2::TokenStream {
+    quote! {
+        /// Checks that the given `start_url` and `scope` of the web app manifest fall inside the
+        /// routes: both must match a route, and the `start_url` must be within the `scope`.
+        /// Returns the route the installed app opens on. Assert it at startup of PWA builds, as
+        /// mis-scoped manifests are hard to notice.
+        ///
+        /// Both URLs must be absolute or paths, as relative URLs depend on the manifest's URL.
+        pub fn check_manifest_scope(
+            start_url: &str,
+            scope: &str,
+        ) -> Result<Route, ::leptos_routes::manifest::ManifestScopeError> {
+            ::leptos_routes::manifest::check_manifest_scope(start_url, scope, Route::from_path)
+        }
+    }
+}
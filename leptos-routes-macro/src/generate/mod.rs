@@ -1,16 +1,137 @@
+use crate::generate::absolute::generate_absolute_impl;
+use crate::generate::ambiguity::generate_ambiguity_warnings;
 use crate::generate::all_routes_enum::generate_route_enum;
+use crate::generate::audit::generate_access_audit;
+use crate::generate::back::generate_back_or;
+use crate::generate::args_struct::generate_args_struct;
+use crate::generate::bench::generate_bench;
+use crate::generate::cache::generate_cache_policy;
+use crate::generate::data_route::{generate_data_route_const, generate_data_routes};
+use crate::generate::form::generate_form_action_impl;
+use crate::generate::guards::generate_intended_navigation;
+use crate::generate::host::{generate_from_url, generate_materialize_absolute_impl};
+use crate::generate::layouts::generate_layout_chains;
+use crate::generate::legacy::generate_legacy_redirects;
+use crate::generate::endpoints::generate_resource_endpoints;
+use crate::generate::manifest::generate_manifest_check;
+use crate::generate::namespace::generate_namespace_aliases;
+use crate::generate::nav::generate_nav_items;
+use crate::generate::no_std::generate_alloc_prelude_import;
+use crate::generate::owners::generate_owners;
+use crate::generate::pagination::{generate_pagination_impl, generate_pagination_type};
+use crate::generate::palette::generate_palette;
+use crate::generate::params_struct::{generate_param_error, generate_params_struct};
+use crate::generate::recorder::generate_route_recorder;
+use crate::generate::relative::generate_relative_impls;
+use crate::generate::renders::generate_render_check;
+use crate::generate::resolve::generate_resolve;
+use crate::generate::robots::generate_noindex_patterns;
+use crate::generate::precache::generate_precache_urls;
+use crate::generate::prefetch::generate_prefetch;
+use crate::generate::route_index::generate_route_index_page;
+use crate::generate::route_list::generate_route_list;
+use crate::generate::routes_json::generate_routes_json;
+use crate::generate::route_set::generate_route_set;
 use crate::generate::route_struct::generate_route_struct;
-use crate::generate::router::maybe_generate_routes_component;
+use crate::generate::route_table::{generate_route_table_const, generate_route_table_doc};
+use crate::generate::router::{generate_subtree_routes_component, maybe_generate_routes_component};
+use crate::generate::sections::generate_route_classification;
+use crate::generate::security::generate_security_headers;
+use crate::generate::shortcuts::{generate_shortcuts, generate_shortcuts_hook};
+use crate::generate::siblings::generate_sibling_navigation;
+use crate::generate::static_links::generate_static_link_map;
+use crate::generate::stats::generate_route_stats;
+use crate::generate::status::generate_status_for;
+use crate::generate::strip_debug::{debug_only, strip_debug};
+use crate::generate::telemetry::{check_tracing_args, generate_telemetry_consts};
+use crate::generate::titles::generate_title_check;
+use crate::generate::transitions::generate_transitions;
+use crate::generate::usage::generate_usage_registry;
+use crate::generate::wizard::generate_wizard;
+use crate::path::{
+    collect_patterns, collect_static_segments, generate_patterns, generate_static_segments,
+};
 use crate::route_def::{flatten, RouteDef};
 use crate::RoutesMacroArgs;
 use proc_macro_error2::abort_call_site;
+use quote::quote;
 use syn::{parse_quote, Attribute, Item, ItemMod};
 
+pub mod absolute;
+pub mod ambiguity;
 pub mod all_routes_enum;
+pub mod args_struct;
+pub mod audit;
+pub mod back;
+pub mod bench;
+pub mod cache;
+pub mod data_route;
+pub mod endpoints;
+pub mod form;
+pub mod guards;
+pub mod host;
+pub mod layouts;
+pub mod legacy;
+pub mod manifest;
+pub mod namespace;
+pub mod nav;
+pub mod no_std;
+pub mod owners;
+pub mod pagination;
+pub mod palette;
+pub mod params_struct;
+pub mod precache;
+pub mod prefetch;
+pub mod recorder;
+pub mod relative;
+pub mod renders;
+pub mod resolve;
+pub mod robots;
+pub mod route_index;
+pub mod route_list;
+pub mod routes_json;
+pub mod route_set;
+pub mod route_table;
 pub mod route_struct;
 pub mod router;
+pub mod sections;
+pub mod security;
+pub mod shortcuts;
+pub mod siblings;
+pub mod static_links;
+pub mod stats;
+pub mod status;
+pub mod strip_debug;
+pub mod telemetry;
+pub mod titles;
+pub mod transitions;
+pub mod usage;
+pub mod warnings;
+pub mod wizard;
+
+/// Generates all items of the given routes module, returning the items generated next to it.
+pub fn impls(
+    root_mod: &mut ItemMod,
+    args: RoutesMacroArgs,
+    route_defs: Vec<RouteDef>,
+) -> proc_macro2::TokenStream {
+    // All items following the user's own items of the routes module are generated.
+    let first_generated_item = root_mod.content.as_ref().map_or(0, |(_, items)| items.len());
+
+    // Warn about routes shadowed by the static segments of siblings declared after them. This
+    // happens before any attributes are added to the routes module, as only the lints allowed by
+    // the user apply to the warnings.
+    let (ambiguous_routes, ambiguity_warnings) = generate_ambiguity_warnings(root_mod, &route_defs);
+    insert_into_module(root_mod, ambiguous_routes);
+
+    // With `audit_access`, warn about leaf routes without an access decision, the same way.
+    let (unaudited_routes, audit_warnings) = generate_access_audit(&args, root_mod, &route_defs);
+    insert_into_module(root_mod, unaudited_routes);
+
+    // With `stats`, note the statistics of the route tree, checking its declared limits.
+    let (route_stats, route_stats_note) = generate_route_stats(&args, root_mod, &route_defs);
+    insert_into_module(root_mod, route_stats);
 
-pub fn impls(root_mod: &mut ItemMod, args: RoutesMacroArgs, route_defs: Vec<RouteDef>) {
     // A common pattern could be to add a root-level `routes.rs` file containing the `#[routes]`
     // annotated inline-defined `routes` module.
     // Clippy does not like this nesting of similarly named modules. As it generally should!
@@ -18,25 +139,220 @@ pub fn impls(root_mod: &mut ItemMod, args: RoutesMacroArgs, route_defs: Vec<Rout
     let allow_module_inception: Attribute = parse_quote!(#[allow(clippy::module_inception)]);
     root_mod.attrs.push(allow_module_inception);
 
+    // The generated code itself keeps referring to deprecated routes. Only their uses outside of
+    // the routes module should be reported.
+    if flatten(&route_defs).any(|route_def| route_def.deprecated.is_some()) {
+        root_mod.attrs.push(parse_quote!(#[allow(deprecated)]));
+    }
+
+    // Document all routes in a table on the routes module.
+    root_mod.attrs.extend(generate_route_table_doc(&args, &root_mod.ident, &route_defs));
+
+    // With `strip_debug`, doc strings and `Debug` implementations only exist in debug builds.
+    let strip = |ts: proc_macro2::TokenStream| {
+        if args.strip_debug {
+            strip_debug(ts, true)
+        } else {
+            ts
+        }
+    };
+
+    // With `no_std`, import the items of the standard prelude into all modules receiving generated
+    // items, being the routes module and the modules of all routes.
+    let alloc_prelude = generate_alloc_prelude_import(&args);
+    if !alloc_prelude.is_empty() {
+        let mut module_paths = flatten(&route_defs)
+            .map(|route_def| route_def.found_in_module_path.without_first())
+            .collect::<Vec<_>>();
+        module_paths.sort();
+        module_paths.dedup();
+        insert_into_module(root_mod, alloc_prelude.clone());
+        for module_path in module_paths.into_iter().filter(|path| !path.is_empty()) {
+            let src_mod = find_src_module(root_mod, module_path).expect("present");
+            insert_into_module(src_mod, alloc_prelude.clone());
+        }
+    }
+
     // Generate the individual route structs.
-    for route_def in flatten(&route_defs) {
-        let (struct_def, struct_impl) = generate_route_struct(route_def, &route_defs);
+    for (index, route_def) in flatten(&route_defs).enumerate() {
+        let usage_index = args.deny_unused.then_some(index);
+        let (struct_def, struct_impl) =
+            generate_route_struct(route_def, &route_defs, &args, usage_index);
 
         let src_mod = find_src_module(root_mod, route_def.found_in_module_path.without_first())
             .expect("present");
 
-        insert_into_module(src_mod, struct_def);
-        insert_into_module(src_mod, struct_impl);
+        insert_into_module(src_mod, strip(struct_def));
+        insert_into_module(src_mod, strip(struct_impl));
+        insert_into_module(src_mod, strip(generate_params_struct(route_def, &route_defs)));
+        insert_into_module(src_mod, strip(generate_pagination_impl(route_def, &route_defs)));
+        insert_into_module(src_mod, strip(generate_args_struct(route_def, &route_defs)));
+        insert_into_module(src_mod, strip(generate_form_action_impl(&args, route_def, &route_defs)));
+        insert_into_module(
+            src_mod,
+            strip(generate_materialize_absolute_impl(&args, route_def, &route_defs)),
+        );
+        insert_into_module(src_mod, strip(generate_absolute_impl(route_def, &route_defs)));
+        insert_into_module(src_mod, strip(generate_data_route_const(&args, route_def)));
+        insert_into_module(src_mod, strip(generate_telemetry_consts(route_def, &route_defs)));
+        if args.with_views {
+            insert_into_module(src_mod, strip(generate_subtree_routes_component(route_def)));
+        }
+
+        // The wizard of a route is generated in the module of its steps.
+        if let Some(step) = route_def.children.iter().find(|child| child.step.is_some()) {
+            let steps_mod = find_src_module(root_mod, step.found_in_module_path.without_first())
+                .expect("present");
+            insert_into_module(steps_mod, strip(generate_wizard(route_def, &route_defs)));
+        }
     }
 
-    // Generate a "Route" enum listing all possible routes.
-    insert_into_module(root_mod, generate_route_enum(&route_defs));
+    // Generate the registry of materialized routes.
+    insert_into_module(root_mod, strip(generate_usage_registry(&args, &route_defs)));
 
-    // Generate a "Router" implementation.
+    // Generate the static segments shared by the runtime patterns of all routes.
     insert_into_module(
         root_mod,
-        maybe_generate_routes_component(&args, &route_defs),
+        generate_static_segments(&collect_static_segments(&route_defs)),
     );
+
+    // Generate the table of full path patterns the helpers of all routes index into.
+    insert_into_module(root_mod, generate_patterns(&collect_patterns(&route_defs)));
+
+    // Generate the pagination helper, if any route is paginated.
+    insert_into_module(root_mod, strip(generate_pagination_type(&route_defs)));
+
+    // Generate the error type of all params structs. Its `Error` implementation requires `Debug`.
+    let mut param_error = generate_param_error();
+    if args.strip_debug {
+        param_error = strip_debug(param_error, false);
+    }
+    insert_into_module(root_mod, param_error);
+
+    // Generate the table of all routes documented on the routes module as a constant.
+    let mut route_table = generate_route_table_const(&args, &root_mod.ident, &route_defs);
+    if args.strip_debug {
+        route_table = debug_only(strip(route_table));
+    }
+    insert_into_module(root_mod, route_table);
+
+    // Generate a "Route" enum listing all possible routes and the items built on it, which are only
+    // generated for debug builds with `strip_debug`.
+    let route_enum = generate_route_enum(&args, &route_defs);
+
+    // Classifying and looking up routes.
+    let classification = generate_route_classification(&route_defs);
+    let route_set = generate_route_set(&route_defs);
+    let from_url = generate_from_url(&args);
+    let resolve = generate_resolve();
+
+    // Relations between routes.
+    let layout_chains = generate_layout_chains(&route_defs);
+    let manifest_check = generate_manifest_check();
+    let relative = generate_relative_impls(&route_defs);
+    let siblings = generate_sibling_navigation(&route_defs);
+
+    // Navigation UI.
+    let shortcuts = generate_shortcuts(&route_defs);
+    let palette = generate_palette(&route_defs);
+    let nav_items = generate_nav_items(&route_defs);
+
+    // Per-route HTTP behavior.
+    let cache_policy = generate_cache_policy(&route_defs);
+    let status_for = generate_status_for(&route_defs);
+    let security_headers = generate_security_headers(&route_defs);
+    let prefetch = generate_prefetch(&route_defs);
+    let legacy_redirects = generate_legacy_redirects(&route_defs);
+
+    // Tooling.
+    let owners = generate_owners(&route_defs);
+    let resource_endpoints = generate_resource_endpoints(&args, &route_defs);
+    let bench = generate_bench(&route_defs);
+    let mut route_enum = quote! {
+        #route_enum
+        #classification
+        #route_set
+        #from_url
+        #resolve
+        #layout_chains
+        #manifest_check
+        #relative
+        #siblings
+        #shortcuts
+        #palette
+        #nav_items
+        #cache_policy
+        #status_for
+        #security_headers
+        #prefetch
+        #legacy_redirects
+        #owners
+        #resource_endpoints
+        #bench
+    };
+    if args.strip_debug {
+        route_enum = debug_only(strip(route_enum));
+    }
+    insert_into_module(root_mod, route_enum);
+
+    // Generate a listing of all routes excluded from indexing.
+    insert_into_module(root_mod, strip(generate_noindex_patterns(&route_defs)));
+
+    // Generate the list of all rendered paths for server integrations.
+    insert_into_module(root_mod, strip(generate_route_list(&args, &route_defs)));
+
+    // Generate a "Router" implementation, optionally rendering all views in `tracing` spans.
+    check_tracing_args(&args);
+    insert_into_module(
+        root_mod,
+        strip(maybe_generate_routes_component(&args, &route_defs)),
+    );
+
+    // Generate the identifiers of the `data-route` attributes of all routes.
+    insert_into_module(root_mod, strip(generate_data_routes(&args, &route_defs)));
+
+    // Generate the link templates of all routes for server templates.
+    insert_into_module(root_mod, strip(generate_static_link_map(&route_defs)));
+
+    // Generate the route table for JavaScript glue code.
+    insert_into_module(root_mod, strip(generate_routes_json(&route_defs)));
+
+    // Generate the paths to precache for offline use.
+    insert_into_module(root_mod, strip(generate_precache_urls(&route_defs)));
+
+    // Generate the hook navigating to routes using their keyboard shortcuts.
+    insert_into_module(root_mod, strip(generate_shortcuts_hook(&route_defs)));
+
+    // Generate the helper going back only to pages of this app.
+    insert_into_module(root_mod, strip(generate_back_or(&args, &route_defs)));
+
+    // Generate the table of allowed transitions between routes and the helper enforcing it.
+    insert_into_module(root_mod, strip(generate_transitions(&args, &route_defs)));
+
+    // Generate the helpers resuming the navigation a guard redirected away from.
+    insert_into_module(root_mod, strip(generate_intended_navigation(&args, &route_defs)));
+
+    // Generate the page listing all routes.
+    insert_into_module(root_mod, strip(generate_route_index_page(&args, &route_defs)));
+
+    // Generate a recorder of the rendered routes.
+    insert_into_module(root_mod, generate_route_recorder(&args));
+
+    // Generate a check for views of all routes not rendering deterministically.
+    insert_into_module(root_mod, strip(generate_render_check(&args, &route_defs)));
+
+    // Generate a check for the titles applied by all routes.
+    insert_into_module(root_mod, strip(generate_title_check(&args, &route_defs)));
+
+    // With a `namespace`, generate prefixed aliases of all generated items.
+    let aliases = generate_namespace_aliases(&args, root_mod, first_generated_item);
+    insert_into_module(root_mod, aliases);
+
+    quote! {
+        #ambiguity_warnings
+        #audit_warnings
+        #route_stats_note
+    }
 }
 
 pub fn find_src_module<'a>(
@@ -61,10 +377,10 @@ pub fn find_src_module<'a>(
 }
 
 pub fn insert_into_module(module: &mut ItemMod, ts: proc_macro2::TokenStream) {
-    match syn::parse2::<Item>(ts) {
-        Ok(item) => {
+    match syn::parse2::<syn::File>(ts) {
+        Ok(file) => {
             if let Some((_, items)) = &mut module.content {
-                items.push(item);
+                items.extend(file.items);
             } else {
                 abort_call_site!("Expected module to have content");
             }
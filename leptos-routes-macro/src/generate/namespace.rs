@@ -0,0 +1,56 @@
+use crate::util::to_snake_case;
+use crate::RoutesMacroArgs;
+use proc_macro_error2::abort_call_site;
+use quote::{format_ident, quote};
+use syn::{Item, ItemMod};
+
+/// Generates prefixed aliases of all public items generated in the routes module, starting at
+/// the given index of its items, when declaring a `namespace`. Types are prefixed like
+/// `AdminRoute`, functions like `admin_generated_routes` and constants like `ADMIN_DATA_ROUTES`.
+pub fn generate_namespace_aliases(
+    args: &RoutesMacroArgs,
+    root_mod: &ItemMod,
+    first_generated_item: usize,
+) -> proc_macro2::TokenStream {
+    let Some(namespace) = &args.namespace else {
+        return quote! {};
+    };
+    let mut chars = namespace.chars();
+    if !chars.next().is_some_and(|c| c.is_ascii_uppercase())
+        || !chars.all(|c| c.is_ascii_alphanumeric())
+    {
+        abort_call_site!("The namespace \"{}\" prefixes the names of generated types, and must be written in pascal case, like namespace = \"Admin\".", namespace);
+    }
+    let snake = to_snake_case(namespace);
+    let upper = snake.to_uppercase();
+    let type_alias = |ident: &syn::Ident| format_ident!("{namespace}{ident}");
+    let fn_alias = |ident: &syn::Ident| format_ident!("{snake}_{ident}");
+    let const_alias = |ident: &syn::Ident| format_ident!("{upper}_{ident}");
+
+    let Some((_, items)) = &root_mod.content else {
+        return quote! {};
+    };
+    let aliases = items[first_generated_item..].iter().filter_map(|item| {
+        let (attrs, vis, ident, alias) = match item {
+            Item::Struct(item) => (&item.attrs, &item.vis, &item.ident, type_alias(&item.ident)),
+            Item::Enum(item) => (&item.attrs, &item.vis, &item.ident, type_alias(&item.ident)),
+            Item::Trait(item) => (&item.attrs, &item.vis, &item.ident, type_alias(&item.ident)),
+            Item::Type(item) => (&item.attrs, &item.vis, &item.ident, type_alias(&item.ident)),
+            Item::Fn(item) => (&item.attrs, &item.vis, &item.sig.ident, fn_alias(&item.sig.ident)),
+            Item::Const(item) => (&item.attrs, &item.vis, &item.ident, const_alias(&item.ident)),
+            Item::Static(item) => (&item.attrs, &item.vis, &item.ident, const_alias(&item.ident)),
+            _ => return None,
+        };
+        // Hidden items, like those generating warnings, are no part of the API.
+        if !matches!(vis, syn::Visibility::Public(_)) || ident.to_string().starts_with("__") {
+            return None;
+        }
+        // Items only generated in some builds are only aliased in those.
+        let cfgs = attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+        Some(quote! {
+            #(#cfgs)*
+            pub use self::#ident as #alias;
+        })
+    });
+    quote! { #(#aliases)* }
+}
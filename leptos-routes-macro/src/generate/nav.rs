@@ -0,0 +1,97 @@
+use crate::path::ParamInfo;
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates `Route::roles()` and `Route::permits()`, as well as the `NavItem` type, `nav_items()`
+/// listing all routes declaring a `title` or `nav(label_key = "...")` and `nav_items_for()` listing
+/// those permitted to the given roles. Routes with required parameters are not listed, as they can't be navigated to from
+/// a menu without parameter values.
+pub fn generate_nav_items(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let role_arms = flatten(route_defs).map(|route_def| {
+        let variant_name = route_def.variant_name();
+        let roles = &route_def.roles;
+        quote! { Route::#variant_name(_) => &[#(#roles),*], }
+    });
+    let items = flatten(route_defs)
+        .filter_map(|route_def| {
+            let title = route_def.title.as_ref().or(route_def.nav_label_key.as_ref())?;
+            let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+            if params.iter().any(|p| !p.is_optional) {
+                return None;
+            }
+            Some((title, route_def, params.len()))
+        })
+        .map(|(title, route_def, param_count)| {
+            let variant_name = route_def.variant_name();
+            let struct_path = route_def.full_module_path_to_struct_def();
+            let nones = (0..param_count).map(|_| quote!(None));
+            let label_key = match &route_def.nav_label_key {
+                Some(label_key) => quote! { Some(#label_key) },
+                None => quote! { None },
+            };
+            quote! {
+                NavItem {
+                    title: #title,
+                    label_key: #label_key,
+                    route: Route::#variant_name(self::#struct_path),
+                    href: || self::#struct_path.materialize(#(#nones),*),
+                }
+            }
+        });
+    quote! {
+        impl Route {
+            /// The roles permitted to access this route, declared using `roles("...")` on the
+            /// route itself or on its nearest parent. Empty for routes accessible to everyone.
+            pub fn roles(&self) -> &'static [&'static str] {
+                match self {
+                    #(#role_arms)*
+                }
+            }
+
+            /// Whether a user with the given roles may access this route: either the route
+            /// declares no roles, or the user has any of them.
+            pub fn permits(&self, user_roles: &[&str]) -> bool {
+                let roles = self.roles();
+                roles.is_empty() || roles.iter().any(|role| user_roles.contains(role))
+            }
+        }
+
+        /// A route shown in a navigation menu.
+        #[derive(Debug, Clone, Copy)]
+        pub struct NavItem {
+            /// The title declared using `title = "..."`, or the `label_key` if the route declares
+            /// no title.
+            pub title: &'static str,
+            /// The message key declared using `nav(label_key = "...")`.
+            pub label_key: Option<&'static str>,
+            pub route: Route,
+            /// Materializes the path to navigate to when this item is chosen.
+            pub href: fn() -> String,
+        }
+
+        impl NavItem {
+            /// The label to show for this item: the message of its `label_key` resolved by the
+            /// given resolver, falling back to its `title`.
+            pub fn label(&self, resolver: &impl ::leptos_routes::labels::LabelResolver) -> String {
+                self.label_key
+                    .and_then(|key| resolver.resolve(key))
+                    .unwrap_or_else(|| self.title.to_string())
+            }
+        }
+
+        /// All routes declaring a `title` or `nav(label_key = "...")`, in declaration order.
+        /// Routes with required parameters are not listed.
+        pub fn nav_items() -> Vec<NavItem> {
+            vec![#(#items),*]
+        }
+
+        /// The `nav_items()` a user with the given roles may access, as decided by
+        /// `Route::permits`.
+        pub fn nav_items_for(user_roles: &[&str]) -> Vec<NavItem> {
+            nav_items()
+                .into_iter()
+                .filter(|item| item.route.permits(user_roles))
+                .collect()
+        }
+    }
+}
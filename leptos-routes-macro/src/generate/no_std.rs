@@ -0,0 +1,19 @@
+use crate::RoutesMacroArgs;
+use proc_macro_error2::abort_call_site;
+use quote::quote;
+
+/// Generates the import of the `alloc` prelude, if requested with `no_std`. Generated items refer
+/// to `String`, `Vec` and `format!` without a path, which only the standard prelude provides, so
+/// every module receiving generated items imports them.
+pub fn generate_alloc_prelude_import(args: &RoutesMacroArgs) -> proc_macro2::TokenStream {
+    if !args.no_std {
+        return quote! {};
+    }
+    if args.with_views {
+        abort_call_site!("\"no_std\" lets the generated route structs compile without the standard library, which the views rendered with \"with_views\" require. Remove one of the arguments.");
+    }
+    quote! {
+        #[allow(unused_imports)]
+        use ::leptos_routes::__private::alloc_prelude::*;
+    }
+}
@@ -0,0 +1,21 @@
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates `owners()`, pairing every route with the team declared using `owner`, on the route
+/// itself or on its nearest parent.
+pub fn generate_owners(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let entries = flatten(route_defs).filter_map(|route_def| {
+        let owner = route_def.owner.as_ref()?;
+        let variant_name = route_def.variant_name();
+        let struct_path = route_def.full_module_path_to_struct_def();
+        Some(quote! { (Route::#variant_name(self::#struct_path), #owner) })
+    });
+    quote! {
+        /// All routes owned by a team declared using `owner = "..."`, paired with that team, in
+        /// declaration order. Routes inherit the owner of their nearest parent declaring one.
+        /// Routes without an owner are not listed.
+        pub fn owners() -> Vec<(Route, &'static str)> {
+            vec![#(#entries),*]
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::path::ParamInfo;
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates the `Pagination` type returned by the `use_pagination` functions of all routes
+/// declared with `pagination`. Nothing is generated when no such route exists.
+pub fn generate_pagination_type(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    if !flatten(route_defs).any(|route_def| route_def.pagination.is_some()) {
+        return quote! {};
+    }
+
+    quote! {
+        /// Reactive access to the `?page=` and `?per_page=` query parameters of a paginated route.
+        ///
+        /// Pages are counted starting at 1.
+        #[derive(Debug, Clone, Copy)]
+        pub struct Pagination {
+            page: ::leptos::prelude::Memo<Option<u32>>,
+            set_page: ::leptos::reactive::wrappers::write::SignalSetter<Option<u32>>,
+            per_page: ::leptos::prelude::Memo<Option<u32>>,
+            set_per_page: ::leptos::reactive::wrappers::write::SignalSetter<Option<u32>>,
+            default_per_page: u32,
+        }
+
+        impl Pagination {
+            /// Creates signals synchronized with the `page` and `per_page` query parameters of the
+            /// current location. Must be called within a `<Router>`.
+            pub fn new(default_per_page: u32) -> Self {
+                let (page, set_page) = ::leptos_router::hooks::query_signal::<u32>("page");
+                let (per_page, set_per_page) = ::leptos_router::hooks::query_signal::<u32>("per_page");
+                Self {
+                    page,
+                    set_page,
+                    per_page,
+                    set_per_page,
+                    default_per_page,
+                }
+            }
+
+            /// The current page, defaulting to 1 when absent or invalid. Tracked.
+            pub fn page(&self) -> u32 {
+                use ::leptos::prelude::Get;
+                self.page.get().filter(|page| *page > 0).unwrap_or(1)
+            }
+
+            /// The current page size, defaulting to the route's declared `per_page`. Tracked.
+            pub fn per_page(&self) -> u32 {
+                use ::leptos::prelude::Get;
+                self.per_page
+                    .get()
+                    .filter(|per_page| *per_page > 0)
+                    .unwrap_or(self.default_per_page)
+            }
+
+            /// The number of items on all pages before the current one. Tracked. Computed as a
+            /// `u64`, so that large pages requested in the URL cannot overflow it.
+            pub fn offset(&self) -> u64 {
+                u64::from(self.page() - 1) * u64::from(self.per_page())
+            }
+
+            /// Navigates to the given page.
+            pub fn set_page(&self, page: u32) {
+                use ::leptos::prelude::Set;
+                self.set_page.set(Some(page.max(1)));
+            }
+
+            /// Navigates to the first page, using the given page size.
+            pub fn set_per_page(&self, per_page: u32) {
+                use ::leptos::prelude::Set;
+                self.set_per_page.set(Some(per_page.max(1)));
+                self.set_page.set(Some(1));
+            }
+
+            /// Navigates to the next page.
+            pub fn next_page(&self) {
+                use ::leptos::prelude::GetUntracked;
+                let page = self.page.get_untracked().unwrap_or(1).max(1);
+                self.set_page(page + 1);
+            }
+
+            /// Navigates to the previous page, staying on the first page.
+            pub fn previous_page(&self) {
+                use ::leptos::prelude::GetUntracked;
+                let page = self.page.get_untracked().unwrap_or(1).max(1);
+                self.set_page(page.saturating_sub(1));
+            }
+        }
+    }
+}
+
+/// Generates the `materialize_page` and `use_pagination` functions of a route declared with
+/// `pagination`.
+pub fn generate_pagination_impl(
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let Some(pagination) = &route_def.pagination else {
+        return quote! {};
+    };
+    let struct_name = &route_def.name;
+    let default_per_page = pagination.per_page;
+    let depth = route_def.found_in_module_path.without_first().len();
+    let supers = (0..depth).map(|_| quote!(super::)).collect::<Vec<_>>();
+
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    let param_idents = params.iter().map(|p| p.ident()).collect::<Vec<_>>();
+    let param_types = params.iter().map(|p| p.signature_type(depth));
+
+    quote! {
+        impl #struct_name {
+            /// Materializes the path of this route, selecting the given page of the given size
+            /// using the `page` and `per_page` query parameters.
+            pub fn materialize_page(&self, #(#param_idents: #param_types,)* page: u32, per_page: u32) -> String {
+                format!("{}?page={}&per_page={}", self.materialize(#(#param_idents),*), page, per_page)
+            }
+
+            /// Reactive access to the `page` and `per_page` query parameters of the current
+            /// location. Must be called within a `<Router>`.
+            pub fn use_pagination(&self) -> #(#supers)* Pagination {
+                #(#supers)* Pagination::new(#default_per_page)
+            }
+        }
+    }
+}
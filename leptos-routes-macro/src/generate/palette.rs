@@ -0,0 +1,62 @@
+use crate::path::ParamInfo;
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates the `PaletteEntry` type and the `searchable_routes()` listing all routes declaring a
+/// `title`. Routes with required parameters are skipped, as they can't be navigated to from a
+/// search result without parameter values.
+pub fn generate_palette(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let entries = flatten(route_defs)
+        .filter_map(|route_def| {
+            let title = route_def.title.as_ref()?;
+            let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+            if params.iter().any(|p| !p.is_optional) {
+                return None;
+            }
+            Some((title, route_def, params.len()))
+        })
+        .map(|(title, route_def, param_count)| {
+            let keywords = &route_def.keywords;
+            let variant_name = route_def.variant_name();
+            let struct_path = route_def.full_module_path_to_struct_def();
+            let nones = (0..param_count).map(|_| quote!(None));
+            quote! {
+                PaletteEntry {
+                    title: #title,
+                    keywords: &[#(#keywords),*],
+                    route: Route::#variant_name(self::#struct_path),
+                    href: || self::#struct_path.materialize(#(#nones),*),
+                }
+            }
+        });
+    quote! {
+        /// A searchable route, as shown in a command palette.
+        #[derive(Debug, Clone, Copy)]
+        pub struct PaletteEntry {
+            /// The title declared using `title = "..."`.
+            pub title: &'static str,
+            /// The keywords declared using `keywords("...")`.
+            pub keywords: &'static [&'static str],
+            pub route: Route,
+            /// Materializes the path to navigate to when this entry is chosen.
+            pub href: fn() -> String,
+        }
+
+        impl PaletteEntry {
+            /// Whether the title or any keyword contains the given query, ignoring case.
+            /// An empty query matches all entries.
+            pub fn matches(&self, query: &str) -> bool {
+                let query = query.trim().to_lowercase();
+                ::core::iter::once(self.title)
+                    .chain(self.keywords.iter().copied())
+                    .any(|text| text.to_lowercase().contains(&query))
+            }
+        }
+
+        /// All routes declaring a `title`, in declaration order. Routes with required parameters
+        /// are not listed.
+        pub fn searchable_routes() -> Vec<PaletteEntry> {
+            vec![#(#entries),*]
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::path::{ParamInfo, ParamType};
+use crate::route_def::RouteDef;
+use quote::quote;
+
+/// Generates the `ParamError` type, returned when converting raw parameter values into the typed
+/// params struct of a route.
+pub fn generate_param_error() -> proc_macro2::TokenStream {
+    quote! {
+        /// An error converting raw route parameters into the typed params struct of a route.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum ParamError {
+            /// A required parameter was not present.
+            Missing { name: &'static str },
+            /// A parameter value did not satisfy its declared constraint or could not be parsed into
+            /// its declared type.
+            Invalid { name: &'static str, value: String },
+        }
+
+        impl ::core::fmt::Display for ParamError {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    ParamError::Missing { name } => write!(f, "missing parameter \"{name}\""),
+                    ParamError::Invalid { name, value } => {
+                        write!(f, "invalid value \"{value}\" for parameter \"{name}\"")
+                    }
+                }
+            }
+        }
+
+        impl ::core::error::Error for ParamError {}
+    }
+}
+
+/// Generates the typed params struct of a route, holding the values of all parameters of the route
+/// and its parents.
+pub fn generate_params_struct(
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let params_struct_name = route_def.params_struct_name();
+    let vis = &route_def.vis;
+    let depth = route_def.found_in_module_path.without_first().len();
+    let supers = (0..depth).map(|_| quote!(super::)).collect::<Vec<_>>();
+    let param_error = quote!(#(#supers)* ParamError);
+
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    let fields = params.iter().map(|p| {
+        let ident = p.ident();
+        let ty = p.field_type(depth);
+        quote! { pub #ident: #ty }
+    });
+    let conversions = params.iter().map(|p| {
+        let ident = p.ident();
+        let name = &p.name;
+        let constraint_check = p.constraint_check(quote!(value)).map(|check| {
+            quote! {
+                if !#check {
+                    return Err(#param_error::Invalid { name: #name, value: value.to_owned() });
+                }
+            }
+        });
+        let parsed = match &p.ty {
+            ParamType::Str => quote! { value.to_owned() },
+            ty => {
+                let parsed = ty.parse(quote!(value), depth);
+                quote! {
+                    #parsed.map_err(|_| #param_error::Invalid {
+                        name: #name,
+                        value: value.to_owned(),
+                    })?
+                }
+            }
+        };
+        if let Some(default) = &p.default {
+            quote! {
+                #ident: {
+                    let value = param(#name).unwrap_or(#default);
+                    #constraint_check
+                    #parsed
+                }
+            }
+        } else if p.is_optional {
+            quote! {
+                #ident: match param(#name) {
+                    Some(value) => {
+                        #constraint_check
+                        Some(#parsed)
+                    }
+                    None => None,
+                }
+            }
+        } else {
+            quote! {
+                #ident: {
+                    let value = param(#name).ok_or(#param_error::Missing { name: #name })?;
+                    #constraint_check
+                    #parsed
+                }
+            }
+        }
+    });
+
+    let doc = format!(
+        "The typed parameters of the `{}` route.",
+        route_def.full_path
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, PartialEq)]
+        #vis struct #params_struct_name {
+            #(#fields,)*
+        }
+
+        impl #params_struct_name {
+            /// Converts the raw parameter values returned by `param` into their declared types,
+            /// checking all declared constraints.
+            #[allow(unused_variables)]
+            pub fn from_params<'a>(
+                param: impl Fn(&str) -> Option<&'a str>,
+            ) -> Result<Self, #param_error> {
+                Ok(Self {
+                    #(#conversions,)*
+                })
+            }
+        }
+
+        impl ::leptos_router::params::Params for #params_struct_name {
+            fn from_map(
+                map: &::leptos_router::params::ParamsMap,
+            ) -> Result<Self, ::leptos_router::params::ParamsError> {
+                Self::from_params(|name| map.get_str(name)).map_err(|e| {
+                    ::leptos_router::params::ParamsError::Params(
+                        ::leptos_routes::__private::alloc::sync::Arc::new(e),
+                    )
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::generate::route_index::sample_path;
+use crate::path::ParamInfo;
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates the `precache_urls` function, listing the paths of all routes without parameters, as
+/// materialized, and those of routes with parameters, using the sample values declared with
+/// `sample(...)`. Routes with required parameters lacking a sample value are left out.
+pub fn generate_precache_urls(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let mut paths = Vec::new();
+    let mut urls = Vec::new();
+    for route_def in flatten(route_defs) {
+        let is_static = ParamInfo::collect_params_through_hierarchy(route_defs, route_def).is_empty();
+        let (path, url) = if is_static {
+            // Static paths are taken as materialized, respecting `case_insensitive`.
+            let struct_path = route_def.full_module_path_to_struct_def();
+            (route_def.full_path.clone(), quote!(self::#struct_path::MATERIALIZED_PATH))
+        } else {
+            match sample_path(route_def) {
+                Some(path) => (path.clone(), quote!(#path)),
+                None => continue,
+            }
+        };
+        if !paths.contains(&path) {
+            paths.push(path);
+            urls.push(url);
+        }
+    }
+    quote! {
+        /// The paths of all routes without parameters, and of all routes with parameters using
+        /// the sample values declared with `sample(...)`, to be precached by the service worker of
+        /// an offline-capable app.
+        pub fn precache_urls() -> &'static [&'static str] {
+            &[#(#urls),*]
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::route_def::{flatten, RouteDef};
+use crate::route_macro_args::PrefetchArg;
+use quote::quote;
+
+pub fn generate_prefetch(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let arms = flatten(route_defs).map(|route_def| {
+        let variant_name = route_def.variant_name();
+        let prefetch = match route_def.prefetch.unwrap_or(PrefetchArg::None) {
+            PrefetchArg::None => quote! { ::leptos_routes::prefetch::Prefetch::None },
+            PrefetchArg::Hover => quote! { ::leptos_routes::prefetch::Prefetch::Hover },
+            PrefetchArg::Visible => quote! { ::leptos_routes::prefetch::Prefetch::Visible },
+        };
+        quote! {
+            Route::#variant_name(_) => #prefetch,
+        }
+    });
+
+    quote! {
+        /// When links to the given route prefetch it, declared (or inherited from a parent route
+        /// declaring it) using `prefetch = "..."`.
+        pub fn prefetch(route: &Route) -> ::leptos_routes::prefetch::Prefetch {
+            match *route {
+                #(#arms)*
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::RoutesMacroArgs;
+use proc_macro_error2::abort_call_site;
+use quote::quote;
+
+/// Generates the `RouteRecorder`, if requested with `route_recorder`.
+pub fn generate_route_recorder(args: &RoutesMacroArgs) -> proc_macro2::TokenStream {
+    if !args.route_recorder {
+        return quote! {};
+    }
+    if !args.with_views {
+        abort_call_site!("\"route_recorder\" records the routes rendered by the generated routes and requires \"with_views\". Add \"with_views\" or remove the argument.");
+    }
+    if args.strip_debug {
+        abort_call_site!("\"route_recorder\" records `Route` values, which \"strip_debug\" removes from release builds. Remove one of the arguments.");
+    }
+
+    quote! {
+        /// Records the routes rendered by the generated routes while provided as context, for
+        /// asserting the navigation flow of an app in tests.
+        #[derive(Debug, Clone, Default)]
+        pub struct RouteRecorder(::std::sync::Arc<::std::sync::Mutex<Vec<Route>>>);
+
+        impl RouteRecorder {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Provides this recorder as context, recording all routes rendered below the current
+            /// reactive owner.
+            pub fn provide(&self) {
+                ::leptos::prelude::provide_context(self.clone());
+            }
+
+            pub fn record(&self, route: Route) {
+                self.0.lock().expect("not poisoned").push(route);
+            }
+
+            /// All recorded routes, in the order they were rendered in.
+            pub fn visited(&self) -> Vec<Route> {
+                self.0.lock().expect("not poisoned").clone()
+            }
+
+            pub fn has_visited(&self, route: Route) -> bool {
+                self.0.lock().expect("not poisoned").contains(&route)
+            }
+
+            pub fn clear(&self) {
+                self.0.lock().expect("not poisoned").clear();
+            }
+
+            /// Panics if the given route was not rendered.
+            #[track_caller]
+            pub fn assert_visited(&self, route: Route) {
+                assert!(
+                    self.has_visited(route),
+                    "Expected route {route:?} to be visited. Visited: {:?}",
+                    self.visited()
+                );
+            }
+
+            /// Panics if the given route was rendered.
+            #[track_caller]
+            pub fn assert_not_visited(&self, route: Route) {
+                assert!(
+                    !self.has_visited(route),
+                    "Expected route {route:?} not to be visited. Visited: {:?}",
+                    self.visited()
+                );
+            }
+        }
+    }
+}
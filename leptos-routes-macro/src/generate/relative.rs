@@ -0,0 +1,54 @@
+use crate::path::{collect_patterns, pattern_ref, ParamInfo};
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates `Route::full_path()` and a `materialize_relative_to(other, ...)` function on every
+/// route struct, computing the href of the route relative to another one, like "../details".
+pub fn generate_relative_impls(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let patterns = collect_patterns(route_defs);
+    let full_path_arms = flatten(route_defs).map(|route_def| {
+        let variant_name = route_def.variant_name();
+        let full_path = pattern_ref(&patterns, &route_def.full_path, 0);
+        quote! {
+            Route::#variant_name(_) => #full_path,
+        }
+    });
+    let impls = flatten(route_defs).map(|route_def| {
+        let struct_path = route_def.full_module_path_to_struct_def();
+        let full_path = pattern_ref(&patterns, &route_def.full_path, 0);
+        let all_params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+        let params = all_params.iter().map(|p| {
+            let name = p.ident();
+            let ty = p.signature_type(0);
+            quote! { #name: #ty }
+        });
+        let param_idents = all_params.iter().map(|p| p.ident());
+        let deprecated = route_def.deprecated_attr();
+        quote! {
+            impl self::#struct_path {
+                /// The href of this route relative to the given route, like "../details", as
+                /// resolved by links rendered within the view of the given route. Only parameters
+                /// following the path both routes share end up in the href.
+                #deprecated
+                #[allow(deprecated)]
+                #[track_caller]
+                pub fn materialize_relative_to(&self, other: &Route, #(#params),*) -> String {
+                    let path = self.materialize(#(#param_idents),*);
+                    ::leptos_routes::links::relative_href(other.full_path(), #full_path, &path)
+                }
+            }
+        }
+    });
+    quote! {
+        impl Route {
+            /// The full path of this route, including all parent paths, like "/users/:id/details".
+            pub fn full_path(&self) -> &'static str {
+                match *self {
+                    #(#full_path_arms)*
+                }
+            }
+        }
+
+        #(#impls)*
+    }
+}
@@ -0,0 +1,72 @@
+use crate::path::{PathSegment, PathSegments};
+use crate::generate::route_index;
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use quote::quote;
+
+/// A path not matched by any route, used to check the render of the `fallback` view.
+const UNMATCHED_PATH: &str = "/__leptos_routes_unmatched__";
+
+/// Generates a `check_renders` function, checking the render of one path per route, when using
+/// `with_views` and the "render-tests" feature.
+pub fn generate_render_check(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    if !cfg!(feature = "render-tests") || !args.with_views {
+        return quote! {};
+    }
+
+    let mut paths = Vec::new();
+    for path in flatten(route_defs)
+        .map(test_path)
+        .chain([UNMATCHED_PATH.to_string()])
+    {
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+
+    quote! {
+        /// The paths checked by `check_renders`: one per route, with each parameter set to its
+        /// `sample` value or "1", and one path not matched by any route.
+        pub const RENDER_TEST_PATHS: &[&str] = &[#(#paths),*];
+
+        /// Renders each path of `RENDER_TEST_PATHS` twice like the server would, returning the first
+        /// unbalanced branch or difference between both renders found.
+        pub fn check_renders() -> ::std::result::Result<(), ::leptos_routes::renders::RenderMismatch> {
+            use ::leptos_router::components::Router;
+            use ::leptos::prelude::*;
+
+            ::leptos_routes::renders::check_paths(RENDER_TEST_PATHS, || {
+                view! {
+                    <Router>
+                        {generated_routes()}
+                    </Router>
+                }
+            })
+        }
+    }
+}
+
+/// A path matched by the given route, with each parameter set to its `sample` value. Parameters
+/// without one are set to "1", so that no route is left out.
+pub fn test_path(route_def: &RouteDef) -> String {
+    route_index::sample_path(route_def).unwrap_or_else(|| placeholder_path(route_def))
+}
+
+/// A path matched by the given route, with each parameter set to "1". Optional parameters and
+/// wildcards are left out.
+fn placeholder_path(route_def: &RouteDef) -> String {
+    let path = PathSegments::parse(&route_def.full_path)
+        .segments
+        .iter()
+        .filter_map(|segment| match segment {
+            PathSegment::Static(s) => Some(s.as_str()),
+            PathSegment::Param(_) => Some("1"),
+            PathSegment::OptionalParam(_) | PathSegment::Wildcard(_) => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("/{path}")
+}
@@ -0,0 +1,32 @@
+use quote::quote;
+
+/// Generates the `resolve(path)` function, finding the route of a path together with its
+/// parameters and the routes whose layouts wrap it, without a reactive owner.
+pub fn generate_resolve() -> proc_macro2::TokenStream {
+    quote! {
+        /// A route found by `resolve`.
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct ResolvedRoute {
+            /// The route matching the path.
+            pub route: Route,
+            /// The typed values of the route's parameters.
+            pub params: RouteParams,
+            /// The route and its parent routes declaring a `layout` or `layout_stack`, wrapping
+            /// its view, outermost first.
+            pub layout_chain: &'static [Route],
+        }
+
+        /// Finds the route matching the given path like `Route::from_path_with_params`, together
+        /// with the routes whose layouts wrap its view. Being a pure function, needing neither a
+        /// reactive owner nor a rendered app, it suits unit tests of routing logic and decisions
+        /// made on the server.
+        pub fn resolve(path: &str) -> Option<ResolvedRoute> {
+            let (route, params) = Route::from_path_with_params(path)?;
+            Some(ResolvedRoute {
+                route,
+                params,
+                layout_chain: route.layout_chain(),
+            })
+        }
+    }
+}
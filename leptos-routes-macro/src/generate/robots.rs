@@ -0,0 +1,51 @@
+use crate::path::{PathSegment, PathSegments};
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Converts a full route path into robots.txt compatible patterns, replacing all dynamic segments
+/// with a `*` wildcard. An optional parameter yields one pattern with and one without its segment,
+/// as `/users/*` does not cover `/users` itself.
+fn to_robots_patterns(full_path: &str) -> Vec<String> {
+    let segments = PathSegments::parse(full_path);
+    let mut patterns = vec![String::new()];
+    for segment in &segments.segments {
+        match segment {
+            PathSegment::Static(s) => patterns.iter_mut().for_each(|pattern| {
+                pattern.push('/');
+                pattern.push_str(s);
+            }),
+            PathSegment::Param(_) | PathSegment::Wildcard(_) => {
+                patterns.iter_mut().for_each(|pattern| pattern.push_str("/*"))
+            }
+            PathSegment::OptionalParam(_) => {
+                let with_segment = patterns.iter().map(|pattern| format!("{pattern}/*"));
+                patterns = patterns.iter().cloned().chain(with_segment).collect();
+            }
+        }
+    }
+    let mut unique = Vec::new();
+    for pattern in patterns {
+        let pattern = if pattern.is_empty() { "/".to_owned() } else { pattern };
+        if !unique.contains(&pattern) {
+            unique.push(pattern);
+        }
+    }
+    unique
+}
+
+pub fn generate_noindex_patterns(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let patterns = flatten(route_defs)
+        // Index routes share the pattern of their parent.
+        .filter(|route_def| route_def.noindex && !route_def.is_index)
+        .flat_map(|route_def| to_robots_patterns(&route_def.full_path));
+
+    quote! {
+        /// Patterns of all routes declared (or nested below a route declared) with `noindex`.
+        ///
+        /// Dynamic segments are replaced with a `*` wildcard, making each pattern usable as a
+        /// `Disallow:` rule in a `robots.txt` file.
+        pub fn noindex_patterns() -> Vec<String> {
+            vec![#(#patterns.to_string()),*]
+        }
+    }
+}
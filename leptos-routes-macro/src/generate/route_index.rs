@@ -0,0 +1,71 @@
+use crate::path::{collect_patterns, pattern_ref, PathSegment, PathSegments};
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use proc_macro_error2::abort_call_site;
+use quote::quote;
+
+/// Generates the `route_index_page` component listing all routes, if requested with `route_index`.
+pub fn generate_route_index_page(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    if !args.route_index {
+        return quote! {};
+    }
+    if !args.with_views {
+        abort_call_site!("\"route_index\" renders a page listing all routes and requires \"with_views\". Add \"with_views\" or remove the argument.");
+    }
+    let patterns = collect_patterns(route_defs);
+    let entries = flatten(route_defs).filter(|route_def| !route_def.is_index).map(|route_def| {
+        let full_path = pattern_ref(&patterns, &route_def.full_path, 0);
+        match sample_path(route_def) {
+            Some(href) => quote! { <li><a href=#href>{#full_path}</a></li> },
+            None => quote! { <li>{#full_path}</li> },
+        }
+    });
+
+    quote! {
+        /// Lists all routes, linking to each route whose parameters have sample values declared
+        /// using `sample(...)`. Rendered at "/__routes" in debug builds.
+        pub fn route_index_page() -> impl ::leptos::IntoView {
+            use ::leptos::prelude::*;
+
+            view! {
+                <h1>"Routes"</h1>
+                <ul>
+                    #(#entries)*
+                </ul>
+            }
+        }
+    }
+}
+
+/// The full path of the given route with all parameters replaced by their sample values. Optional
+/// parameters without a sample value are left out. Returns `None` if a required parameter has no
+/// sample value.
+pub fn sample_path(route_def: &RouteDef) -> Option<String> {
+    let sample = |name: &str| {
+        route_def
+            .samples
+            .iter()
+            .rev()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    };
+    let mut path = String::new();
+    for segment in PathSegments::parse(&route_def.full_path).segments {
+        let value = match &segment {
+            PathSegment::Static(s) => Some(s.as_str()),
+            PathSegment::Param(name) | PathSegment::Wildcard(name) => Some(sample(name)?),
+            PathSegment::OptionalParam(name) => sample(name),
+        };
+        if let Some(value) = value {
+            path.push('/');
+            path.push_str(value);
+        }
+    }
+    if path.is_empty() {
+        path.push('/');
+    }
+    Some(path)
+}
@@ -0,0 +1,96 @@
+use crate::path::{PathSegment, PathSegments};
+use crate::route_def::{routing_roots, sort_by_match_order, RouteDef};
+use crate::route_macro_args::SsrModeArg;
+use crate::RoutesMacroArgs;
+use quote::quote;
+
+/// Generates the given `leptos_router::SsrMode`.
+pub fn ssr_mode_tokens(ssr_mode: SsrModeArg) -> proc_macro2::TokenStream {
+    match ssr_mode {
+        SsrModeArg::OutOfOrder => quote! { ::leptos_router::SsrMode::OutOfOrder },
+        SsrModeArg::PartiallyBlocked => quote! { ::leptos_router::SsrMode::PartiallyBlocked },
+        SsrModeArg::InOrder => quote! { ::leptos_router::SsrMode::InOrder },
+        SsrModeArg::Async => quote! { ::leptos_router::SsrMode::Async },
+        SsrModeArg::Static => quote! {
+            ::leptos_router::SsrMode::Static(::leptos_router::static_routes::StaticRoute::new())
+        },
+    }
+}
+
+pub fn generate_route_list(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let mut listings = Vec::new();
+    if args.route_index {
+        listings.push(quote! {
+            ::leptos_router::RouteListing::new(
+                [::leptos_router::PathSegment::Static("__routes".into())],
+                ::leptos_router::SsrMode::OutOfOrder,
+                [::leptos_router::Method::Get],
+                [],
+            )
+        });
+    }
+    // Routes declared with an absolute path are routed like top-level routes.
+    collect_listings(args, routing_roots(route_defs), false, &mut listings);
+
+    quote! {
+        /// The list of all paths rendered by the generated routes, together with their `SsrMode`,
+        /// as discovered by `leptos_router::RouteList::generate` when rendering the app. Pass it to
+        /// the server integration (like `leptos_axum` or `leptos_actix`) without rendering the app
+        /// to discover its routes.
+        pub fn generated_route_list_with_ssr() -> ::leptos_router::RouteList {
+            ::leptos_router::RouteList::from(vec![#(#listings),*])
+        }
+    }
+}
+
+/// Collects one listing for each route rendering a page: leaf routes, and routes with children
+/// rendering their (own or inherited) `fallback`. Routes are visited in the order they are
+/// matched in, like the generated router declares them.
+fn collect_listings(
+    args: &RoutesMacroArgs,
+    mut route_defs: Vec<&RouteDef>,
+    has_inherited_fallback: bool,
+    listings: &mut Vec<proc_macro2::TokenStream>,
+) {
+    sort_by_match_order(&mut route_defs);
+    for route_def in route_defs {
+        let children = route_def.nested_children();
+        if children.is_empty() {
+            listings.push(listing(route_def));
+            continue;
+        }
+        let has_fallback = route_def.fallback.is_some() || (args.inherit_fallback && has_inherited_fallback);
+        // An index route takes the place of any fallback.
+        let has_index = children.iter().any(|child| child.is_index);
+        collect_listings(args, children, has_fallback, listings);
+        if has_fallback && !has_index {
+            listings.push(listing(route_def));
+        }
+    }
+}
+
+fn listing(route_def: &RouteDef) -> proc_macro2::TokenStream {
+    let segments = PathSegments::parse(&route_def.full_path)
+        .segments
+        .into_iter()
+        .map(|segment| match segment {
+            PathSegment::Static(s) => quote! { ::leptos_router::PathSegment::Static(#s.into()) },
+            PathSegment::Param(name) => quote! { ::leptos_router::PathSegment::Param(#name.into()) },
+            PathSegment::OptionalParam(name) => {
+                quote! { ::leptos_router::PathSegment::OptionalParam(#name.into()) }
+            }
+            PathSegment::Wildcard(name) => quote! { ::leptos_router::PathSegment::Splat(#name.into()) },
+        });
+    let ssr_mode = ssr_mode_tokens(route_def.ssr_mode);
+    quote! {
+        ::leptos_router::RouteListing::new(
+            [#(#segments),*],
+            #ssr_mode,
+            [::leptos_router::Method::Get],
+            [],
+        )
+    }
+}
@@ -0,0 +1,159 @@
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// The amount of routes stored in each word of a `RouteSet`.
+const WORD_BITS: usize = u64::BITS as usize;
+
+pub fn generate_route_set(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let count = flatten(route_defs).count();
+    let words = count.div_ceil(WORD_BITS).max(1);
+    let index_arms = flatten(route_defs).enumerate().map(|(index, route_def)| {
+        let variant_name = route_def.variant_name();
+        quote! {
+            Route::#variant_name(_) => #index,
+        }
+    });
+
+    // Only the bits of existing routes are set in the full set, keeping `len()` exact.
+    let all_words = (0..words).map(|word| {
+        let bits = count.saturating_sub(word * WORD_BITS).min(WORD_BITS);
+        let mask = match bits {
+            WORD_BITS => u64::MAX,
+            bits => (1u64 << bits) - 1,
+        };
+        quote! { #mask }
+    });
+
+    // Routes are disabled unless all flags declared on them and their parents are enabled.
+    let flag_checks = flatten(route_defs)
+        .filter(|route_def| !route_def.flags.is_empty())
+        .map(|route_def| {
+            let variant_name = route_def.variant_name();
+            let path = route_def.full_module_path_to_struct_def();
+            let flags = &route_def.flags;
+            quote! {
+                if ![#(#flags),*].iter().all(|flag| flags.is_enabled(flag)) {
+                    routes = routes.without(Route::#variant_name(#path));
+                }
+            }
+        });
+
+    quote! {
+        /// The set of routes enabled by the given flags: all routes whose flags, declared using
+        /// `flag = "..."` on them or any of their parents, are enabled.
+        pub fn enabled_routes(flags: &::leptos_routes::flags::FlagSet) -> RouteSet {
+            let mut routes = RouteSet::ALL;
+            #(#flag_checks)*
+            routes
+        }
+
+        impl Route {
+            /// The number of declared routes.
+            pub const COUNT: usize = #count;
+
+            /// The position of this route in declaration order, being less than `Route::COUNT`.
+            pub const fn index(&self) -> usize {
+                match self {
+                    #(#index_arms)*
+                }
+            }
+        }
+
+        /// A set of routes, stored as one bit per route. Use it for lookups on every navigation,
+        /// like the routes allowed for a role, without allocating.
+        ///
+        /// ```ignore
+        /// const ADMIN_ROUTES: RouteSet = RouteSet::EMPTY.with(Route::RootAdmin(root::Admin));
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct RouteSet([u64; #words]);
+
+        impl RouteSet {
+            /// The set containing no routes.
+            pub const EMPTY: RouteSet = RouteSet([0; #words]);
+
+            /// The set containing all routes.
+            pub const ALL: RouteSet = RouteSet([#(#all_words),*]);
+
+            /// This set, additionally containing the given route.
+            pub const fn with(mut self, route: Route) -> Self {
+                let index = route.index();
+                self.0[index / 64] |= 1 << (index % 64);
+                self
+            }
+
+            /// This set, no longer containing the given route.
+            pub const fn without(mut self, route: Route) -> Self {
+                let index = route.index();
+                self.0[index / 64] &= !(1 << (index % 64));
+                self
+            }
+
+            /// Adds the given route, returning whether it was not contained before.
+            pub fn insert(&mut self, route: Route) -> bool {
+                let contained = self.contains(route);
+                *self = self.with(route);
+                !contained
+            }
+
+            /// Removes the given route, returning whether it was contained before.
+            pub fn remove(&mut self, route: Route) -> bool {
+                let contained = self.contains(route);
+                *self = self.without(route);
+                contained
+            }
+
+            pub const fn contains(&self, route: Route) -> bool {
+                let index = route.index();
+                self.0[index / 64] & (1 << (index % 64)) != 0
+            }
+
+            /// The number of routes contained.
+            pub const fn len(&self) -> usize {
+                let mut len = 0;
+                let mut word = 0;
+                while word < self.0.len() {
+                    len += self.0[word].count_ones() as usize;
+                    word += 1;
+                }
+                len
+            }
+
+            pub const fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// The set of routes contained in this or the other set.
+            pub const fn union(mut self, other: RouteSet) -> Self {
+                let mut word = 0;
+                while word < self.0.len() {
+                    self.0[word] |= other.0[word];
+                    word += 1;
+                }
+                self
+            }
+
+            /// The set of routes contained in both this and the other set.
+            pub const fn intersection(mut self, other: RouteSet) -> Self {
+                let mut word = 0;
+                while word < self.0.len() {
+                    self.0[word] &= other.0[word];
+                    word += 1;
+                }
+                self
+            }
+        }
+
+        impl FromIterator<Route> for RouteSet {
+            fn from_iter<I: IntoIterator<Item = Route>>(iter: I) -> Self {
+                iter.into_iter().fold(RouteSet::EMPTY, RouteSet::with)
+            }
+        }
+
+        impl Extend<Route> for RouteSet {
+            fn extend<I: IntoIterator<Item = Route>>(&mut self, iter: I) {
+                *self = iter.into_iter().fold(*self, RouteSet::with);
+            }
+        }
+    }
+}
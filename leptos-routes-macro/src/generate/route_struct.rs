@@ -1,95 +1,179 @@
-use crate::path::{ParamInfo, PathSegment, PathSegments};
-use crate::route_def::RouteDef;
-use crate::util::sanitize_identifier;
+use crate::path::{
+    collect_patterns, collect_static_segments, pattern_ref, OptionalParams, ParamInfo, PathSegment,
+    PathSegments,
+};
+use crate::route_def::{RouteDef, VariantsEnum};
+use crate::util::{sanitize_identifier, to_pascal_case};
+use crate::RoutesMacroArgs;
+use proc_macro_error2::abort;
 use quote::{format_ident, quote};
 
 // For the format string, we need to handle both:
 // 1. The original path segments from self.path() for static segments
 // 2. The function parameters for dynamic segments
 fn create_format(
-    segments: &PathSegments,
+    route_def: &RouteDef,
+    args: &RoutesMacroArgs,
     format_str: &mut String,
     format_args: &mut Vec<proc_macro2::TokenStream>,
-    has_parent_with_empty_path: bool,
 ) {
+    let segments = &route_def.path_segments;
+    if route_def.is_index || route_def.is_group {
+        // Index routes and groups share the path of their parent.
+        return;
+    }
     if segments.segments.is_empty() {
         format_str.push('/');
         return;
     }
+    let last = segments.segments.len() - 1;
     for (i, seg) in segments.segments.iter().enumerate() {
         let segment_var = format_ident!("segment_{}", i);
         match seg {
             PathSegment::Static(_) => {
-                if i == 0 && has_parent_with_empty_path {
-                    format_str.push_str("{}");
-                } else {
-                    format_str.push_str("/{}");
-                }
+                format_str.push_str("/{}");
                 format_args.push(quote! { ::leptos_router::AsPath::as_path(&(#segment_var).0) });
             }
-            PathSegment::Param(name) => {
-                if i == 0 && has_parent_with_empty_path {
-                    format_str.push_str("{}");
-                } else {
-                    format_str.push_str("/{}");
-                }
-                let name = format_ident!("{}", sanitize_identifier(name));
-                format_args.push(quote! { #name });
+            PathSegment::Param(name) | PathSegment::Wildcard(name) => {
+                format_str.push_str("/{}");
+                let ident = format_ident!("{}", sanitize_identifier(name));
+                format_args.push(route_def.param_type_for(name).display(quote!(#ident)));
             }
             PathSegment::OptionalParam(name) => {
                 format_str.push_str("{}");
+                let display = route_def.param_type_for(name).display(quote!(val));
                 let name = format_ident!("{}", sanitize_identifier(name));
+                let omitted = match args.optional_params {
+                    OptionalParams::KeepTrailingSlash if i == last => quote!(String::from("/")),
+                    _ => quote!(String::new()),
+                };
                 format_args.push(quote! {
                     if let Some(val) = #name {
-                        format!("/{}", val)
+                        format!("/{}", #display)
                     } else {
-                        String::new()
+                        #omitted
                     }
                 });
             }
-            PathSegment::Wildcard(name) => {
-                if i == 0 && has_parent_with_empty_path {
-                    format_str.push_str("{}");
-                } else {
-                    format_str.push_str("/{}");
-                }
-                let name = format_ident!("{}", sanitize_identifier(name));
-                format_args.push(quote! { #name });
-            }
         }
     }
 }
 
+/// Replaces an empty `materialized` path by "/". It can only be empty when all segments of the
+/// route are optional and given as `None`.
+fn or_root(
+    route_def: &RouteDef,
+    materialized: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let segments = &route_def.path_segments.segments;
+    let may_be_empty = !route_def.is_index
+        && !route_def.is_group
+        && !segments.is_empty()
+        && segments
+            .iter()
+            .all(|seg| matches!(seg, PathSegment::OptionalParam(_)));
+    if may_be_empty {
+        quote! {{
+            let path = #materialized;
+            if path.is_empty() { String::from("/") } else { path }
+        }}
+    } else {
+        materialized
+    }
+}
+
+/// Generates the struct of the given route and its implementation. With a `usage_index`, calls to
+/// `materialize` are recorded in the `__ROUTE_USAGE` registry of the root module.
 pub fn generate_route_struct(
     route_def: &RouteDef,
     route_defs: &[RouteDef],
+    args: &RoutesMacroArgs,
+    usage_index: Option<usize>,
 ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
     let struct_name = &route_def.name;
-    let path = &route_def.path;
     let vis = &route_def.vis;
 
+    let path = route_def.declared_path(args.case_insensitive);
+
     let path_segments = &route_def.path_segments;
     let path_segment_count = path_segments.segments.len();
     let path_type = path_segments.generate_path_type();
 
+    // Depth of the module this struct is generated in, relative to the root module.
+    let depth = route_def.found_in_module_path.without_first().len();
+
+    let variants_enums = route_def
+        .variants_enums
+        .iter()
+        .map(|variants_enum| generate_variants_enum(variants_enum, vis));
+
+    let must_use = usage_index.map(|_| quote!(#[must_use]));
+    let deprecated = route_def.deprecated_attr();
+    let owner = route_def.owner.as_ref().map(|owner| {
+        let owner = format!("Owned by `{owner}`.");
+        quote! {
+            #[doc = ""]
+            #[doc = #owner]
+        }
+    });
     let struct_def = quote! {
         #[doc = #path]
+        #owner
+        #deprecated
+        #must_use
         #[derive(Debug, Clone, Copy, PartialEq, Eq)]
         #vis struct #struct_name;
+
+        #(#variants_enums)*
+    };
+
+    // With usage tracking, `materialize` records its call and delegates to `__materialize`, which
+    // child routes use to materialize the path of their parent without recording it.
+    let (materialize_fn, materialize_vis) = match usage_index {
+        Some(_) => (format_ident!("__materialize"), quote!(#[doc(hidden)] pub)),
+        None => (format_ident!("materialize"), quote!(#deprecated pub)),
+    };
+
+    // In debug builds, the values of the route's own parameters are checked to form valid segments.
+    // Parameters of parent routes are checked when materializing the parent's path.
+    let full_path = pattern_ref(&collect_patterns(route_defs), &route_def.full_path, depth);
+    let value_checks = path_segments.segments.iter().filter_map(|seg| match seg {
+        PathSegment::Param(name) => {
+            let ident = format_ident!("{}", sanitize_identifier(name));
+            let display = route_def.param_type_for(name).display(quote!(#ident));
+            Some(quote! {
+                ::leptos_routes::params::assert_path_value(#full_path, #name, &#display);
+            })
+        }
+        PathSegment::OptionalParam(name) => {
+            let ident = format_ident!("{}", sanitize_identifier(name));
+            let display = route_def.param_type_for(name).display(quote!(val));
+            Some(quote! {
+                if let Some(val) = &#ident {
+                    ::leptos_routes::params::assert_path_value(#full_path, #name, &#display);
+                }
+            })
+        }
+        PathSegment::Static(_) | PathSegment::Wildcard(_) => None,
+    });
+    let value_checks = quote! {
+        if cfg!(debug_assertions) {
+            #(#value_checks)*
+        }
     };
 
     let struct_impl = match &route_def.parent_struct {
-        Some((parent_path, parent)) => {
+        Some((_, parent)) => {
             let all_params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
 
-            let params = all_params.iter().map(|p| {
-                let name = format_ident!("{}", sanitize_identifier(&p.name));
-                if p.is_optional {
-                    quote! { #name: Option<&str> }
-                } else {
-                    quote! { #name: &str }
-                }
-            });
+            let params = all_params
+                .iter()
+                .map(|p| {
+                    let name = p.ident();
+                    let ty = p.signature_type(depth);
+                    quote! { #name: #ty }
+                })
+                .collect::<Vec<_>>();
 
             let parent_params = all_params
                 .iter()
@@ -102,17 +186,24 @@ pub fn generate_route_struct(
                         )
                     })
                 })
-                .map(|p| format_ident!("{}", sanitize_identifier(&p.name)));
+                .map(|p| p.ident());
 
             let mut format_str = String::new();
             format_str.push_str("{}"); // Capturing the parent path!
             let mut format_args = Vec::new();
-            create_format(
-                path_segments,
-                &mut format_str,
-                &mut format_args,
-                parent_path.is_empty() || parent_path == "/",
+            create_format(route_def, args, &mut format_str, &mut format_args);
+            // Every segment brings its own slash, so the one ending the parent's path, like that of
+            // "/", is dropped. Index routes and groups keep the parent's path as it is.
+            let parent_path = if format_args.is_empty() && format_str == "{}" {
+                quote!(parent_path)
+            } else {
+                quote!(parent_path.strip_suffix('/').unwrap_or(&parent_path))
+            };
+            let materialized = or_root(
+                route_def,
+                quote!(format!(#format_str, #parent_path, #(#format_args),*)),
             );
+            let materialized = with_query_variant(route_def, materialized);
 
             let segment_vars = (0..path_segment_count).map(|i| format_ident!("segment_{}", i));
 
@@ -124,11 +215,13 @@ pub fn generate_route_struct(
 
                     // TODO add full_path
 
-                    pub fn materialize(&self, #(#params),*) -> String {
+                    #[track_caller]
+                    #materialize_vis fn #materialize_fn(&self, #(#params),*) -> String {
+                        #value_checks
                         let parent = super::#parent;
-                        let parent_path = parent.materialize(#(#parent_params),*);
+                        let parent_path = parent.#materialize_fn(#(#parent_params),*);
                         let (#(#segment_vars,)*) = self.path();
-                        format!(#format_str, parent_path, #(#format_args),*)
+                        #materialized
                     }
                 }
             }
@@ -141,29 +234,21 @@ pub fn generate_route_struct(
             let segment_vars = (0..path_segment_count).map(|i| format_ident!("segment_{}", i));
 
             // Collect parameters for dynamic segments
-            let params: Vec<_> = path_segments
-                .segments
+            let params: Vec<_> = ParamInfo::collect_params_through_hierarchy(route_defs, route_def)
                 .iter()
-                .filter_map(|seg| match seg {
-                    PathSegment::Param(name) => {
-                        let name = format_ident!("{}", sanitize_identifier(name));
-                        Some(quote! { #name: &str })
-                    }
-                    PathSegment::OptionalParam(name) => {
-                        let name = format_ident!("{}", sanitize_identifier(name));
-                        Some(quote! { #name: Option<&str> })
-                    }
-                    PathSegment::Wildcard(name) => {
-                        let name = format_ident!("{}", sanitize_identifier(name));
-                        Some(quote! { #name: &str })
-                    }
-                    PathSegment::Static(_) => None,
+                .map(|p| {
+                    let name = p.ident();
+                    let ty = p.signature_type(depth);
+                    quote! { #name: #ty }
                 })
                 .collect();
 
             let mut format_str = String::new();
             let mut format_args = Vec::new();
-            create_format(path_segments, &mut format_str, &mut format_args, false);
+            create_format(route_def, args, &mut format_str, &mut format_args);
+            let materialized =
+                or_root(route_def, quote!(format!(#format_str, #(#format_args),*)));
+            let materialized = with_query_variant(route_def, materialized);
 
             quote! {
                 impl #struct_name {
@@ -171,14 +256,440 @@ pub fn generate_route_struct(
                         ::leptos_router::path!(#path)
                     }
 
-                    pub fn materialize(&self, #(#params),*) -> String {
+                    #[track_caller]
+                    #materialize_vis fn #materialize_fn(&self, #(#params),*) -> String {
+                        #value_checks
                         let (#(#segment_vars,)*) = self.path();
-                        format!(#format_str, #(#format_args),*)
+                        #materialized
                     }
                 }
             }
         }
     };
 
+    let usage_impl = usage_index.map(|index| {
+        let all_params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+        let params = all_params.iter().map(|p| {
+            let name = p.ident();
+            let ty = p.signature_type(depth);
+            quote! { #name: #ty }
+        });
+        let param_idents = all_params.iter().map(|p| p.ident());
+        let supers = (0..depth).map(|_| quote!(super::));
+        quote! {
+            impl #struct_name {
+                #deprecated
+                #[must_use]
+                #[track_caller]
+                pub fn materialize(&self, #(#params),*) -> String {
+                    #(#supers)* __ROUTE_USAGE[#index].store(true, ::core::sync::atomic::Ordering::Relaxed);
+                    self.__materialize(#(#param_idents),*)
+                }
+            }
+        }
+    });
+    let materialize_into_impl =
+        generate_materialize_into_impl(args, route_def, route_defs, usage_index);
+    let const_impl = generate_const_impl(args, route_def, route_defs);
+    let matching_impl = generate_matching_impl(args, route_def, route_defs);
+    let query_impl = generate_query_impl(route_def, route_defs);
+    let redirect_impl = generate_redirect_impl(route_def, route_defs);
+    let specificity = route_def.specificity();
+    let priority = route_def.priority;
+    let struct_impl = quote! {
+        #struct_impl
+        #usage_impl
+        #materialize_into_impl
+        #const_impl
+        #matching_impl
+        #query_impl
+        #redirect_impl
+
+        impl #struct_name {
+            /// One score per segment of the full path of this route: 4 for static segments, 3 for
+            /// parameters, 1 for optional parameters and 0 for wildcards, followed by a 2 marking
+            /// the end of the path. Compared lexicographically, more specific routes have higher
+            /// specificities.
+            pub fn specificity(&self) -> &'static [u8] {
+                &[#(#specificity),*]
+            }
+
+            /// The priority declared for this route, overriding its specificity when ordering
+            /// routes for matching. Defaults to 0.
+            pub fn priority(&self) -> i32 {
+                #priority
+            }
+        }
+    };
+
     (struct_def, struct_impl)
 }
+
+/// Appends the query string of a query variant to the given materialized path.
+fn with_query_variant(
+    route_def: &RouteDef,
+    materialized: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match route_def.query_variant_suffix() {
+        Some(suffix) => quote! {{
+            let mut path = #materialized;
+            path.push_str(#suffix);
+            path
+        }},
+        None => materialized,
+    }
+}
+
+/// Generates the `MATERIALIZED_PATH` const and `materialize_const` function of the given route, if
+/// its full path consists of static segments only.
+fn generate_const_impl(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> Option<proc_macro2::TokenStream> {
+    if !ParamInfo::collect_params_through_hierarchy(route_defs, route_def).is_empty() {
+        return None;
+    }
+    let mut materialized = String::new();
+    for segment in &PathSegments::parse(&route_def.full_path).segments {
+        if let PathSegment::Static(segment) = segment {
+            materialized.push('/');
+            materialized.push_str(&materialized_static_segment(args, segment));
+        }
+    }
+    if materialized.is_empty() {
+        materialized.push('/');
+    }
+    materialized.extend(route_def.query_variant_suffix());
+    let struct_name = &route_def.name;
+    let deprecated = route_def.deprecated_attr();
+    Some(quote! {
+        impl #struct_name {
+            /// The path of this route, as returned by `materialize`, known at compile time as
+            /// the route has no parameters. Using it is not recorded by `deny_unused`.
+            #deprecated
+            pub const MATERIALIZED_PATH: &'static str = #materialized;
+
+            /// The path of this route, like `materialize`, for use in const contexts.
+            #deprecated
+            pub const fn materialize_const(&self) -> &'static str {
+                Self::MATERIALIZED_PATH
+            }
+        }
+    })
+}
+
+/// A static segment as `materialize` writes it, which is lowercased with `case_insensitive`.
+fn materialized_static_segment(args: &RoutesMacroArgs, segment: &str) -> String {
+    match args.case_insensitive {
+        true => segment.to_ascii_lowercase(),
+        false => segment.to_owned(),
+    }
+}
+
+/// Generates the `materialize_into` function of the given route, writing the same path as
+/// `materialize` to a `core::fmt::Write` without allocating it. It walks the full path of the
+/// route instead of delegating to its parent, as the parent's path may end in a slash the route's
+/// own segments replace. With a `usage_index`, calls are recorded like those of `materialize`.
+fn generate_materialize_into_impl(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+    usage_index: Option<usize>,
+) -> proc_macro2::TokenStream {
+    let struct_name = &route_def.name;
+    let depth = route_def.found_in_module_path.without_first().len();
+    let all_params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    let param_of = |name: &str| {
+        all_params
+            .iter()
+            .find(|p| p.name == name)
+            .expect("param to be collected")
+    };
+    let params = all_params.iter().map(|p| {
+        let name = p.ident();
+        let ty = p.signature_type(depth);
+        quote! { #name: #ty }
+    });
+
+    let full_path = pattern_ref(&collect_patterns(route_defs), &route_def.full_path, depth);
+    let value_checks = all_params.iter().filter(|p| !p.is_wildcard).map(|p| {
+        let name = &p.name;
+        let ident = p.ident();
+        if p.is_optional {
+            let display = p.ty.display(quote!(val));
+            quote! {
+                if let Some(val) = &#ident {
+                    ::leptos_routes::params::assert_path_value(#full_path, #name, &#display);
+                }
+            }
+        } else {
+            let display = p.ty.display(quote!(#ident));
+            quote! {
+                ::leptos_routes::params::assert_path_value(#full_path, #name, &#display);
+            }
+        }
+    });
+
+    let segments = PathSegments::parse(&route_def.full_path).segments;
+    let last = segments.len().saturating_sub(1);
+    // The path is only empty when all of its segments are optional and given as `None`.
+    let may_be_empty = !segments.is_empty()
+        && segments.iter().all(|seg| matches!(seg, PathSegment::OptionalParam(_)));
+    let writes = segments.iter().enumerate().map(|(i, seg)| {
+        let written = may_be_empty.then(|| quote!(written = true;));
+        match seg {
+            PathSegment::Static(segment) => {
+                let segment = format!("/{}", materialized_static_segment(args, segment));
+                quote! { out.write_str(#segment)?; }
+            }
+            PathSegment::Param(name) | PathSegment::Wildcard(name) => {
+                let ident = param_of(name).ident();
+                let display = param_of(name).ty.display(quote!(#ident));
+                quote! { ::core::write!(out, "/{}", #display)?; }
+            }
+            PathSegment::OptionalParam(name) => {
+                let ident = param_of(name).ident();
+                let display = param_of(name).ty.display(quote!(val));
+                let omitted = match args.optional_params {
+                    OptionalParams::KeepTrailingSlash if i == last => quote! {
+                        out.write_str("/")?;
+                        #written
+                    },
+                    _ => quote!(),
+                };
+                quote! {
+                    if let Some(val) = #ident {
+                        ::core::write!(out, "/{}", #display)?;
+                        #written
+                    } else {
+                        #omitted
+                    }
+                }
+            }
+        }
+    });
+    let (track_written, write_root) = match may_be_empty {
+        true => (
+            quote!(let mut written = false;),
+            quote! {
+                if !written {
+                    out.write_str("/")?;
+                }
+            },
+        ),
+        false if segments.is_empty() => (quote!(), quote!(out.write_str("/")?;)),
+        false => (quote!(), quote!()),
+    };
+
+    let write_query_variant = route_def
+        .query_variant_suffix()
+        .map(|suffix| quote! { out.write_str(#suffix)?; });
+
+    let record_usage = usage_index.map(|index| {
+        let supers = (0..depth).map(|_| quote!(super::));
+        quote! {
+            #(#supers)* __ROUTE_USAGE[#index].store(true, ::core::sync::atomic::Ordering::Relaxed);
+        }
+    });
+    let deprecated = route_def.deprecated_attr();
+    quote! {
+        impl #struct_name {
+            /// Writes the path of this route to `out`, like `materialize`, but without allocating
+            /// it. Only requires `core`, letting paths be written to fixed-size buffers.
+            #deprecated
+            #[track_caller]
+            pub fn materialize_into(
+                &self,
+                out: &mut impl ::core::fmt::Write,
+                #(#params),*
+            ) -> ::core::fmt::Result {
+                #record_usage
+                if cfg!(debug_assertions) {
+                    #(#value_checks)*
+                }
+                #track_written
+                #(#writes)*
+                #write_root
+                #write_query_variant
+                Ok(())
+            }
+        }
+    }
+}
+
+fn generate_matching_impl(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let struct_name = &route_def.name;
+    let match_path = args.match_path_fn();
+    let depth = route_def.found_in_module_path.without_first().len();
+    let pattern = PathSegments::parse(&route_def.full_path)
+        .generate_runtime_pattern(&collect_static_segments(route_defs), depth);
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    let type_checks = params.iter().filter_map(|p| p.type_check(depth));
+    let constraints = params
+        .iter()
+        .filter_map(|p| {
+            let name = &p.name;
+            p.constraint_check(quote!(value))
+                .map(|check| quote! { param(#name).is_none_or(|value| #check) })
+        })
+        .chain(type_checks)
+        .collect::<Vec<_>>();
+
+    // Query variants additionally match the query parameters distinguishing them.
+    let (query_check, ignored) = match route_def.query_variant.is_empty() {
+        true => (None, "any query string or hash fragment"),
+        false => {
+            let check = route_def.query_variant_check(quote!(path));
+            let ignored = "any hash fragment and query parameters other than those of this variant";
+            (Some(quote!(#check &&)), ignored)
+        }
+    };
+
+    if constraints.is_empty() {
+        let doc =
+            format!("Whether the given path, ignoring {ignored}, matches the full path of this route.");
+        return quote! {
+            impl #struct_name {
+                #[doc = #doc]
+                pub fn matches(&self, path: &str) -> bool {
+                    #query_check #match_path(#pattern, path).is_some()
+                }
+            }
+        };
+    }
+
+    let doc = format!(
+        "Whether the given path, ignoring {ignored}, matches the full path of this route while satisfying all parameter constraints."
+    );
+    quote! {
+        impl #struct_name {
+            #[doc = #doc]
+            pub fn matches(&self, path: &str) -> bool {
+                #query_check #match_path(#pattern, path)
+                    .is_some_and(|params| Self::satisfies_constraints(|name| params.get(name)))
+            }
+
+            /// Whether the parameter values returned by `param` satisfy all parameter constraints
+            /// and parameter types declared on this route and its parents. Absent parameters are
+            /// not checked.
+            pub fn satisfies_constraints<'a>(param: impl Fn(&str) -> Option<&'a str>) -> bool {
+                true #(&& #constraints)*
+            }
+        }
+    }
+}
+
+fn generate_query_impl(route_def: &RouteDef, route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let Some(query_struct) = &route_def.query_struct else {
+        return quote! {};
+    };
+    let struct_name = &route_def.name;
+    let depth = route_def.found_in_module_path.without_first().len();
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    let param_idents = params.iter().map(|p| p.ident()).collect::<Vec<_>>();
+    let param_types = params.iter().map(|p| p.signature_type(depth));
+
+    quote! {
+        impl #struct_name {
+            /// Materializes the path of this route, followed by the query string serialized from
+            /// `query`.
+            pub fn materialize_with_query(&self, #(#param_idents: #param_types,)* query: &#query_struct) -> String {
+                ::leptos_routes::query::append_query(self.materialize(#(#param_idents),*), query)
+            }
+
+            /// Parses the query string of this route, with or without its leading '?'.
+            pub fn parse_query(&self, query: &str) -> Option<#query_struct> {
+                ::leptos_routes::query::parse_query(query)
+            }
+        }
+    }
+}
+
+fn generate_redirect_impl(route_def: &RouteDef, route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let struct_name = &route_def.name;
+    let depth = route_def.found_in_module_path.without_first().len();
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    let param_idents = params.iter().map(|p| p.ident()).collect::<Vec<_>>();
+    let param_types = params.iter().map(|p| p.signature_type(depth));
+    let deprecated = route_def.deprecated_attr();
+
+    quote! {
+        impl #struct_name {
+            /// A redirect to the materialized path of this route, using `RedirectStatus::Found`.
+            /// Use `permanent()`, `temporary()` or `with_status(...)` to respond with another
+            /// status code.
+            #deprecated
+            pub fn redirect(&self, #(#param_idents: #param_types),*) -> ::leptos_routes::redirect::Redirect {
+                ::leptos_routes::redirect::Redirect::new(self.materialize(#(#param_idents),*))
+            }
+        }
+    }
+}
+
+fn generate_variants_enum(
+    variants_enum: &VariantsEnum,
+    vis: &syn::Visibility,
+) -> proc_macro2::TokenStream {
+    let enum_name = &variants_enum.name;
+    let values = &variants_enum.variants;
+    let variants = values
+        .iter()
+        .map(|value| {
+            let name = to_pascal_case(&value.value().replace(['-', ' ', '.'], "_"));
+            syn::parse_str::<syn::Ident>(&name).unwrap_or_else(|_| {
+                abort!(value.span(), "Cannot derive an enum variant name from \"{}\".", value.value())
+            })
+        })
+        .collect::<Vec<_>>();
+    let variant_count = variants.len();
+    let doc = format!(
+        "The allowed values of a route parameter: {}",
+        values
+            .iter()
+            .map(|value| format!("`{}`", value.value()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #enum_name {
+            #(#variants,)*
+        }
+
+        impl #enum_name {
+            /// All variants, in declaration order.
+            pub const ALL: [Self; #variant_count] = [#(Self::#variants),*];
+
+            /// The value representing this variant in a path.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #values,)*
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::core::str::FromStr for #enum_name {
+            type Err = ::leptos_routes::params::UnknownVariant;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#values => Ok(Self::#variants),)*
+                    _ => Err(::leptos_routes::params::UnknownVariant(s.to_owned())),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Attribute, Expr};
+
+/// Generates the doc strings of the root routes module rendering a table of all routes: their full
+/// paths, structs and, with `with_views`, the views and layouts they render. `cargo doc` then shows
+/// the whole routing surface on the page of the routes module.
+pub fn generate_route_table_doc(
+    args: &RoutesMacroArgs,
+    root_mod: &syn::Ident,
+    route_defs: &[RouteDef],
+) -> Vec<Attribute> {
+    let mut lines = vec![String::from("# Routes"), String::new()];
+    lines.extend(route_table_lines(args, root_mod, route_defs));
+
+    let mut attrs: Vec<Attribute> = vec![parse_quote!(#[doc = ""])];
+    attrs.extend(lines.iter().map(|line| -> Attribute { parse_quote!(#[doc = #line]) }));
+    if args.strip_debug {
+        attrs = attrs
+            .into_iter()
+            .map(|attr| {
+                let meta = attr.meta;
+                parse_quote!(#[cfg_attr(debug_assertions, #meta)])
+            })
+            .collect();
+    }
+    attrs
+}
+
+/// Generates the `ROUTE_TABLE` constant, holding the table of all routes documented on the routes
+/// module.
+pub fn generate_route_table_const(
+    args: &RoutesMacroArgs,
+    root_mod: &syn::Ident,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let table = route_table_lines(args, root_mod, route_defs).join("\n");
+    quote! {
+        /// The Markdown table of all routes shown in the documentation of this module, listing
+        /// their full paths, structs and, with `with_views`, the views and layouts they render.
+        pub const ROUTE_TABLE: &str = #table;
+    }
+}
+
+/// The lines of the Markdown table of all routes, starting with its header.
+fn route_table_lines(
+    args: &RoutesMacroArgs,
+    root_mod: &syn::Ident,
+    route_defs: &[RouteDef],
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    if args.with_views {
+        lines.push(String::from("| Pattern | Route | View | Layout |"));
+        lines.push(String::from("|---|---|---|---|"));
+    } else {
+        lines.push(String::from("| Pattern | Route |"));
+        lines.push(String::from("|---|---|"));
+    }
+    for route_def in flatten(route_defs) {
+        let struct_path = route_def.full_module_path_to_struct_def();
+        let struct_path = struct_path.to_token_stream().to_string().replace(' ', "");
+        // Doc strings of a module resolve links from its surrounding module.
+        let mut line = format!(
+            "| `{}` | [`{struct_path}`]({root_mod}::{struct_path}) |",
+            route_def.full_path
+        );
+        if args.with_views {
+            let view = match &route_def.panes {
+                Some(panes) => format!("{} \\| {}", code(&panes.list), code(&panes.detail)),
+                None => route_def.view.as_ref().or(route_def.fallback.as_ref()).map(code).unwrap_or_default(),
+            };
+            let layout = route_def.layout.as_ref().map(code).unwrap_or_default();
+            line.push_str(&format!(" {view} | {layout} |"));
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// The given view expression as inline code of a table cell.
+fn code(expr: &Expr) -> String {
+    let code = expr.to_token_stream().to_string().replace('|', "\\|");
+    format!("`{code}`")
+}
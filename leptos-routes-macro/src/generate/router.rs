@@ -1,14 +1,41 @@
-use crate::route_def::RouteDef;
-use crate::{ExprWrapper, RoutesMacroArgs};
-use proc_macro_error2::abort;
-use quote::quote;
+use crate::generate::route_list::ssr_mode_tokens;
+use crate::generate::telemetry::{enter_route_span, record_route_metrics};
+use crate::path::{collect_static_segments, ParamInfo, PathSegments};
+use crate::route_def::{
+    detail_param, find_parent_of, flatten, routing_roots, sort_by_match_order,
+    takes_unmatched_path, RouteDef,
+};
+use crate::route_macro_args::{PanesArgs, SsrModeArg};
+use crate::RoutesMacroArgs;
+use proc_macro_error2::{abort, abort_call_site};
+use quote::{format_ident, quote};
+use syn::Expr;
 
 pub fn maybe_generate_routes_component(
     args: &RoutesMacroArgs,
     route_defs: &[RouteDef],
 ) -> proc_macro2::TokenStream {
+    if args.base.is_some() && !args.with_router {
+        abort_call_site!("\"base\" is only used by the generated router. Add \"with_router\" or remove the argument.");
+    }
+    if args.on_not_found.is_some() && !args.with_views {
+        abort_call_site!("\"on_not_found\" is called before rendering the \"fallback\" of the generated routes and requires \"with_views\". Add \"with_views\" or remove the argument.");
+    }
+    if !args.captures.is_empty() && !args.with_views {
+        abort_call_site!("\"captures\" declares values bound in the views of the generated routes and requires \"with_views\". Add \"with_views\" or remove the argument.");
+    }
+    if args.preview_prefix.is_some() && !args.with_views {
+        abort_call_site!("\"preview_prefix\" registers previewable routes in the generated routes and requires \"with_views\". Add \"with_views\" or remove the argument.");
+    }
     if args.with_views {
-        generate_routes_component(route_defs, args.fallback.clone())
+        let routes = generate_routes_component(args, route_defs);
+        let router = generate_router_component(args);
+        quote! {
+            #routes
+            #router
+        }
+    } else if args.with_router {
+        abort_call_site!("\"with_router\" renders the generated routes and requires \"with_views\". Add \"with_views\" or remove the argument.");
     } else {
         quote! {
             /// Not implemented!
@@ -23,71 +50,271 @@ pub fn maybe_generate_routes_component(
 }
 
 pub fn generate_routes_component(
+    args: &RoutesMacroArgs,
     route_defs: &[RouteDef],
-    fallback: Option<ExprWrapper>,
 ) -> proc_macro2::TokenStream {
-    let fallback = fallback.expect("fallback is required").0;
+    let fallback = not_found_fallback(args);
 
     let mut ts = quote! {};
+    let mut into_routes_impls = Vec::new();
 
-    fn process_route_def(route_def: &RouteDef, ts: &mut proc_macro2::TokenStream) {
+    /// Generates the route definitions of the given route and all of its children, collecting an
+    /// `into_routes` implementation for each of them. The children of the `merged` routes are
+    /// registered as children of the given route. With `preview`, routes render their `preview`
+    /// views where declared and no implementations are collected.
+    fn process_route_def(
+        args: &RoutesMacroArgs,
+        route_def: &RouteDef,
+        route_defs: &[RouteDef],
+        inherited_fallback: Option<&Expr>,
+        into_routes_impls: &mut Vec<proc_macro2::TokenStream>,
+        preview: bool,
+        merged: &[&RouteDef],
+    ) -> proc_macro2::TokenStream {
         let full_path = &route_def.full_module_path_to_struct_def();
+        let ssr = ssr_prop(route_def);
+        let mut ts = quote! {};
 
-        if !route_def.children.is_empty() {
+        let mut children = route_def.nested_children();
+        children.extend(merged.iter().flat_map(|merged| merged.nested_children()));
+        if !children.is_empty() {
+            // Groups and merged routes render their children in place, unless declaring a layout.
+            let outlet: Option<Expr> = (route_def.is_group || route_def.merge)
+                .then(|| syn::parse_quote!(::leptos_router::components::Outlet));
             let layout = route_def
-                .layout
+                .preview
                 .as_ref()
-                .map(|v| quote! { view=#v })
+                .filter(|_| preview)
+                .or(route_def.layout.as_ref())
+                .or(outlet.as_ref())
+                .map(|v| {
+                    let v = bind_captures(args, route_def, v);
+                    let v = wrap_class(route_def, quote! { #v });
+                    let v = wrap_suspense(route_def, v);
+                    let v = wrap_error_boundary(route_def, v);
+                    let v = wrap_leave_guard(route_def, v);
+                    let v = wrap_guard(route_def, v);
+                    let v = wrap_deprecation(args, route_def, v);
+                    let v = wrap_flag(args, route_def, v);
+                    let v = wrap_constraints(args, route_def, v);
+                    quote! { view=#v }
+                })
                 .unwrap_or_else(|| abort! {
                     route_def.route_ident_span,
                     "Any #[route] with child routes requires a \"layout\" view! Set an optional \"fallback\" view to handle the immediate path. Remember to embed an `<Outlet />` in your \"layout\" view.`"
                 });
 
             ts.extend([quote! {
-                <ParentRoute path=#full_path.path() #layout>
+                <ParentRoute path=self::#full_path.path() #layout #ssr>
             }]);
             {
-                for child in &route_def.children {
-                    process_route_def(child, ts);
+                let own_or_inherited_fallback = if args.inherit_fallback {
+                    route_def.fallback.as_ref().or(inherited_fallback)
+                } else {
+                    route_def.fallback.as_ref()
+                };
+                // An index route takes the place of any fallback.
+                let has_index = children.iter().any(|child| child.is_index);
+                sort_by_match_order(&mut children);
+                // Leaf children next to a child kept alive are all rendered by one host route, so
+                // that navigating between them does not unmount the kept views.
+                let keeps_alive = children.iter().any(|child| child.keep_alive);
+                let (hosted, routed): (Vec<_>, Vec<_>) = children.into_iter().partition(|child| {
+                    keeps_alive && child.children.is_empty() && !child.modal
+                });
+                for (child, merged) in merge_registrations(routed) {
+                    ts.extend(process_route_def(
+                        args,
+                        child,
+                        route_defs,
+                        own_or_inherited_fallback,
+                        into_routes_impls,
+                        preview,
+                        &merged,
+                    ));
+                    collect_merged_into_routes(args, &merged, route_defs, into_routes_impls, preview);
+                }
+                for child in &hosted {
+                    // Only collects the `into_routes` implementation of the hosted child.
+                    process_route_def(
+                        args,
+                        child,
+                        route_defs,
+                        own_or_inherited_fallback,
+                        into_routes_impls,
+                        preview,
+                        &[],
+                    );
                 }
 
-                let fallback = route_def.fallback.as_ref().map(|v| quote! { view=#v });
-                if let Some(fallback) = fallback {
+                // A fallback taking the unmatched path also renders for any path below this route
+                // not matched by a child, even next to an index route.
+                let fallback = own_or_inherited_fallback
+                    .filter(|v| takes_unmatched_path(v) || !has_index)
+                    .map(|v| {
+                        let (path, v) = if takes_unmatched_path(v) {
+                            (quote!("*unmatched"), pass_unmatched_path(v))
+                        } else {
+                            (quote!(""), v.clone())
+                        };
+                        let v = bind_captures(args, route_def, &v);
+                        let v = wrap_view(args, route_def, route_defs, &v);
+                        quote! { path=::leptos_router::path!(#path) view=#v }
+                    });
+                if !hosted.is_empty() {
+                    // The host matches any path below this route, rendering the fallback itself.
+                    let fallback = own_or_inherited_fallback
+                        .filter(|v| takes_unmatched_path(v) || !has_index);
+                    let host = keep_alive_host(args, route_def, route_defs, &hosted, fallback, preview);
                     ts.extend([quote! {
-                        <Route path=::leptos_router::path!("") #fallback/>
+                        <Route path=::leptos_router::path!("*unmatched") view=#host #ssr/>
                     }]);
+                }
+                if let Some(fallback) = fallback {
+                    if hosted.is_empty() {
+                        ts.extend([quote! {
+                            <Route #fallback #ssr/>
+                        }]);
+                    }
                 } else if route_def.view.is_some() {
                     abort!(
                         route_def.view_span.expect("present"),
                         "Any #[route] with child routes requires a \"layout\" and an optional \"fallback\". \"view\" must only be set on leaf routes. Replace \"view\" with \"fallback\" or remove the argument."
                     );
+                } else if route_def.structured_data.is_some() && !has_index {
+                    abort!(
+                        route_def.structured_data_span.expect("present"),
+                        "\"structured_data\" on a #[route] with child routes is rendered alongside its \"fallback\" view. Add a \"fallback\" or remove the argument."
+                    );
                 }
             }
             ts.extend([quote! {
                 </ParentRoute>
             }]);
         } else {
-            let view = route_def
-                .view
-                .as_ref()
-                .map(|v| quote! { view=#v })
-                .unwrap_or_else(|| {
-                    abort! {
-                        route_def.route_ident_span,
-                        "Any leaf #[route] (without children) requires a \"view\"!"
-                    }
-                });
+            let view = leaf_view(args, route_def, route_defs, preview);
+            let view = quote! { view=#view };
 
-            ts.extend([quote! {
-                <Route path=#full_path.path() #view/>
-            }]);
+            if route_def.panes.is_some() {
+                // One route per number of trailing optional parameters present, as matching
+                // optional segments following other segments is unreliable.
+                for path in expand_trailing_optionals(&route_def.declared_path(args.case_insensitive)) {
+                    ts.extend([quote! {
+                        <Route path=::leptos_router::path!(#path) #view #ssr/>
+                    }]);
+                }
+            } else {
+                ts.extend([quote! {
+                    <Route path=self::#full_path.path() #view #ssr/>
+                }]);
+            }
         }
+
+        let ts = wrap_layout_stack(args, route_def, ts);
+
+        if preview {
+            return ts;
+        }
+        into_routes_impls.push(quote! {
+            impl #full_path {
+                /// The route definitions of this route and all of its child routes, for use in a
+                /// hand-written `<Routes>` or `<ParentRoute>`. They are declared with this route's own
+                /// path, so place them where the parent route of this route would be.
+                pub fn into_routes(self) -> impl ::leptos_router::MatchNestedRoutes + Clone + Send + 'static {
+                    use ::leptos_router::components::ParentRoute;
+                    use ::leptos_router::components::Route;
+                    use ::leptos::prelude::*;
+                    use super::*;
+
+                    view! { #ts }.into_inner()
+                }
+            }
+        });
+        ts
     }
 
-    for route_def in route_defs {
-        process_route_def(route_def, &mut ts);
+    /// Collects the `into_routes` implementations of the given routes registered together with
+    /// another route, whose child routes were already generated as part of that route.
+    fn collect_merged_into_routes(
+        args: &RoutesMacroArgs,
+        merged: &[&RouteDef],
+        route_defs: &[RouteDef],
+        into_routes_impls: &mut Vec<proc_macro2::TokenStream>,
+        preview: bool,
+    ) {
+        for route_def in merged {
+            // The implementation of the route itself is collected last, after those of its
+            // children.
+            let mut impls = Vec::new();
+            process_route_def(args, route_def, route_defs, None, &mut impls, preview, &[]);
+            into_routes_impls.extend(impls.pop());
+        }
     }
 
+    // The route index page is only rendered in debug builds.
+    if args.route_index {
+        ts.extend([quote! {
+            <Route
+                path=::leptos_router::path!("/__routes")
+                view=move || if cfg!(debug_assertions) {
+                    self::route_index_page().into_any()
+                } else {
+                    (#fallback)().into_any()
+                }
+            />
+        }]);
+    }
+
+    // Previewable subtrees are registered a second time under the preview prefix, matched first.
+    for route_def in previewable_roots(args, route_defs) {
+        let prefix = args.preview_prefix.as_deref().expect("validated");
+        let parent_path = find_parent_of(route_defs, route_def)
+            .filter(|_| !route_def.is_absolute)
+            .map_or("", |parent| parent.full_path.as_str());
+        let path = format!("{prefix}{}", parent_path.trim_end_matches('/'));
+        let subtree =
+            process_route_def(args, route_def, route_defs, None, &mut into_routes_impls, true, &[]);
+        ts.extend([quote! {
+            <ParentRoute path=::leptos_router::path!(#path) view=::leptos_router::components::Outlet>
+                #subtree
+            </ParentRoute>
+        }]);
+    }
+
+    // Routes declared with an absolute path are routed like top-level routes.
+    let mut ordered = routing_roots(route_defs);
+    sort_by_match_order(&mut ordered);
+    for (route_def, merged) in merge_registrations(ordered) {
+        ts.extend(process_route_def(
+            args,
+            route_def,
+            route_defs,
+            None,
+            &mut into_routes_impls,
+            false,
+            &merged,
+        ));
+        collect_merged_into_routes(args, &merged, route_defs, &mut into_routes_impls, false);
+    }
+
+    // Modal routes render on top of the sibling route rendered before them, tracked by a
+    // `ModalBackground` shared by all routes.
+    let has_modals = flatten(route_defs).any(|route_def| route_def.modal);
+    let modal_background = has_modals.then(generate_modal_background);
+    let provide_modal_background = has_modals.then(|| {
+        quote! {
+            if use_context::<self::ModalBackground>().is_none() {
+                provide_context(self::ModalBackground::new());
+            }
+        }
+    });
+
+    let view_path_assertions = generate_view_path_assertions(route_defs);
+
+    // The previous location is tracked for `back_or`.
+    let track_locations =
+        cfg!(feature = "back").then(|| quote! { ::leptos_routes::back::track_locations(); });
+
     quote! {
         pub fn generated_routes() -> impl ::leptos::IntoView {
             use ::leptos_router::components::Routes;
@@ -98,11 +325,866 @@ pub fn generate_routes_component(
             // surrounding module.
             use super::*;
 
+            #view_path_assertions
+
+            #provide_modal_background
+            #track_locations
             view! {
                 <Routes fallback=#fallback>
                     #ts
                 </Routes>
             }
         }
+
+        /// Renders all routes like `generated_routes()`, adapted to the given configuration. Its
+        /// flags are provided as context to the routes declaring a `flag`.
+        pub fn generated_routes_with(
+            config: ::leptos_routes::config::RoutesConfig,
+        ) -> impl ::leptos::IntoView {
+            if let Some(flags) = config.flags {
+                ::leptos::prelude::provide_context(flags);
+            }
+            generated_routes()
+        }
+
+        #(#into_routes_impls)*
+
+        #modal_background
+    }
+}
+
+/// Generates one assertion per view, layout and fallback given as a bare path, like "Users",
+/// referencing the path with the span of its string literal. A typo in a component name then
+/// errors at the `#[route(...)]` attribute instead of deep inside the generated `view!`.
+fn generate_view_path_assertions(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let assertions = flatten(route_defs)
+        .flat_map(|route_def| [&route_def.view, &route_def.layout, &route_def.fallback])
+        .filter_map(|expr| match expr {
+            Some(Expr::Path(expr))
+                if expr.qself.is_none()
+                    && expr.path.segments.iter().all(|segment| segment.arguments.is_none()) =>
+            {
+                Some(quote! { const _: fn() = || { let _ = #expr; }; })
+            }
+            _ => None,
+        });
+    quote! { #(#assertions)* }
+}
+
+/// The routes declared `previewable` that are registered under the `preview_prefix`, being those not
+/// already registered as part of the subtree of a previewable parent. Aborts on `preview` views
+/// declared outside of previewable subtrees.
+fn previewable_roots<'a>(args: &RoutesMacroArgs, route_defs: &'a [RouteDef]) -> Vec<&'a RouteDef> {
+    fn collect<'a>(route_def: &'a RouteDef, covered: bool, roots: &mut Vec<&'a RouteDef>) {
+        let covered = covered && !route_def.is_absolute;
+        if route_def.previewable && !covered {
+            roots.push(route_def);
+        }
+        if let (Some(span), false) = (route_def.preview_span, covered || route_def.previewable) {
+            abort!(
+                span,
+                "A \"preview\" view is rendered under the \"preview_prefix\", but this route is not part of a \"previewable\" subtree. Declare this route or one of its parents \"previewable\" or remove the argument."
+            );
+        }
+        for child in &route_def.children {
+            collect(child, covered || route_def.previewable, roots);
+        }
+    }
+
+    let mut roots = Vec::new();
+    for route_def in route_defs {
+        collect(route_def, false, &mut roots);
+    }
+    match (&args.preview_prefix, roots.first()) {
+        (Some(_), None) => abort_call_site!("\"preview_prefix\" registers the subtrees of \"previewable\" routes a second time, but no route is previewable. Declare routes \"previewable\" or remove the argument."),
+        (None, Some(route_def)) => abort!(
+            route_def.route_ident_span,
+            "\"previewable\" registers the subtree of this route under the \"preview_prefix\", which is not set. Add \"preview_prefix\" to #[routes(...)] or remove the argument."
+        ),
+        (Some(prefix), _) if !prefix.starts_with('/') || prefix.len() < 2 || prefix.ends_with('/') => {
+            abort_call_site!("\"preview_prefix\" must be a path like \"/preview\", starting with a '/' and not ending with one.")
+        }
+        _ => roots,
+    }
+}
+
+/// Groups the given sibling routes, in match order, into their registrations: each route paired with
+/// the routes registered together with it, being the other routes of the same full path declaring
+/// `merge`. The route declaring the layout of a merged group, or else its first route, is
+/// registered with the children of all of them.
+fn merge_registrations(siblings: Vec<&RouteDef>) -> Vec<(&RouteDef, Vec<&RouteDef>)> {
+    let mut registrations: Vec<(&RouteDef, Vec<&RouteDef>)> = Vec::new();
+    for route_def in siblings {
+        let group = registrations.iter_mut().find(|(registered, _)| {
+            route_def.merge && registered.merge && registered.full_path == route_def.full_path
+        });
+        match group {
+            Some((registered, merged)) => {
+                if route_def.layout.is_some() && registered.layout.is_none() {
+                    merged.push(std::mem::replace(registered, route_def));
+                } else {
+                    merged.push(route_def);
+                }
+            }
+            None => registrations.push((route_def, Vec::new())),
+        }
+    }
+    for (route_def, merged) in &registrations {
+        if route_def.merge && merged.is_empty() {
+            abort!(
+                route_def.route_ident_span,
+                "\"merge\" registers this route together with the other routes of the path \"{}\" declaring it, but there are none. Declare \"merge\" on them or remove the argument.",
+                route_def.full_path
+            );
+        }
+        if let Some(merged) = merged.iter().find(|m| m.layout.is_some() || m.fallback.is_some()) {
+            abort!(
+                merged.route_ident_span,
+                "Routes declaring \"merge\" are registered once, with the \"layout\" and \"fallback\" of only one of them. Remove the arguments from all other routes of the path \"{}\".",
+                merged.full_path
+            );
+        }
+    }
+    registrations
+}
+
+/// Generates the `ModalBackground` type, tracking the sibling route rendered behind modal routes.
+fn generate_modal_background() -> proc_macro2::TokenStream {
+    quote! {
+        /// The route rendered behind the routes declared `modal`: the sibling route rendered last
+        /// before navigating to a modal route. Provided as context by `generated_routes()`.
+        /// Provide one yourself to share it across routers.
+        #[derive(Debug, Clone, Copy)]
+        pub struct ModalBackground(::leptos::prelude::RwSignal<Option<&'static str>>);
+
+        impl ModalBackground {
+            /// A background tracking no route yet, rendering modal routes on their own.
+            #[allow(clippy::new_without_default)]
+            pub fn new() -> Self {
+                Self(::leptos::prelude::RwSignal::new(None))
+            }
+
+            /// Records the route with the given `data-route` identifier as the background.
+            pub fn record(&self, route: &'static str) {
+                use ::leptos::prelude::Set;
+                self.0.set(Some(route));
+            }
+
+            /// The `data-route` identifier of the route rendered last before the modal route.
+            pub fn route(&self) -> Option<&'static str> {
+                use ::leptos::prelude::GetUntracked;
+                self.0.get_untracked()
+            }
+        }
+    }
+}
+
+/// Generates a `generated_router()` function rendering the generated routes inside a `<Router>`,
+/// when requested with `with_router`.
+fn generate_router_component(args: &RoutesMacroArgs) -> proc_macro2::TokenStream {
+    if !args.with_router {
+        return quote! {};
+    }
+    let base = args.base.as_ref().map(|base| quote! { base=#base });
+    let default_base = match &args.base {
+        Some(base) => quote! { #base },
+        None => quote! { "" },
+    };
+    quote! {
+        /// Renders all routes inside a `<Router>`, ready to be used as the root of an app.
+        pub fn generated_router() -> impl ::leptos::IntoView {
+            use ::leptos_router::components::Router;
+            use ::leptos::prelude::*;
+
+            view! {
+                <Router #base>
+                    {generated_routes()}
+                </Router>
+            }
+        }
+
+        /// Renders all routes inside a `<Router>` like `generated_router()`, adapted to the given
+        /// configuration. Its base path overrides the declared `base`.
+        pub fn generated_router_with(
+            config: ::leptos_routes::config::RoutesConfig,
+        ) -> impl ::leptos::IntoView {
+            use ::leptos_router::components::Router;
+            use ::leptos::prelude::*;
+
+            let base = config
+                .base
+                .clone()
+                .unwrap_or(::std::borrow::Cow::Borrowed(#default_base));
+            view! {
+                <Router base=base>
+                    {generated_routes_with(config)}
+                </Router>
+            }
+        }
+    }
+}
+
+/// Generates a transparent component rendering the route definitions of the given route with
+/// children and all of its child routes, named like "UsersRoutes". Use it inside a hand-written
+/// `<Routes>` to embed only a subtree of the declared routes.
+pub fn generate_subtree_routes_component(route_def: &RouteDef) -> proc_macro2::TokenStream {
+    if route_def.nested_children().is_empty() {
+        return quote! {};
+    }
+    let struct_name = &route_def.name;
+    let vis = &route_def.vis;
+    let component_name = format_ident!("{}Routes", struct_name);
+    let doc = format!(
+        "The route definitions of the `{}` route and all of its child routes.",
+        route_def.full_path
+    );
+    quote! {
+        #[doc = #doc]
+        #[::leptos::component(transparent)]
+        #vis fn #component_name() -> impl ::leptos_router::MatchNestedRoutes + Clone {
+            #struct_name.into_routes()
+        }
+    }
+}
+
+/// The `ssr` property of the given route's definitions, if it is not rendered using the default
+/// `SsrMode::OutOfOrder`.
+fn ssr_prop(route_def: &RouteDef) -> proc_macro2::TokenStream {
+    if route_def.ssr_mode == SsrModeArg::default() {
+        return quote! {};
+    }
+    let ssr_mode = ssr_mode_tokens(route_def.ssr_mode);
+    quote! { ssr=#ssr_mode }
+}
+
+/// The general `fallback`, rendered for paths no route matches. With `on_not_found`, the handler is
+/// called with the unmatched URL, including any query string, before the fallback is rendered.
+fn not_found_fallback(args: &RoutesMacroArgs) -> proc_macro2::TokenStream {
+    let fallback = &args.fallback.as_ref().expect("fallback is required").0;
+    let Some(on_not_found) = &args.on_not_found else {
+        return quote! { #fallback };
+    };
+    let on_not_found = &on_not_found.0;
+    quote! {
+        move || {
+            let location = ::leptos_router::hooks::use_location();
+            let url = location.pathname.get_untracked()
+                + &location.query.with_untracked(|query| query.to_query_string());
+            (#on_not_found)(&url);
+            (#fallback)()
+        }
+    }
+}
+
+/// Wraps the given fallback taking the unmatched path into a view function, reading the path from
+/// the "unmatched" wildcard parameter of the fallback's route.
+fn pass_unmatched_path(fallback: &Expr) -> Expr {
+    syn::parse_quote! {
+        move || {
+            let path = ::leptos_router::hooks::use_params_map()
+                .with_untracked(|params| params.get("unmatched").unwrap_or_default());
+            (#fallback)(path)
+        }
+    }
+}
+
+/// Wraps the view rendered for the given route with any per-route additions, like structured data,
+/// records the route with `route_recorder` and sets its `data-route` attribute with `data_route`.
+fn wrap_view(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+    view: &Expr,
+) -> proc_macro2::TokenStream {
+    let mut setup = Vec::new();
+    let mut prepended = Vec::new();
+
+    if let Some(structured_data) = &route_def.structured_data {
+        let args = ParamInfo::collect_params_through_hierarchy(route_defs, route_def)
+            .iter()
+            .map(|p| p.read_from_params_map(0))
+            .collect::<Vec<_>>();
+        // Parameters are taken from the URL, so escape every `<` to keep values like "</script>"
+        // from closing the script element. JSON strings may contain the escaped form. Nothing is
+        // rendered into it when a parameter does not parse.
+        setup.push(quote! {
+            let params = ::leptos_router::hooks::use_params_map();
+            let structured_data = move || {
+                params
+                    .with(|params| Some(#structured_data(#(#args?),*)))
+                    .map(|structured_data| structured_data.replace('<', "\\u003c"))
+                    .unwrap_or_default()
+            };
+        });
+        prepended.push(quote! {
+            <script type="application/ld+json" inner_html=structured_data></script>
+        });
+    }
+
+    if args.noindex_meta && route_def.noindex {
+        prepended.push(quote! {
+            <meta name="robots" content="noindex"/>
+        });
+    }
+
+    if let Some(enter_span) = enter_route_span(args, route_def, route_defs) {
+        setup.push(enter_span);
+    }
+
+    if let Some(record_metrics) = record_route_metrics(route_def, route_defs) {
+        setup.push(record_metrics);
+    }
+
+    if args.route_recorder {
+        let variant_name = route_def.variant_name();
+        let struct_path = route_def.full_module_path_to_struct_def();
+        setup.push(quote! {
+            if let Some(recorder) = use_context::<self::RouteRecorder>() {
+                recorder.record(self::Route::#variant_name(self::#struct_path));
+            }
+        });
+    }
+
+    let rendered = if args.data_route {
+        let id = route_def.data_route_id();
+        quote! {
+            ::leptos::tachys::view::add_attr::AddAnyAttr::add_any_attr(
+                (#view)(),
+                ::leptos::tachys::html::attribute::custom::custom_attribute("data-route", #id),
+            )
+        }
+    } else if setup.is_empty() && prepended.is_empty() {
+        return quote! { #view };
+    } else {
+        quote! { (#view)() }
+    };
+    quote! {
+        move || {
+            #(#setup)*
+            view! {
+                #(#prepended)*
+                {#rendered}
+            }
+        }
+    }
+}
+
+/// Wraps the given layout or view in a `<div>` with the CSS class declared on the given route, so
+/// that stylesheets can theme the whole section rendered by the route.
+fn wrap_class(route_def: &RouteDef, view: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let Some(class) = &route_def.class else {
+        return view;
+    };
+    quote! {
+        move || view! {
+            <div class=#class>{(#view)()}</div>
+        }
+    }
+}
+
+/// Binds the values the given route `captures` around the given view: each value is evaluated
+/// once where the route is declared and cloned every time the view renders, so that the view can
+/// move its own copy into closures.
+fn bind_captures(args: &RoutesMacroArgs, route_def: &RouteDef, view: &Expr) -> Expr {
+    if route_def.captures.is_empty() {
+        return view.clone();
+    }
+    let values = route_def.captures.iter().map(|name| {
+        let Some(value) = args.captures.get(&name.to_string()) else {
+            let mut declared = args.captures.keys().cloned().collect::<Vec<_>>();
+            declared.sort();
+            abort!(
+                name.span(),
+                "Unknown capture: \"{}\". Declare it using `#[routes(captures({} = \"...\"))]`. Declared captures: {:?}.",
+                name,
+                name,
+                declared
+            );
+        };
+        &value.0
+    });
+    let names = &route_def.captures;
+    syn::parse_quote! {
+        {
+            #(#[allow(unused_variables)] let #names = #values;)*
+            move || {
+                #(#[allow(unused_variables)] let #names = ::core::clone::Clone::clone(&#names);)*
+                (#view)()
+            }
+        }
+    }
+}
+
+/// Wraps the given route definitions in one path-less `<ParentRoute>` for each layout of the stack
+/// declared using `layout_stack`, the first layout being the outermost.
+fn wrap_layout_stack(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    ts: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let Some(name) = &route_def.layout_stack else {
+        return ts;
+    };
+    let Some(layouts) = args.layout_stacks.get(&name.value()) else {
+        let mut declared = args.layout_stacks.keys().cloned().collect::<Vec<_>>();
+        declared.sort();
+        abort!(
+            name.span(),
+            "Unknown layout stack: \"{}\". Declare it using `#[routes(layout_stacks({}(\"Layout\", ...)))]`. Declared stacks: {:?}.",
+            name.value(),
+            name.value(),
+            declared
+        );
+    };
+    layouts.0.iter().rev().fold(ts, |ts, layout| {
+        quote! {
+            <ParentRoute path=() view=#layout>
+                #ts
+            </ParentRoute>
+        }
+    })
+}
+
+/// Renders the given route's `suspense_fallback` while the given view is loading, using a
+/// `<Transition>` instead of a `<Suspense>` when the route is marked with `transition`.
+fn wrap_suspense(route_def: &RouteDef, view: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    if route_def.transition && route_def.suspense_fallback.is_none() {
+        abort!(
+            route_def.route_ident_span,
+            "\"transition\" requires a \"suspense_fallback\", rendered while the route is loaded initially. Add a \"suspense_fallback\" or remove the argument."
+        );
+    }
+    let Some(suspense_fallback) = &route_def.suspense_fallback else {
+        return view;
+    };
+    if route_def.transition {
+        quote! {
+            move || view! {
+                <Transition fallback=#suspense_fallback>
+                    {(#view)()}
+                </Transition>
+            }
+        }
+    } else {
+        quote! {
+            move || view! {
+                <Suspense fallback=#suspense_fallback>
+                    {(#view)()}
+                </Suspense>
+            }
+        }
+    }
+}
+
+/// Renders the given route's `error` view instead of the given view, while the view renders any
+/// errors. Applies to the layout of routes with children, so that errors of child routes are caught.
+fn wrap_error_boundary(
+    route_def: &RouteDef,
+    view: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let Some(error) = &route_def.error else {
+        return view;
+    };
+    quote! {
+        move || view! {
+            <ErrorBoundary fallback=#error>
+                {(#view)()}
+            </ErrorBoundary>
+        }
+    }
+}
+
+/// Asks for confirmation before leaving the given route while its `leave_guard` returns `true`.
+/// Applies to the layout of routes with children, so that the guard spans all of their child routes:
+/// links to any path matched by the route or one of its child routes do not ask for confirmation.
+fn wrap_leave_guard(
+    route_def: &RouteDef,
+    view: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let Some(leave_guard) = &route_def.leave_guard else {
+        return view;
+    };
+    let guarded = std::iter::once(route_def)
+        .chain(flatten(&route_def.children))
+        .map(RouteDef::full_module_path_to_struct_def);
+    quote! {
+        move || {
+            ::leptos_routes::guard::use_leave_guard(
+                #leave_guard,
+                |path: &str| #(self::#guarded.matches(path))||*,
+            );
+            (#view)()
+        }
+    }
+}
+
+/// Renders the given view only once the `guard` of the given route resolves to `true`, redirecting
+/// to its `guard_redirect` otherwise, passing the attempted path and query string in the `next`
+/// query parameter. Its `guard_fallback` is rendered while the guard is pending.
+/// The guard runs whenever the route is rendered, as a `Resource` resolved on the server during
+/// SSR. Applies to the layout of routes with children, so that the guard spans all of their child
+/// routes.
+fn wrap_guard(route_def: &RouteDef, view: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let Some(guard) = &route_def.guard else {
+        return view;
+    };
+    let redirect = route_def.guard_redirect.as_ref().expect("validated");
+    let separator = if redirect.value().contains('?') { "&" } else { "?" };
+    let redirect = format!("{}{separator}next=", redirect.value());
+    let fallback = route_def
+        .guard_fallback
+        .as_ref()
+        .map(|fallback| quote! { fallback=#fallback });
+    quote! {
+        move || {
+            use ::leptos_router::components::Redirect;
+            let allowed = ::leptos::prelude::Resource::new(|| (), move |_| (#guard)());
+            let location = ::leptos_router::hooks::use_location();
+            let (pathname, query) = (location.pathname, location.query);
+            view! {
+                <Suspense #fallback>
+                    {move || Suspend::new(async move {
+                        if allowed.await {
+                            (#view)().into_any()
+                        } else {
+                            let next = pathname.get_untracked()
+                                + &query.with_untracked(|query| query.to_query_string());
+                            let path = format!(
+                                "{}{}",
+                                #redirect,
+                                ::leptos_router::location::Url::escape(&next)
+                            );
+                            view! { <Redirect path=path/> }.into_any()
+                        }
+                    })}
+                </Suspense>
+            }
+        }
+    }
+}
+
+/// Logs a warning whenever the given route is rendered, if it is deprecated and `log_deprecated`
+/// is set. Applies to the layout of routes with children, so that it spans all of their child routes.
+fn wrap_deprecation(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    view: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let Some(note) = route_def.deprecated.as_ref().filter(|_| args.log_deprecated) else {
+        return view;
+    };
+    let message = match note {
+        Some(note) => format!("Route \"{}\" is deprecated: {}", route_def.full_path, note),
+        None => format!("Route \"{}\" is deprecated.", route_def.full_path),
+    };
+    quote! {
+        move || {
+            ::leptos::logging::warn!("{}", #message);
+            (#view)()
+        }
+    }
+}
+
+/// Renders the `bot_view` of the given route instead of the given view, when the `IsBot` provided
+/// as context marks the request as made by a bot.
+fn with_bot_view(route_def: &RouteDef, view: &Expr) -> Expr {
+    let Some(bot_view) = &route_def.bot_view else {
+        return view.clone();
+    };
+    syn::parse_quote! {
+        move || {
+            let human = move || {
+                !use_context::<::leptos_routes::bot::IsBot>().is_some_and(|is_bot| is_bot.0)
+            };
+            view! {
+                <Show when=human fallback=#bot_view>
+                    {(#view)()}
+                </Show>
+            }
+        }
+    }
+}
+
+/// The view rendered for the given leaf route, wrapped with all of its per-route additions. With
+/// `preview`, its `preview` view is rendered instead, if declared.
+fn leaf_view(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+    preview: bool,
+) -> proc_macro2::TokenStream {
+    let panes = route_def.panes.as_ref().map(|panes| split_panes(route_def, panes));
+    let view = route_def.preview.as_ref().filter(|_| preview);
+    let Some(view) = view.or(route_def.view.as_ref()).or(panes.as_ref()) else {
+        abort! {
+            route_def.route_ident_span,
+            "Any leaf #[route] (without children) requires a \"view\"!"
+        }
+    };
+    let v = with_bot_view(route_def, view);
+    let v = bind_captures(args, route_def, &v);
+    let v = with_modal_background(route_defs, route_def, &v);
+    let v = wrap_view(args, route_def, route_defs, &v);
+    let v = wrap_class(route_def, v);
+    let v = wrap_suspense(route_def, v);
+    let v = wrap_error_boundary(route_def, v);
+    let v = wrap_leave_guard(route_def, v);
+    let v = wrap_guard(route_def, v);
+    let v = wrap_deprecation(args, route_def, v);
+    let v = wrap_flag(args, route_def, v);
+    wrap_constraints(args, route_def, v)
+}
+
+/// Renders the given leaf children of a route, when any of them is declared `keep_alive`, from one
+/// host route matching any path below the route. Views kept alive stay mounted, hidden while
+/// another child is rendered, once their route was visited. Each child reads the parameters of its
+/// own path, which keep their last values while the child is hidden. Paths not matching any child
+/// render the given fallback of the route, or the general `fallback`. With `preview`, the host
+/// renders the `preview` views of the children.
+fn keep_alive_host(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+    hosted: &[&RouteDef],
+    fallback: Option<&Expr>,
+    preview: bool,
+) -> proc_macro2::TokenStream {
+    let match_path = args.match_path_fn();
+    // Under the `preview_prefix`, children are matched against the path without the prefix.
+    let unprefixed = match (preview, &args.preview_prefix) {
+        (true, Some(prefix)) => quote!(path.strip_prefix(#prefix).unwrap_or(path)),
+        _ => quote!(path),
+    };
+    let static_segments = collect_static_segments(route_defs);
+    let patterns = hosted
+        .iter()
+        .map(|child| PathSegments::parse(&child.full_path).generate_runtime_pattern(&static_segments, 0))
+        .collect::<Vec<_>>();
+    let positions = (0..hosted.len()).collect::<Vec<_>>();
+    let views = hosted.iter().zip(&patterns).enumerate().map(|(i, (child, pattern))| {
+        let view = leaf_view(args, child, route_defs, preview);
+        let visited = child.keep_alive.then(|| {
+            quote! {
+                let visited = Memo::new(move |visited: Option<&bool>| {
+                    visited.copied().unwrap_or(false) || active()
+                });
+            }
+        });
+        let rendered = quote! {
+            <Provider value=params.clone()>{(#view)()}</Provider>
+        };
+        let rendered = if child.keep_alive {
+            quote! {
+                <Show when=move || visited.get()>
+                    <div style:display=move || if active() { "contents" } else { "none" }>
+                        #rendered
+                    </div>
+                </Show>
+            }
+        } else {
+            quote! { <Show when=active>#rendered</Show> }
+        };
+        quote! {
+            {
+                let params = ArcMemo::new(move |previous: Option<&::leptos_router::params::ParamsMap>| {
+                    path.with(|path| match #match_path(#pattern, #unprefixed) {
+                        Some(params) => params.iter().collect(),
+                        None => previous.cloned().unwrap_or_default(),
+                    })
+                });
+                let active = move || matched.get() == Some(#i);
+                #visited
+                view! { #rendered }
+            }
+        }
+    });
+    let global_fallback = not_found_fallback(args);
+    let fallback = match fallback {
+        Some(v) if takes_unmatched_path(v) => {
+            let v = bind_captures(args, route_def, &pass_unmatched_path(v));
+            let v = wrap_view(args, route_def, route_defs, &v);
+            quote! { (#v)().into_any() }
+        }
+        Some(v) => {
+            let v = bind_captures(args, route_def, v);
+            let v = wrap_view(args, route_def, route_defs, &v);
+            quote! {
+                let unmatched = ::leptos_router::hooks::use_params_map();
+                if unmatched.with(|params| params.get_str("unmatched").unwrap_or_default().is_empty()) {
+                    (#v)().into_any()
+                } else {
+                    (#global_fallback)().into_any()
+                }
+            }
+        }
+        None => quote! { (#global_fallback)().into_any() },
+    };
+    quote! {
+        move || {
+            use ::leptos::context::Provider;
+            let path = ::leptos_router::hooks::use_location().pathname;
+            let matched = Memo::new(move |_| {
+                path.with(|path| {
+                    #(if #match_path(#patterns, #unprefixed).is_some() {
+                        return Some(#positions);
+                    })*
+                    None::<usize>
+                })
+            });
+            view! {
+                #(#views)*
+                <Show when=move || matched.get().is_none()>
+                    {#fallback}
+                </Show>
+            }
+        }
+    }
+}
+
+/// Expands the trailing optional parameters of the given path into one path per number of them
+/// being present, like "/mail/:folder" and "/mail/:folder/:message" for "/mail/:folder/:message?",
+/// ordered from most to least segments.
+fn expand_trailing_optionals(path: &str) -> Vec<String> {
+    let segments = path.split('/').collect::<Vec<_>>();
+    let required = segments
+        .iter()
+        .rposition(|segment| !segment.ends_with('?'))
+        .map_or(0, |position| position + 1);
+    (required..=segments.len())
+        .rev()
+        .map(|len| {
+            let path = segments[..len]
+                .iter()
+                .map(|segment| segment.trim_end_matches('?'))
+                .collect::<Vec<_>>()
+                .join("/");
+            if path.is_empty() { String::from("/") } else { path }
+        })
+        .collect()
+}
+
+/// Renders the `list` and `detail` panes of a route declaring `panes` side by side, the detail pane
+/// only showing its view while the trailing optional parameter of the route is present.
+fn split_panes(route_def: &RouteDef, panes: &PanesArgs) -> Expr {
+    let detail_param = detail_param(&route_def.path_segments).expect("validated");
+    let PanesArgs { list, detail, .. } = panes;
+    syn::parse_quote! {
+        move || {
+            let params = ::leptos_router::hooks::use_params_map();
+            let selected = move || params.with(|params| params.get_str(#detail_param).is_some());
+            view! {
+                <div data-pane="list">{(#list)()}</div>
+                <div data-pane="detail">
+                    <Show when=selected>{(#detail)()}</Show>
+                </div>
+            }
+        }
+    }
+}
+
+/// Renders a route declared `modal` on top of the sibling route recorded as the `ModalBackground`,
+/// or on its own when no sibling was rendered before, like when loading the modal's path directly.
+/// Siblings of modal routes record themselves as the background whenever they are rendered.
+fn with_modal_background(route_defs: &[RouteDef], route_def: &RouteDef, view: &Expr) -> Expr {
+    let Some(parent) = find_parent_of(route_defs, route_def) else {
+        return view.clone();
+    };
+    let backgrounds = parent
+        .nested_children()
+        .into_iter()
+        .filter(|sibling| !sibling.modal && !sibling.is_group && sibling.view.is_some())
+        .collect::<Vec<_>>();
+    if !route_def.modal {
+        if !parent.children.iter().any(|sibling| sibling.modal) {
+            return view.clone();
+        }
+        let id = route_def.data_route_id();
+        return syn::parse_quote! {
+            move || {
+                if let Some(background) = use_context::<self::ModalBackground>() {
+                    background.record(#id);
+                }
+                (#view)()
+            }
+        };
+    }
+    let arms = backgrounds.iter().map(|sibling| {
+        let id = sibling.data_route_id();
+        let view = sibling.view.as_ref().expect("present");
+        quote! { #id => (#view)().into_any(), }
+    });
+    syn::parse_quote! {
+        move || {
+            let background = use_context::<self::ModalBackground>()
+                .and_then(|background| background.route())
+                .map(|route| match route {
+                    #(#arms)*
+                    _ => ().into_any(),
+                });
+            view! {
+                {background}
+                <div role="dialog" aria-modal="true">{(#view)()}</div>
+            }
+        }
+    }
+}
+
+/// Renders the general `fallback` instead of the given view, unless the flag declared on the given
+/// route is enabled in the `FlagSet` provided as context. Applies to the layout of routes with
+/// children, so that it spans all of their child routes.
+fn wrap_flag(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    view: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let Some(flag) = &route_def.flag else {
+        return view;
+    };
+    let fallback = args.fallback.as_ref().expect("fallback is required").0.clone();
+    quote! {
+        move || {
+            let enabled = move || {
+                use_context::<::leptos_routes::flags::FlagSet>()
+                    .is_some_and(|flags| flags.is_enabled(#flag))
+            };
+            view! {
+                <Show when=enabled fallback=#fallback>
+                    {(#view)()}
+                </Show>
+            }
+        }
+    }
+}
+
+/// Renders the `constraint_fallback` (or the general `fallback`) instead of the given view, when
+/// parameters declared on the given route do not satisfy their constraints or types.
+fn wrap_constraints(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    view: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if !route_def.has_param_checks() {
+        return view;
+    }
+    let struct_path = route_def.full_module_path_to_struct_def();
+    let fallback = args
+        .constraint_fallback
+        .as_ref()
+        .or(args.fallback.as_ref())
+        .expect("fallback is required")
+        .0
+        .clone();
+    quote! {
+        move || {
+            let params = ::leptos_router::hooks::use_params_map();
+            let satisfied = move || params.with(|params| {
+                self::#struct_path::satisfies_constraints(|name| params.get_str(name))
+            });
+            view! {
+                <Show when=satisfied fallback=#fallback>
+                    {(#view)()}
+                </Show>
+            }
+        }
     }
 }
@@ -0,0 +1,61 @@
+use crate::path::ParamInfo;
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates the `routes_json` function, returning the table of all routes as JSON. With the
+/// `wasm-bindgen` feature, it is exported to JavaScript using `#[wasm_bindgen]`.
+pub fn generate_routes_json(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let entries = flatten(route_defs)
+        .map(|route_def| {
+            let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def)
+                .iter()
+                .map(|p| {
+                    format!(r#"{{"name":{},"optional":{}}}"#, json_string(&p.name), p.is_optional)
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            let title = route_def
+                .title
+                .as_deref()
+                .map_or_else(|| String::from("null"), json_string);
+            format!(
+                r#"{{"name":{},"path":{},"params":[{params}],"title":{title}}}"#,
+                json_string(&route_def.variant_name().to_string()),
+                json_string(&route_def.full_path),
+            )
+        })
+        .collect::<Vec<_>>();
+    let json = format!("[{}]", entries.join(","));
+    let export = cfg!(feature = "wasm-bindgen").then(|| {
+        quote! {
+            #[::leptos_routes::__private::wasm_bindgen::prelude::wasm_bindgen(
+                wasm_bindgen = ::leptos_routes::__private::wasm_bindgen
+            )]
+        }
+    });
+    quote! {
+        /// The table of all routes as a JSON array, listing the name of each route's `Route`
+        /// variant, its full path, its parameters (with their name and whether they are optional)
+        /// and its `title`, or `null`. With the `wasm-bindgen` feature of `leptos-routes`, it is
+        /// exported to JavaScript, letting JS glue code like service workers read the routes.
+        #export
+        pub fn routes_json() -> String {
+            String::from(#json)
+        }
+    }
+}
+
+/// The given string as a JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
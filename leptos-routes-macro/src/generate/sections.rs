@@ -0,0 +1,85 @@
+use crate::path::PathSegments;
+use crate::route_def::{flatten, RouteDef};
+use crate::util::to_pascal_case;
+use quote::{format_ident, quote};
+
+/// Generates the classification of all routes: `Route::is_leaf()`, `Route::has_params()` and,
+/// when routes declare a `section`, a `Section` enum of all sections and `Route::section()`.
+pub fn generate_route_classification(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let mut leaf_arms = Vec::new();
+    let mut params_arms = Vec::new();
+    let mut section_arms = Vec::new();
+    let mut sections = Vec::<&String>::new();
+    for route_def in flatten(route_defs) {
+        let variant_name = route_def.variant_name();
+        let is_leaf = route_def.children.is_empty();
+        let has_params = PathSegments::parse(&route_def.full_path).param_names().next().is_some();
+        leaf_arms.push(quote! { Route::#variant_name(_) => #is_leaf, });
+        params_arms.push(quote! { Route::#variant_name(_) => #has_params, });
+        let section = match &route_def.section {
+            Some(section) => {
+                if !sections.contains(&section) {
+                    sections.push(section);
+                }
+                let section_variant = format_ident!("{}", to_pascal_case(section));
+                quote!(Some(Section::#section_variant))
+            }
+            None => quote!(None),
+        };
+        section_arms.push(quote! { Route::#variant_name(_) => #section, });
+    }
+
+    let section = (!sections.is_empty()).then(|| {
+        let variants = sections
+            .iter()
+            .map(|section| format_ident!("{}", to_pascal_case(section)))
+            .collect::<Vec<_>>();
+        quote! {
+            /// All sections declared by routes using `section = "..."`, in declaration order.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum Section {
+                #(#variants,)*
+            }
+
+            impl Section {
+                /// The name this section was declared with, like "admin".
+                pub fn as_str(&self) -> &'static str {
+                    match self {
+                        #(Section::#variants => #sections,)*
+                    }
+                }
+            }
+
+            impl Route {
+                /// The section this route belongs to, declared using `section = "..."` on the route
+                /// itself or on its nearest parent.
+                pub fn section(&self) -> Option<Section> {
+                    match self {
+                        #(#section_arms)*
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl Route {
+            /// Whether this route has no child routes, rendering a view instead of a layout.
+            pub fn is_leaf(&self) -> bool {
+                match self {
+                    #(#leaf_arms)*
+                }
+            }
+
+            /// Whether the full path of this route declares any parameters, including those of its
+            /// parents.
+            pub fn has_params(&self) -> bool {
+                match self {
+                    #(#params_arms)*
+                }
+            }
+        }
+
+        #section
+    }
+}
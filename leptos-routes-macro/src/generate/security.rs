@@ -0,0 +1,33 @@
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates `security_headers_for(route)`, returning the security headers declared using
+/// `security(...)`.
+pub fn generate_security_headers(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let arms = flatten(route_defs).map(|route_def| {
+        let variant_name = route_def.variant_name();
+        let referrer = route_def
+            .security
+            .referrer
+            .map(|referrer| quote! { ("Referrer-Policy", #referrer), });
+        let frame = route_def
+            .security
+            .frame
+            .map(|frame| quote! { ("X-Frame-Options", #frame), });
+        quote! {
+            Route::#variant_name(_) => &[#referrer #frame],
+        }
+    });
+
+    quote! {
+        /// The security headers declared (or inherited from parent routes declaring them) using
+        /// `security(...)` for the given route, as pairs of header names and values, like
+        /// `("Referrer-Policy", "no-referrer")`. Set them in server middleware on responses
+        /// rendering the route.
+        pub fn security_headers_for(route: &Route) -> &'static [(&'static str, &'static str)] {
+            match *route {
+                #(#arms)*
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::path::ParamInfo;
+use crate::route_def::{flatten, RouteDef};
+use proc_macro_error2::abort;
+use quote::quote;
+
+/// The routes declaring a `shortcut`, together with their shortcut normalized to keys separated
+/// by single spaces.
+fn routes_with_shortcuts(route_defs: &[RouteDef]) -> Vec<(String, &RouteDef)> {
+    let mut shortcuts: Vec<(String, &RouteDef)> = Vec::new();
+    for route_def in flatten(route_defs) {
+        let Some(shortcut) = &route_def.shortcut else {
+            continue;
+        };
+        let shortcut = shortcut.split_whitespace().collect::<Vec<_>>().join(" ");
+        if let Some((_, other)) = shortcuts.iter().find(|(other, _)| *other == shortcut) {
+            abort!(
+                route_def.route_ident_span,
+                "The shortcut \"{}\" is already declared on the \"{}\" route. Declare a distinct shortcut.",
+                shortcut,
+                other.full_path
+            );
+        }
+        shortcuts.push((shortcut, route_def));
+    }
+    shortcuts
+}
+
+/// Generates the expression materializing the path of the given route, which must not have any
+/// required parameters.
+fn materialize_without_params(
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    if params.iter().any(|p| !p.is_optional) {
+        abort!(
+            route_def.route_ident_span,
+            "A route declaring a \"shortcut\" is navigated to without parameter values and must not have required parameters. Remove the \"shortcut\"."
+        );
+    }
+    let struct_path = route_def.full_module_path_to_struct_def();
+    let nones = params.iter().map(|_| quote!(None));
+    quote! { self::#struct_path.materialize(#(#nones),*) }
+}
+
+/// Generates the `shortcuts()` table of all routes declaring a `shortcut`.
+pub fn generate_shortcuts(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let entries = routes_with_shortcuts(route_defs)
+        .into_iter()
+        .map(|(shortcut, route_def)| {
+            // Checks that the route can be navigated to using its shortcut.
+            let _ = materialize_without_params(route_def, route_defs);
+            let variant_name = route_def.variant_name();
+            let struct_path = route_def.full_module_path_to_struct_def();
+            quote! { (#shortcut.to_string(), Route::#variant_name(self::#struct_path)) }
+        });
+    quote! {
+        /// The keyboard shortcuts declared using `shortcut = "..."`, as space-separated keys like
+        /// "g u", together with the route each of them navigates to.
+        pub fn shortcuts() -> Vec<(String, Route)> {
+            vec![#(#entries),*]
+        }
+    }
+}
+
+/// Generates the `use_route_shortcuts()` hook, if the `shortcuts` feature is enabled.
+pub fn generate_shortcuts_hook(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    if !cfg!(feature = "shortcuts") {
+        return quote! {};
+    }
+    let entries = routes_with_shortcuts(route_defs)
+        .into_iter()
+        .map(|(shortcut, route_def)| {
+            let materialize = materialize_without_params(route_def, route_defs);
+            quote! { (#shortcut, #materialize) }
+        });
+    quote! {
+        /// Navigates to the routes declaring a `shortcut` when their keys are typed, while the
+        /// calling component is mounted. Must be called inside a `<Router>`.
+        pub fn use_route_shortcuts() {
+            ::leptos_routes::shortcuts::use_shortcuts(vec![#(#entries),*]);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// Generates `next_sibling()` and `prev_sibling()` functions on the child routes of all routes
+/// declared `ordered`, and on the `Route` enum, navigating between the steps of a multi-step flow.
+pub fn generate_sibling_navigation(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let mut impls = Vec::new();
+    let mut arms = Vec::new();
+    for parent in flatten(route_defs).filter(|route_def| route_def.ordered) {
+        // The index renders the page of the parent itself, being no step.
+        let steps = parent
+            .children
+            .iter()
+            .filter(|child| !child.is_index)
+            .map(|child| (child.full_module_path_to_struct_def(), child.variant_name()))
+            .collect::<Vec<_>>();
+        let step_route = |i: Option<usize>| match i.and_then(|i| steps.get(i)) {
+            Some((struct_path, variant_name)) => quote!(Some(Route::#variant_name(self::#struct_path))),
+            None => quote!(None),
+        };
+        for (i, (struct_path, variant_name)) in steps.iter().enumerate() {
+            let next = step_route(Some(i + 1));
+            let prev = step_route(i.checked_sub(1));
+            impls.push(quote! {
+                impl self::#struct_path {
+                    /// The next step among the child routes of the `ordered` parent route, or
+                    /// `None` for the last step.
+                    pub fn next_sibling(&self) -> Option<Route> {
+                        #next
+                    }
+
+                    /// The previous step among the child routes of the `ordered` parent route, or
+                    /// `None` for the first step.
+                    pub fn prev_sibling(&self) -> Option<Route> {
+                        #prev
+                    }
+                }
+            });
+            arms.push(quote! {
+                Route::#variant_name(route) => (route.prev_sibling(), route.next_sibling()),
+            });
+        }
+    }
+    quote! {
+        #(#impls)*
+
+        impl Route {
+            /// The next step among the child routes of the `ordered` parent of this route. Returns
+            /// `None` for the last step and for routes without an `ordered` parent.
+            pub fn next_sibling(&self) -> Option<Route> {
+                self.siblings().1
+            }
+
+            /// The previous step among the child routes of the `ordered` parent of this route.
+            /// Returns `None` for the first step and for routes without an `ordered` parent.
+            pub fn prev_sibling(&self) -> Option<Route> {
+                self.siblings().0
+            }
+
+            fn siblings(&self) -> (Option<Route>, Option<Route>) {
+                #[allow(unreachable_patterns)]
+                match *self {
+                    #(#arms)*
+                    _ => (None, None),
+                }
+            }
+        }
+    }
+}
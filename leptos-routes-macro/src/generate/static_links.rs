@@ -0,0 +1,42 @@
+use crate::path::{PathSegment, PathSegments};
+use crate::route_def::{flatten, RouteDef};
+use quote::quote;
+
+/// The link template of the given route, like "/users/{id}/{tab?}", with placeholders for its
+/// parameters, as filled by `leptos_routes::links::fill_href`.
+fn link_template(route_def: &RouteDef) -> String {
+    let segments = PathSegments::parse(&route_def.full_path).segments;
+    if segments.is_empty() {
+        return "/".to_string();
+    }
+    segments
+        .iter()
+        .map(|segment| match segment {
+            PathSegment::Static(s) => format!("/{s}"),
+            PathSegment::Param(name) => format!("/{{{name}}}"),
+            PathSegment::OptionalParam(name) => format!("/{{{name}?}}"),
+            PathSegment::Wildcard(name) => format!("/{{*{name}}}"),
+        })
+        .collect()
+}
+
+/// Generates `static_link_map()`, mapping the identifier of every route to its link template.
+pub fn generate_static_link_map(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let entries = flatten(route_defs)
+        .filter(|route_def| !route_def.is_index)
+        .map(|route_def| {
+            let id = route_def.data_route_id();
+            let template = link_template(route_def);
+            quote! { (#id, #template) }
+        });
+    quote! {
+        /// The link template of every route, like "/users/{id}", keyed by the route's identifier,
+        /// like "root.users.user". Server templates rendering plain `<a>` elements for bots and
+        /// clients without JavaScript fill the placeholders using
+        /// `leptos_routes::links::fill_href`.
+        pub fn static_link_map()
+        -> ::leptos_routes::__private::alloc::collections::BTreeMap<&'static str, &'static str> {
+            ::leptos_routes::__private::alloc::collections::BTreeMap::from([#(#entries),*])
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::generate::warnings::generate_warnings;
+use crate::path::{ParamInfo, PathSegment, PathSegments};
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use darling::ast::NestedMeta;
+use darling::FromMeta;
+use proc_macro_error2::abort;
+use syn::ItemMod;
+
+/// The limits of the route tree declared using `#[routes(stats(...))]`, like
+/// `stats(max_depth = 6)`. A bare `stats` declares no limits.
+#[derive(Debug, Default)]
+pub struct RouteStatsArgs {
+    pub max_routes: Option<usize>,
+    pub max_depth: Option<usize>,
+    pub max_params: Option<usize>,
+    pub max_wildcards: Option<usize>,
+}
+
+impl FromMeta for RouteStatsArgs {
+    fn from_word() -> darling::Result<Self> {
+        Ok(RouteStatsArgs::default())
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut stats = RouteStatsArgs::default();
+        for item in items {
+            let NestedMeta::Meta(syn::Meta::NameValue(meta)) = item else {
+                return Err(darling::Error::custom("Expected a limit like max_depth = 6").with_span(item));
+            };
+            let limit = match meta.path.get_ident().map(|ident| ident.to_string()).as_deref() {
+                Some("max_routes") => &mut stats.max_routes,
+                Some("max_depth") => &mut stats.max_depth,
+                Some("max_params") => &mut stats.max_params,
+                Some("max_wildcards") => &mut stats.max_wildcards,
+                _ => {
+                    let message = "Unknown limit. Expected one of max_routes, max_depth, max_params or max_wildcards.";
+                    return Err(darling::Error::custom(message).with_span(&meta.path));
+                }
+            };
+            *limit = Some(usize::from_expr(&meta.value)?);
+        }
+        Ok(stats)
+    }
+}
+
+/// Generates a note summarizing the route tree with `stats`, failing to compile if any of its
+/// declared limits is exceeded. Like other warnings, the note is generated as a constant in the
+/// routes module, returned first, and its use next to it, returned second.
+pub fn generate_route_stats(
+    args: &RoutesMacroArgs,
+    root_mod: &ItemMod,
+    route_defs: &[RouteDef],
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let Some(limits) = &args.stats else {
+        return generate_warnings(root_mod, "ROUTE_STATS", Vec::new());
+    };
+
+    let routes = flatten(route_defs).count();
+    if let Some(max_routes) = limits.max_routes.filter(|max| routes > *max) {
+        abort!(root_mod.ident, "The routes module declares {} routes, exceeding the max_routes of {} declared in `stats(...)`.", routes, max_routes);
+    }
+
+    let mut depths = Vec::new();
+    collect_depths(route_defs, 1, &mut depths);
+    let deepest = depths.into_iter().max_by_key(|(_, depth)| *depth);
+    let max_depth = deepest.map_or(0, |(_, depth)| depth);
+    let exceeded = limits.max_depth.filter(|max| max_depth > *max);
+    if let (Some((deepest, _)), Some(limit)) = (deepest, exceeded) {
+        abort!(deepest.route_ident_span, "The route \"{}\" is nested {} levels deep, exceeding the max_depth of {} declared in `stats(...)`. Flatten the route tree or raise the limit.", deepest.full_path, max_depth, limit);
+    }
+
+    let params = flatten(route_defs)
+        .map(|route_def| {
+            let count = ParamInfo::collect_params_through_hierarchy(route_defs, route_def).len();
+            (route_def, count)
+        })
+        .collect::<Vec<_>>();
+    let max_params = params.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    if let Some(limit) = limits.max_params {
+        if let Some((route_def, count)) = params.iter().find(|(_, count)| *count > limit) {
+            abort!(route_def.route_ident_span, "The route \"{}\" has {} parameters, exceeding the max_params of {} declared in `stats(...)`.", route_def.full_path, count, limit);
+        }
+    }
+    let total_params = params.iter().map(|(_, count)| *count).sum::<usize>();
+
+    let wildcards = flatten(route_defs)
+        .filter(|route_def| {
+            PathSegments::parse(&route_def.full_path)
+                .segments
+                .iter()
+                .any(|segment| matches!(segment, PathSegment::Wildcard(_)))
+        })
+        .collect::<Vec<_>>();
+    if let Some(limit) = limits.max_wildcards.filter(|max| wildcards.len() > *max) {
+        let route_def = wildcards[limit];
+        abort!(route_def.route_ident_span, "The route \"{}\" uses a wildcard, exceeding the max_wildcards of {} declared in `stats(...)`.", route_def.full_path, limit);
+    }
+
+    let note = format!(
+        "Route statistics: {routes} routes ({} using wildcards), a maximum depth of {max_depth} and at most {max_params} parameters per route, {:.1} on average.",
+        wildcards.len(),
+        total_params as f64 / routes.max(1) as f64,
+    );
+    generate_warnings(root_mod, "ROUTE_STATS", vec![(root_mod.ident.span(), note)])
+}
+
+/// Collects the nesting depth of the given routes and all of their children, counting top-level
+/// routes as 1. An index renders its parent's own page, so it is as deep as its parent.
+fn collect_depths<'a>(
+    route_defs: &'a [RouteDef],
+    depth: usize,
+    depths: &mut Vec<(&'a RouteDef, usize)>,
+) {
+    for route_def in route_defs {
+        let depth = if route_def.is_index { depth - 1 } else { depth };
+        depths.push((route_def, depth));
+        collect_depths(&route_def.children, depth + 1, depths);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::route_def::{flatten, RouteDef};
+use proc_macro_error2::abort;
+use quote::quote;
+
+/// Generates `status_for(route)`, returning the HTTP status code declared using `status = ...`, if
+/// the `status` feature is enabled.
+pub fn generate_status_for(route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    if !cfg!(feature = "status") {
+        if let Some(route_def) = flatten(route_defs).find(|route_def| route_def.status.is_some()) {
+            abort!(
+                route_def.route_ident_span,
+                "\"status\" requires the \"status\" feature of leptos-routes. Enable the feature or remove the argument."
+            );
+        }
+        return quote! {};
+    }
+    let arms = flatten(route_defs).map(|route_def| {
+        let variant_name = route_def.variant_name();
+        let status = match route_def.status {
+            Some(status) => quote! { ::leptos_routes::status::StatusCode::from_u16(#status).ok() },
+            None => quote! { None },
+        };
+        quote! {
+            Route::#variant_name(_) => #status,
+        }
+    });
+    quote! {
+        /// The HTTP status code declared using `status = ...` for the given route. Set it on the
+        /// response rendering the route, e.g. using `leptos_axum::ResponseOptions::set_status`.
+        /// Returns `None` for routes leaving the status up to the server.
+        pub fn status_for(route: &Route) -> Option<::leptos_routes::status::StatusCode> {
+            match *route {
+                #(#arms)*
+            }
+        }
+    }
+}
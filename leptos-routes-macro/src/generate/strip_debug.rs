@@ -0,0 +1,96 @@
+use proc_macro_error2::abort_call_site;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::visit_mut::{self, VisitMut};
+use syn::{parse_quote, Attribute, Item, Meta, Path, Token};
+
+/// Makes the doc strings of all given items, and their `Debug` derives if `strip_derives` is set,
+/// only apply to debug builds.
+pub fn strip_debug(ts: proc_macro2::TokenStream, strip_derives: bool) -> proc_macro2::TokenStream {
+    let mut file = parse(ts);
+    StripDebug { strip_derives }.visit_file_mut(&mut file);
+    quote! { #file }
+}
+
+/// Makes all given items only exist in debug builds.
+pub fn debug_only(ts: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let mut file = parse(ts);
+    for item in &mut file.items {
+        if let Some(attrs) = item_attrs(item) {
+            attrs.insert(0, parse_quote!(#[cfg(debug_assertions)]));
+        }
+    }
+    quote! { #file }
+}
+
+fn parse(ts: proc_macro2::TokenStream) -> syn::File {
+    syn::parse2::<syn::File>(ts).unwrap_or_else(|e| abort_call_site!(e))
+}
+
+fn item_attrs(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Const(item) => Some(&mut item.attrs),
+        Item::Enum(item) => Some(&mut item.attrs),
+        Item::Fn(item) => Some(&mut item.attrs),
+        Item::Impl(item) => Some(&mut item.attrs),
+        Item::Mod(item) => Some(&mut item.attrs),
+        Item::Static(item) => Some(&mut item.attrs),
+        Item::Struct(item) => Some(&mut item.attrs),
+        Item::Use(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+struct StripDebug {
+    strip_derives: bool,
+}
+
+impl StripDebug {
+    /// Moves `Debug` out of all `derive` attributes into a `cfg_attr(debug_assertions, ...)`.
+    fn strip_debug_derive(&self, attrs: &mut Vec<Attribute>) {
+        if !self.strip_derives {
+            return;
+        }
+        let mut derived_debug = false;
+        for attr in attrs.iter_mut() {
+            if !attr.path().is_ident("derive") {
+                continue;
+            }
+            let Ok(derives) =
+                attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+            else {
+                continue;
+            };
+            let (debug, others): (Vec<_>, Vec<_>) =
+                derives.into_iter().partition(|path| path.is_ident("Debug"));
+            if !debug.is_empty() {
+                derived_debug = true;
+                *attr = parse_quote!(#[derive(#(#others),*)]);
+            }
+        }
+        if derived_debug {
+            attrs.push(parse_quote!(#[cfg_attr(debug_assertions, derive(Debug))]));
+        }
+    }
+}
+
+impl VisitMut for StripDebug {
+    fn visit_attribute_mut(&mut self, attr: &mut Attribute) {
+        if let Meta::NameValue(doc) = &attr.meta {
+            if doc.path.is_ident("doc") {
+                let value = &doc.value;
+                attr.meta = parse_quote!(cfg_attr(debug_assertions, doc = #value));
+            }
+        }
+    }
+
+    fn visit_item_struct_mut(&mut self, item: &mut syn::ItemStruct) {
+        self.strip_debug_derive(&mut item.attrs);
+        visit_mut::visit_item_struct_mut(self, item);
+    }
+
+    fn visit_item_enum_mut(&mut self, item: &mut syn::ItemEnum) {
+        self.strip_debug_derive(&mut item.attrs);
+        visit_mut::visit_item_enum_mut(self, item);
+    }
+}
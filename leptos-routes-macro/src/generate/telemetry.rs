@@ -0,0 +1,92 @@
+use crate::path::{collect_patterns, pattern_ref, ParamInfo};
+use crate::route_def::RouteDef;
+use crate::RoutesMacroArgs;
+use proc_macro_error2::abort_call_site;
+use quote::quote;
+
+/// Generates the `PATTERN` and `SPAN_NAME` constants of the given route.
+pub fn generate_telemetry_consts(
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    let struct_name = &route_def.name;
+    let depth = route_def.found_in_module_path.without_first().len();
+    let pattern = pattern_ref(&collect_patterns(route_defs), &route_def.full_path, depth);
+    let span_name = route_def.span_name();
+    quote! {
+        impl #struct_name {
+            /// The full path pattern of this route, like "/users/:id", labelling its metrics.
+            pub const PATTERN: &'static str = #pattern;
+
+            /// The name of the `tracing` span the views of this route are rendered in, when
+            /// using `#[routes(tracing)]`.
+            pub const SPAN_NAME: &'static str = #span_name;
+        }
+    }
+}
+
+/// Aborts if `tracing` is requested without the means to render views in spans.
+pub fn check_tracing_args(args: &RoutesMacroArgs) {
+    if !args.tracing {
+        return;
+    }
+    if !args.with_views {
+        abort_call_site!("\"tracing\" renders the views of the generated routes in spans and requires \"with_views\". Add \"with_views\" or remove the argument.");
+    }
+    if !cfg!(feature = "tracing") {
+        abort_call_site!("\"tracing\" requires the \"tracing\" feature of leptos-routes. Enable the feature or remove the argument.");
+    }
+}
+
+/// Generates the statements recording the metrics of the given route's view, if the `metrics`
+/// feature is enabled. The render duration is recorded once the view is built.
+pub fn record_route_metrics(
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> Option<proc_macro2::TokenStream> {
+    if !cfg!(feature = "metrics") {
+        return None;
+    }
+    let pattern = pattern_ref(&collect_patterns(route_defs), &route_def.full_path, 0);
+    Some(quote! {
+        ::leptos_routes::metrics::record_matched(#pattern);
+        let _render_timer = ::leptos_routes::metrics::RenderTimer::start(#pattern);
+    })
+}
+
+/// Generates the statement entering the span the given route's view is rendered in, if
+/// requested with `tracing`. The typed parameters of the route are recorded as fields.
+pub fn enter_route_span(
+    args: &RoutesMacroArgs,
+    route_def: &RouteDef,
+    route_defs: &[RouteDef],
+) -> Option<proc_macro2::TokenStream> {
+    if !args.tracing {
+        return None;
+    }
+    let span_name = route_def.span_name();
+    let params = ParamInfo::collect_params_through_hierarchy(route_defs, route_def);
+    if params.is_empty() {
+        return Some(quote! {
+            let _span = ::leptos_routes::__private::tracing::info_span!(#span_name).entered();
+        });
+    }
+    let fields = params.iter().map(|p| {
+        let name = &p.name;
+        let value = p.read_from_params_map(0);
+        // Optional parameters given as `None` and values not parsing are recorded as empty.
+        let value = if p.is_optional { quote!((#value).flatten()) } else { value };
+        quote! {
+            #name = (#value)
+                .as_ref()
+                .map(::leptos_routes::__private::tracing::field::display)
+        }
+    });
+    Some(quote! {
+        let _span = ::leptos_router::hooks::use_params_map()
+            .with_untracked(|params| {
+                ::leptos_routes::__private::tracing::info_span!(#span_name, #(#fields),*)
+            })
+            .entered();
+    })
+}
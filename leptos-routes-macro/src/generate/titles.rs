@@ -0,0 +1,62 @@
+use crate::generate::renders;
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use quote::quote;
+
+/// Generates a `check_titles` function, checking the title applied by the render of one path per
+/// route rendering a view or fallback, when using `with_views` and the "title-tests" feature.
+pub fn generate_title_check(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    if !cfg!(feature = "title-tests") || !args.with_views {
+        return quote! {};
+    }
+
+    let mut paths: Vec<String> = Vec::new();
+    let mut entries = Vec::new();
+    for route_def in flatten(route_defs) {
+        if route_def.view.is_none() && route_def.fallback.is_none() {
+            continue;
+        }
+        let path = renders::test_path(route_def);
+        if paths.contains(&path) {
+            continue;
+        }
+        let title = match &route_def.title {
+            Some(title) => quote! { Some(#title) },
+            None => quote! { None },
+        };
+        entries.push(quote! { (#path, #title) });
+        paths.push(path);
+    }
+
+    quote! {
+        /// The paths checked by `check_titles`: one per route rendering a view or fallback, with
+        /// each parameter set to its `sample` value or "1", together with the declared `title`.
+        pub const TITLE_TEST_PATHS: &[(&str, Option<&str>)] = &[#(#entries),*];
+
+        /// Renders each path of `TITLE_TEST_PATHS` like the server would, returning the first
+        /// whose render applied no title, as read from the rendered HTML by `applied_title`, or a
+        /// title not containing the declared `title` of its route. Pass
+        /// `leptos_routes::titles::title_element` to read the `<title>` element rendered by views.
+        pub fn check_titles(
+            applied_title: impl Fn(&str) -> Option<String>,
+        ) -> ::std::result::Result<(), ::leptos_routes::titles::TitleMismatch> {
+            use ::leptos_router::components::Router;
+            use ::leptos::prelude::*;
+
+            ::leptos_routes::titles::check_paths(
+                TITLE_TEST_PATHS,
+                || {
+                    view! {
+                        <Router>
+                            {generated_routes()}
+                        </Router>
+                    }
+                },
+                applied_title,
+            )
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use proc_macro_error2::abort;
+use quote::quote;
+
+/// Generates the `can_transition(from, to)` function, checking the `allowed_from` routes of the
+/// route navigated to, if any route declares them. When using `with_views`, also generates
+/// `navigate_if_allowed(path)`, enforcing them when navigating.
+pub fn generate_transitions(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    if !flatten(route_defs).any(|route_def| !route_def.allowed_from.is_empty()) {
+        return quote! {};
+    }
+
+    let restricted = flatten(route_defs)
+        .filter(|route_def| !route_def.allowed_from.is_empty())
+        .map(RouteDef::variant_name)
+        .collect::<Vec<_>>();
+    let arms = flatten(route_defs)
+        .filter(|route_def| !route_def.allowed_from.is_empty())
+        .map(|route_def| {
+            let variant_name = route_def.variant_name();
+            let from_variants = route_def
+                .allowed_from
+                .iter()
+                .flat_map(|id| {
+                    // A route rendering its own page using an `index` is navigated away from as
+                    // its index route.
+                    let from = find_route(route_defs, id);
+                    std::iter::once(from)
+                        .chain(from.children.iter().filter(|child| child.is_index))
+                        .map(RouteDef::variant_name)
+                })
+                .collect::<Vec<_>>();
+            quote! { Route::#variant_name(_) => matches!(from, #(Route::#from_variants(_))|*), }
+        })
+        .collect::<Vec<_>>();
+
+    let navigate_if_allowed = args.with_views.then(|| {
+        quote! {
+            /// Navigates to the given path if `can_transition` allows navigating to its route from
+            /// the route of the current location, returning whether it navigated. Paths of routes
+            /// without `allowed_from` are always navigated to, while routes declaring them are
+            /// never navigated to from locations matched by no route. Must be called inside a
+            /// `<Router>`.
+            pub fn navigate_if_allowed(path: &str) -> bool {
+                use ::leptos::prelude::GetUntracked;
+                let current = ::leptos_router::hooks::use_location().pathname.get_untracked();
+                let allowed = match (Route::from_path(&current), Route::from_path(path)) {
+                    (Some(from), Some(to)) => can_transition(&from, &to),
+                    (None, Some(to)) => !matches!(to, #(Route::#restricted(_))|*),
+                    (_, None) => true,
+                };
+                if allowed {
+                    ::leptos_router::hooks::use_navigate()(path, Default::default());
+                }
+                allowed
+            }
+        }
+    });
+
+    quote! {
+        /// Whether the route `to` may be navigated to from the route `from`. Routes declaring
+        /// `allowed_from` may only be navigated to from the routes listed in it, while all other
+        /// routes may be navigated to from anywhere.
+        pub fn can_transition(from: &Route, to: &Route) -> bool {
+            match to {
+                #(#arms)*
+                _ => true,
+            }
+        }
+
+        #navigate_if_allowed
+    }
+}
+
+/// The route identified by the given `allowed_from` entry: either its full `data-route`
+/// identifier, like "root.login", or a unique trailing part of it, like "login".
+fn find_route<'a>(route_defs: &'a [RouteDef], id: &syn::LitStr) -> &'a RouteDef {
+    let value = id.value();
+    let matches = flatten(route_defs)
+        .filter(|route_def| {
+            let data_route_id = route_def.data_route_id();
+            data_route_id == value || data_route_id.ends_with(&format!(".{value}"))
+        })
+        .collect::<Vec<_>>();
+    match matches.as_slice() {
+        [route_def] => route_def,
+        [] => {
+            let known = flatten(route_defs)
+                .map(|route_def| format!("\"{}\"", route_def.data_route_id()))
+                .collect::<Vec<_>>();
+            abort!(id.span(), "Unknown route \"{}\". Routes are identified like their `data-route` attributes, being one of: {}.", value, known.join(", "));
+        }
+        _ => {
+            let candidates = matches
+                .iter()
+                .map(|route_def| format!("\"{}\"", route_def.data_route_id()))
+                .collect::<Vec<_>>();
+            abort!(id.span(), "The route \"{}\" is ambiguous, matching {}. Use the full identifier of one of them.", value, candidates.join(", "));
+        }
+    }
+}
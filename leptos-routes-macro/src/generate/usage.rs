@@ -0,0 +1,48 @@
+use crate::route_def::{flatten, RouteDef};
+use crate::RoutesMacroArgs;
+use quote::quote;
+
+/// Generates the `__ROUTE_USAGE` registry, recording which routes were materialized, and the
+/// `unused_routes` function reading it, when using `deny_unused`. The registry holds one entry per
+/// route, in the order of `flatten`.
+pub fn generate_usage_registry(
+    args: &RoutesMacroArgs,
+    route_defs: &[RouteDef],
+) -> proc_macro2::TokenStream {
+    if !args.deny_unused {
+        return quote! {};
+    }
+
+    let names = flatten(route_defs)
+        .map(|route_def| {
+            route_def
+                .full_module_path_to_struct_def()
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("::")
+        })
+        .collect::<Vec<_>>();
+    let count = names.len();
+
+    quote! {
+        #[doc(hidden)]
+        pub static __ROUTE_USAGE: [::core::sync::atomic::AtomicBool; #count] =
+            [const { ::core::sync::atomic::AtomicBool::new(false) }; #count];
+
+        /// The route structs (like "root::users::User") whose `materialize` function, or any
+        /// function building on it, was not called yet in this process.
+        ///
+        /// Call this at the end of a test exercising your app to find routes never linked to.
+        pub fn unused_routes() -> Vec<&'static str> {
+            const NAMES: [&str; #count] = [#(#names),*];
+            NAMES
+                .into_iter()
+                .zip(&__ROUTE_USAGE)
+                .filter(|(_, used)| !used.load(::core::sync::atomic::Ordering::Relaxed))
+                .map(|(name, _)| name)
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use proc_macro2::Span;
+use quote::{format_ident, quote, quote_spanned};
+use syn::ItemMod;
+
+/// Generates the given warnings, each spanned to the given span, like that of a route.
+///
+/// Procedural macros cannot emit warnings on stable Rust, so each warning is the use of a
+/// deprecated constant, named using the given prefix. The constants are generated in the routes
+/// module, while their uses are generated next to it, returned second, so that the warnings are
+/// not silenced by the `#[allow(deprecated)]` of a routes module declaring deprecated routes. The
+/// uses carry the `#[allow(...)]` attributes of the routes module, letting users silence them.
+pub fn generate_warnings(
+    root_mod: &ItemMod,
+    prefix: &str,
+    warnings: Vec<(Span, String)>,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let root_ident = &root_mod.ident;
+    let allows = root_mod
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("allow"))
+        .collect::<Vec<_>>();
+    let (consts, usages): (Vec<_>, Vec<_>) = warnings
+        .into_iter()
+        .enumerate()
+        .map(|(i, (span, note))| {
+            let name = format_ident!("__{}_{}", prefix, i);
+            let used_name = format_ident!("{}", name, span = span);
+            let definition = quote! {
+                #[doc(hidden)]
+                #[deprecated(note = #note)]
+                pub const #name: () = ();
+            };
+            let usage = quote_spanned! {span=>
+                #(#allows)*
+                const _: () = #root_ident::#used_name;
+            };
+            (definition, usage)
+        })
+        .unzip();
+    (quote! { #(#consts)* }, quote! { #(#usages)* })
+}
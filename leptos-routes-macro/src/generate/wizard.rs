@@ -0,0 +1,170 @@
+use crate::path::ParamInfo;
+use crate::route_def::RouteDef;
+use quote::quote;
+
+/// Generates the `WizardStep` enum and the `WizardController` of a route whose child routes declare
+/// a `step`, in the module of these child routes, as well as the `use_wizard` function of the
+/// route. Nothing is generated for routes without steps.
+pub fn generate_wizard(route_def: &RouteDef, route_defs: &[RouteDef]) -> proc_macro2::TokenStream {
+    let mut steps = route_def
+        .children
+        .iter()
+        .filter(|child| child.step.is_some())
+        .collect::<Vec<_>>();
+    if steps.is_empty() {
+        return quote! {};
+    }
+    steps.sort_by_key(|step| step.step);
+
+    let parent_name = &route_def.name;
+    let step_count = steps.len();
+    let depth = steps[0].found_in_module_path.without_first().len();
+
+    // Every step is represented by a variant named like its route struct.
+    let variants = steps.iter().map(|step| &step.name).collect::<Vec<_>>();
+    let struct_names = &variants;
+    let numbers = steps.iter().map(|step| step.step.expect("present"));
+    let positions = 0..step_count;
+
+    // Steps declare no parameters of their own, so all of them are materialized from the parameters
+    // of the parent route, read from the current location.
+    let param_values = ParamInfo::collect_params_through_hierarchy(route_defs, steps[0])
+        .iter()
+        .map(|p| p.read_from_params_map(depth))
+        .collect::<Vec<_>>();
+    let materialize_args = quote!(#(#param_values?),*);
+    let doc = format!(
+        "The steps of the wizard formed by the child routes of the `{}` route, ordered by their \
+         declared `step`.",
+        route_def.full_path
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum WizardStep {
+            #(#variants(#struct_names),)*
+        }
+
+        impl WizardStep {
+            /// All steps, in order.
+            pub const ALL: [Self; #step_count] = [#(Self::#variants(#struct_names)),*];
+
+            /// The step matching the given path, if any.
+            pub fn from_path(path: &str) -> Option<Self> {
+                Self::ALL.into_iter().find(|step| match step {
+                    #(Self::#variants(route) => route.matches(path),)*
+                })
+            }
+
+            /// The `step` number declared on this step's route.
+            pub fn number(&self) -> u32 {
+                match self {
+                    #(Self::#variants(_) => #numbers,)*
+                }
+            }
+
+            /// The position of this step among all steps, starting at 0.
+            pub fn position(&self) -> usize {
+                match self {
+                    #(Self::#variants(_) => #positions,)*
+                }
+            }
+
+            /// The following step, or `None` for the last step.
+            pub fn next(&self) -> Option<Self> {
+                Self::ALL.get(self.position() + 1).copied()
+            }
+
+            /// The preceding step, or `None` for the first step.
+            pub fn prev(&self) -> Option<Self> {
+                self.position().checked_sub(1).map(|position| Self::ALL[position])
+            }
+
+            fn materialize_from(
+                &self,
+                params: &::leptos_router::params::ParamsMap,
+            ) -> Option<String> {
+                match self {
+                    #(Self::#variants(route) => Some(route.materialize(#materialize_args)),)*
+                }
+            }
+        }
+
+        /// Reactive access to the current step of the wizard, navigating between its steps.
+        #[derive(Clone, Copy)]
+        pub struct WizardController {
+            current: ::leptos::prelude::Memo<Option<WizardStep>>,
+            params: ::leptos::prelude::Memo<::leptos_router::params::ParamsMap>,
+            navigate: ::leptos::prelude::StoredValue<
+                Box<dyn Fn(&str)>,
+                ::leptos::prelude::LocalStorage,
+            >,
+        }
+
+        impl WizardController {
+            /// Creates a controller tracking the step of the current location. Must be called
+            /// within a `<Router>`, e.g. in the layout of the wizard's route.
+            #[allow(clippy::new_without_default)]
+            pub fn new() -> Self {
+                use ::leptos::prelude::With;
+                let location = ::leptos_router::hooks::use_location();
+                let navigate = ::leptos_router::hooks::use_navigate();
+                Self {
+                    current: ::leptos::prelude::Memo::new(move |_| {
+                        location.pathname.with(|path| WizardStep::from_path(path))
+                    }),
+                    params: ::leptos_router::hooks::use_params_map(),
+                    navigate: ::leptos::prelude::StoredValue::new_local(Box::new(move |path: &str| {
+                        navigate(path, Default::default())
+                    })),
+                }
+            }
+
+            /// The current step, or `None` when the current location is no step. Tracked.
+            pub fn current(&self) -> Option<WizardStep> {
+                use ::leptos::prelude::Get;
+                self.current.get()
+            }
+
+            /// The fraction of steps reached, from `1 / steps` on the first step to `1.0` on the
+            /// last one, or `0.0` when the current location is no step. Tracked.
+            pub fn progress(&self) -> f32 {
+                self.current()
+                    .map(|step| (step.position() + 1) as f32 / #step_count as f32)
+                    .unwrap_or(0.0)
+            }
+
+            /// Navigates to the following step. Does nothing on the last step.
+            pub fn advance(&self) {
+                self.navigate_to(|step| step.next());
+            }
+
+            /// Navigates to the preceding step. Does nothing on the first step.
+            pub fn back(&self) {
+                self.navigate_to(|step| step.prev());
+            }
+
+            fn navigate_to(&self, target: impl Fn(WizardStep) -> Option<WizardStep>) {
+                use ::leptos::prelude::{GetUntracked, WithUntracked, WithValue};
+                let Some(target) = self.current.get_untracked().and_then(target) else {
+                    return;
+                };
+                let Some(path) =
+                    self.params.with_untracked(|params| target.materialize_from(params))
+                else {
+                    return;
+                };
+                self.navigate.with_value(|navigate| navigate(&path));
+            }
+        }
+
+        impl super::#parent_name {
+            /// Reactive access to the steps of the wizard formed by the child routes of this
+            /// route. Must be called within a `<Router>`.
+            pub fn use_wizard(&self) -> WizardController {
+                WizardController::new()
+            }
+        }
+    }
+}
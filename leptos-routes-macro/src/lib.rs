@@ -1,14 +1,19 @@
 mod expr_wrapper;
+mod external_routes;
 mod generate;
 mod module_path;
 mod path;
 mod route_def;
 mod route_macro_args;
+mod route_path_args;
 mod util;
 
-use crate::expr_wrapper::ExprWrapper;
+use crate::expr_wrapper::{ExprListWrapper, ExprWrapper};
+use crate::generate::stats::RouteStatsArgs;
 use crate::module_path::ModulePath;
-use crate::route_def::{collect_route_definitions, RouteDef};
+use crate::path::OptionalParams;
+use crate::route_def::{check_query_variants, collect_route_definitions, RouteDef};
+use crate::route_path_args::RoutePathArgs;
 use darling::ast::NestedMeta;
 use darling::FromMeta;
 use proc_macro::TokenStream;
@@ -22,6 +27,18 @@ pub fn route(_attr: TokenStream, input: TokenStream) -> TokenStream {
     input
 }
 
+/// Materializes the path of a route from named arguments, checked against the parameters of the
+/// route at compile time. All parameters must be named, passing `None` for absent optional ones.
+///
+/// ```ignore
+/// let path = route_path!(routes::root::users::user::Details, id = "42");
+/// ```
+#[proc_macro]
+#[proc_macro_error]
+pub fn route_path(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as RoutePathArgs).expand().into()
+}
+
 #[derive(Debug, FromMeta)]
 struct RoutesMacroArgs {
     #[darling(default)]
@@ -29,6 +46,171 @@ struct RoutesMacroArgs {
 
     #[darling(default)]
     fallback: Option<ExprWrapper>,
+
+    /// A function called with the URL of every path no route matches, before rendering the
+    /// `fallback`, like `log_404(url: &str)`.
+    #[darling(default)]
+    on_not_found: Option<ExprWrapper>,
+
+    /// Render a `<meta name="robots" content="noindex">` element in front of the views of all
+    /// `noindex` routes.
+    #[darling(default)]
+    noindex_meta: bool,
+
+    /// The view rendered when a matched route's parameters do not satisfy their constraints.
+    /// Defaults to `fallback`.
+    #[darling(default)]
+    constraint_fallback: Option<ExprWrapper>,
+
+    /// Render the `fallback` of the nearest parent route for routes with children not declaring
+    /// a `fallback` of their own.
+    #[darling(default)]
+    inherit_fallback: bool,
+
+    /// Also generate a `generated_router()` function, rendering the generated routes inside a
+    /// `<Router>`. Requires `with_views`.
+    #[darling(default)]
+    with_router: bool,
+
+    /// The base path of the `<Router>` rendered by `generated_router()`.
+    #[darling(default)]
+    base: Option<String>,
+
+    /// The path prefix under which the subtrees of all routes declared `previewable` are registered
+    /// a second time, rendering their `preview` views where declared, like "/preview". Requires
+    /// `with_views`.
+    #[darling(default)]
+    preview_prefix: Option<String>,
+
+    /// The path prefix of the API serving the resources of all routes declaring `crud`, like
+    /// "/api". Generates a `resource_endpoints()` function relating these routes to their API
+    /// endpoints.
+    #[darling(default)]
+    api_prefix: Option<String>,
+
+    /// Only generate doc strings, `Debug` implementations and the `Route` enum (with everything
+    /// depending on it) in debug builds, reducing the size of release builds.
+    #[darling(default)]
+    strip_debug: bool,
+
+    /// Let the generated code compile in `#![no_std]` crates, importing the items of the standard
+    /// prelude it relies on from `alloc`.
+    #[darling(default)]
+    no_std: bool,
+
+    /// Record calls to the `materialize` functions of all routes, listing routes never
+    /// materialized in a generated `unused_routes()` function.
+    #[darling(default)]
+    deny_unused: bool,
+
+    /// Warn about every leaf route declaring neither `roles` nor `public`, on the route itself or on
+    /// any of its parents, making the access decisions of all routes explicit.
+    #[darling(default)]
+    audit_access: bool,
+
+    /// Log a warning whenever a route declared `deprecated` is rendered.
+    #[darling(default)]
+    log_deprecated: bool,
+
+    /// The name of the query parameter carrying the method a form is submitted with, letting the
+    /// server override the form's `POST`. Generates a `form_action_with_method` function for all
+    /// routes.
+    #[darling(default)]
+    method_override: Option<String>,
+
+    /// The name of the parameter selecting the tenant of a multi-tenant app, served on a subdomain
+    /// of `host`. Generates a `materialize_absolute` function for all routes and
+    /// `Route::from_url`. Requires `host`.
+    #[darling(default)]
+    host_param: Option<String>,
+
+    /// The host whose subdomains serve the tenants selected by `host_param`, like "example.com".
+    #[darling(default)]
+    host: Option<String>,
+
+    /// Generate a `RouteRecorder`, recording the routes rendered while provided as context.
+    /// Requires `with_views`.
+    #[darling(default)]
+    route_recorder: bool,
+
+    /// Set a `data-route` attribute identifying the route on the views of all routes, and generate
+    /// a `DATA_ROUTES` map of all identifiers. Requires `with_views`.
+    #[darling(default)]
+    data_route: bool,
+
+    /// Render a page listing all routes at "/__routes" in debug builds. Requires `with_views`.
+    #[darling(default)]
+    route_index: bool,
+
+    /// Render the views of all routes inside `tracing` spans named like their `SPAN_NAME`,
+    /// recording their parameters as fields. Requires `with_views` and the `tracing` feature.
+    #[darling(default)]
+    tracing: bool,
+
+    /// Reusable stacks of layouts, defined like `layout_stacks(auth_stack("AppShell", "Section"))`
+    /// and referenced by routes declaring `layout_stack = "auth_stack"`. Requires `with_views`.
+    #[darling(default)]
+    layout_stacks: std::collections::HashMap<String, ExprListWrapper>,
+
+    /// Values captured by the views of routes declaring `captures(user_ctx)`, defined like
+    /// `captures(user_ctx = "expect_context::<UserContext>()")`. Requires `with_views`.
+    #[darling(default)]
+    captures: std::collections::HashMap<String, ExprWrapper>,
+
+    /// How `materialize` renders optional parameters given as `None`, either "omit_segment"
+    /// (the default) or "keep_trailing_slash".
+    #[darling(default)]
+    optional_params: OptionalParams,
+
+    /// Match static segments ignoring their case in the generated `matches` functions and
+    /// `Route::from_path`, and materialize them in ASCII lowercase.
+    #[darling(default)]
+    case_insensitive: bool,
+
+    /// A prefix of additional names of all items generated in the routes module, like
+    /// `AdminRoute` and `admin_generated_routes` for "Admin", so that the items of multiple
+    /// routes modules can be glob-imported side by side.
+    #[darling(default)]
+    namespace: Option<String>,
+
+    /// Summarize the route tree in a note when compiling, like the number of routes and their
+    /// maximum depth, and fail to compile if it exceeds any of the limits declared like
+    /// `stats(max_depth = 6, max_params = 3)`.
+    #[darling(default)]
+    stats: Option<RouteStatsArgs>,
+}
+
+impl RoutesMacroArgs {
+    /// The runtime function matching paths against route patterns, respecting `case_insensitive`.
+    fn match_path_fn(&self) -> proc_macro2::TokenStream {
+        if self.case_insensitive {
+            quote!(::leptos_routes::matching::match_path_ignore_case)
+        } else {
+            quote!(::leptos_routes::matching::match_path)
+        }
+    }
+}
+
+/// Declares known third-party URLs, generating a struct for each of them that materializes the URL
+/// like the structs of routes do, without registering anything with the router. Declare the URLs
+/// using the `url` attribute on nested modules.
+///
+/// ```
+/// use leptos_routes::external_routes;
+///
+/// #[external_routes]
+/// pub mod docs {
+///
+///     #[url("https://docs.example.com/:page")]
+///     pub mod page {}
+/// }
+///
+/// assert_eq!(docs::Page.materialize("intro"), "https://docs.example.com/intro");
+/// ```
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn external_routes(_attr: TokenStream, input: TokenStream) -> TokenStream {
+    external_routes::expand(parse_macro_input!(input as ItemMod)).into()
 }
 
 /// This is the entry point for route-declarations. Put it on a module. Declare your routes using
@@ -164,14 +346,15 @@ pub fn routes(args: TokenStream, input: TokenStream) -> TokenStream {
             collect_route_definitions(
                 child_module,
                 None,
-                None,
                 &mut route_defs,
                 ModulePath::root(root_mod.ident.clone()),
             );
         }
     }
 
-    generate::impls(&mut root_mod, args, route_defs);
+    check_query_variants(&route_defs);
+
+    let outer_items = generate::impls(&mut root_mod, args, route_defs);
 
     let (brace, ref mut content) = match root_mod.content {
         Some((brace, ref mut content)) => (brace, content),
@@ -181,7 +364,10 @@ pub fn routes(args: TokenStream, input: TokenStream) -> TokenStream {
     // Reconstruct the module with all additions.
     root_mod.content = Some((brace, content.to_vec()));
 
-    Into::into(quote! { #root_mod })
+    Into::into(quote! {
+        #root_mod
+        #outer_items
+    })
 }
 
 fn add_additional_imports_to_modules(module: &mut ItemMod) {
@@ -1,48 +1,283 @@
-use crate::route_def::{find_parent_of, RouteDef};
-use quote::quote;
+use crate::route_def::{find_parent_of, flatten, RouteDef};
+use crate::util::sanitize_identifier;
+use darling::FromMeta;
+use quote::{format_ident, quote};
 
 #[derive(Debug, Clone)]
 pub struct ParamInfo {
     pub name: String,
     pub is_optional: bool,
-    #[expect(unused)]
     pub is_wildcard: bool,
+    /// The regex this parameter must match, if any constraint was declared for it.
+    pub constraint: Option<String>,
+    /// The value used when this optional parameter is absent, if a default was declared for it.
+    pub default: Option<String>,
+    pub ty: ParamType,
+}
+
+/// The type a parameter is represented with in generated functions.
+#[derive(Debug, Clone)]
+pub enum ParamType {
+    /// A plain `&str`.
+    Str,
+    /// A user-provided type implementing `FromStr` and `Display`.
+    Custom(Box<syn::Type>),
+    /// An enum generated from a list of allowed values, given by its path from the root module.
+    Variants(Vec<syn::Ident>),
+    /// A user-provided type, parsed using `parse_from_str(value, format)` and materialized using
+    /// `format(format)`, like chrono's date and time types.
+    Formatted(Box<syn::Type>, String),
+}
+
+impl ParamType {
+    /// A user-provided type. Short names of types supported through the `uuid` and `num`
+    /// features are resolved to their full paths.
+    pub fn custom(ty: syn::Type) -> ParamType {
+        ParamType::Custom(Box::new(resolve_known_type(&ty).unwrap_or(ty)))
+    }
+
+    /// A user-provided type, using the given format when parsing and materializing values.
+    pub fn formatted(ty: syn::Type, format: String) -> ParamType {
+        ParamType::Formatted(Box::new(resolve_known_type(&ty).unwrap_or(ty)), format)
+    }
+
+    /// Generates an expression parsing the given `&str` value into this type, evaluating to a
+    /// `Result`.
+    pub fn parse(&self, value: proc_macro2::TokenStream, depth: usize) -> proc_macro2::TokenStream {
+        match self {
+            ParamType::Str => quote! { Ok::<_, ::core::convert::Infallible>(#value) },
+            ParamType::Formatted(ty, format) => quote! { <#ty>::parse_from_str(#value, #format) },
+            ty => {
+                let ty = ty.to_tokens(depth);
+                quote! { #value.parse::<#ty>() }
+            }
+        }
+    }
+
+    /// Generates an expression displaying the given value of this type in a path.
+    pub fn display(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            ParamType::Formatted(_, format) => quote! { #value.format(#format) },
+            _ => value,
+        }
+    }
+
+    /// Generates this type, as it must be referenced from a module `depth` levels below the root
+    /// module.
+    pub fn to_tokens(&self, depth: usize) -> proc_macro2::TokenStream {
+        match self {
+            ParamType::Str => quote!(&str),
+            ParamType::Custom(ty) | ParamType::Formatted(ty, _) => quote!(#ty),
+            ParamType::Variants(path) => {
+                let supers = (0..depth).map(|_| quote!(super::));
+                quote!(#(#supers)* #(#path)::*)
+            }
+        }
+    }
 }
 
 impl ParamInfo {
-    /// Collect parameters from a route and its parents.
+    /// Collect parameters from a route and its parents, ordered from the root route downwards.
     pub fn collect_params_through_hierarchy(
         root_route_defs: &[RouteDef],
         current_route: &RouteDef,
     ) -> Vec<ParamInfo> {
-        let mut params = Vec::new();
+        let mut params_per_route = Vec::new();
         let mut current = Some(current_route);
 
         while let Some(route_def) = current {
+            let mut params = Vec::new();
             for seg in &route_def.path_segments.segments {
                 match seg {
                     PathSegment::Param(name) => params.push(ParamInfo {
                         name: name.clone(),
                         is_optional: false,
                         is_wildcard: false,
+                        constraint: route_def.constraint_for(name),
+                        default: None,
+                        ty: route_def.param_type_for(name),
                     }),
                     PathSegment::OptionalParam(name) => params.push(ParamInfo {
                         name: name.clone(),
                         is_optional: true,
                         is_wildcard: false,
+                        constraint: route_def.constraint_for(name),
+                        default: route_def.default_for(name),
+                        ty: route_def.param_type_for(name),
                     }),
                     PathSegment::Wildcard(name) => params.push(ParamInfo {
                         name: name.clone(),
                         is_optional: false,
                         is_wildcard: true,
+                        constraint: route_def.constraint_for(name),
+                        default: None,
+                        ty: route_def.param_type_for(name),
                     }),
                     PathSegment::Static(_) => {}
                 }
             }
+            params_per_route.push(params);
+
+            // Routes declared with an absolute path do not inherit any parameters.
+            current = match route_def.is_absolute {
+                true => None,
+                false => find_parent_of(root_route_defs, route_def),
+            };
+        }
+        params_per_route.into_iter().rev().flatten().collect()
+    }
+
+    /// The (sanitized) identifier used for this parameter in generated function signatures.
+    pub fn ident(&self) -> syn::Ident {
+        format_ident!("{}", sanitize_identifier(&self.name))
+    }
+
+    /// The type of this parameter in `materialize`, as referenced from a module `depth` levels
+    /// below the root module. Required untyped parameters accept any `Display` value, like numbers.
+    /// Optional ones stay `Option<&str>`, as a bare `None` could not infer any other type.
+    pub fn signature_type(&self, depth: usize) -> proc_macro2::TokenStream {
+        let ty = self.ty.to_tokens(depth);
+        match (&self.ty, self.is_optional) {
+            (_, true) => quote!(Option<#ty>),
+            (ParamType::Str, false) => quote!(impl ::core::fmt::Display),
+            (_, false) => ty,
+        }
+    }
+
+    /// The type of this parameter in a generated params struct, as referenced from a module `depth`
+    /// levels below the root module. Optional parameters with a default are never absent.
+    pub fn field_type(&self, depth: usize) -> proc_macro2::TokenStream {
+        let ty = match &self.ty {
+            ParamType::Str => quote!(String),
+            ty => ty.to_tokens(depth),
+        };
+        if self.is_optional && self.default.is_none() {
+            quote!(Option<#ty>)
+        } else {
+            ty
+        }
+    }
+
+    /// Generates a check, whether the given `&str` value satisfies the constraint declared for this
+    /// parameter. Returns `None` for unconstrained parameters.
+    pub fn constraint_check(
+        &self,
+        value: proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        self.constraint.as_ref().map(|regex| {
+            // Constraints must match the whole parameter value.
+            let anchored = format!("^(?:{regex})$");
+            quote! {
+                {
+                    use ::leptos_routes::__private::{regex::Regex, std::sync::LazyLock};
+                    static CONSTRAINT: LazyLock<Regex> = LazyLock::new(|| {
+                        Regex::new(#anchored)
+                            .expect("constraint regex to be validated by the routes macro")
+                    });
+                    CONSTRAINT.is_match(#value)
+                }
+            }
+        })
+    }
+
+    /// Generates a check, whether the value of this parameter (if present) satisfies its declared
+    /// type, reading the value with a `param` function. Returns `None` for unchecked parameters.
+    pub fn type_check(&self, depth: usize) -> Option<proc_macro2::TokenStream> {
+        let name = &self.name;
+        match &self.ty {
+            ParamType::Str => None,
+            ty => {
+                let parsed = ty.parse(quote!(value), depth);
+                Some(quote! {
+                    param(#name).is_none_or(|value| #parsed.is_ok())
+                })
+            }
+        }
+    }
+
+    /// Generates an expression reading this parameter from a `ParamsMap` named `params` in a
+    /// module `depth` levels below the root module, as an `Option` of the type this parameter has
+    /// in `materialize`. It is `None` when a required parameter is missing or does not parse, as
+    /// parameters come from the URL.
+    pub fn read_from_params_map(&self, depth: usize) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let parsed = self.ty.parse(quote!(value), depth);
+        match (&self.ty, self.is_optional) {
+            (ParamType::Str, true) => quote! { Some(params.get_str(#name)) },
+            (ParamType::Str, false) => quote! { Some(params.get_str(#name).unwrap_or_default()) },
+            (_, true) => {
+                quote! { Some(params.get_str(#name).and_then(|value| #parsed.ok())) }
+            }
+            (_, false) => {
+                quote! { params.get_str(#name).and_then(|value| #parsed.ok()) }
+            }
+        }
+    }
+}
 
-            current = find_parent_of(root_route_defs, route_def);
+/// Resolves short type names, made available through feature flags, to their full paths.
+#[allow(unused_variables)]
+fn resolve_known_type(ty: &syn::Type) -> Option<syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segments = type_path
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+
+    #[cfg(feature = "uuid")]
+    if matches!(segments.as_slice(), ["Uuid"] | ["uuid", "Uuid"]) {
+        return Some(syn::parse_quote!(::leptos_routes::__private::uuid::Uuid));
+    }
+    #[cfg(feature = "chrono")]
+    if let ["NaiveDate" | "NaiveTime" | "NaiveDateTime"]
+    | ["chrono", "NaiveDate" | "NaiveTime" | "NaiveDateTime"] = segments.as_slice()
+    {
+        let name = format_ident!("{}", segments.last().expect("present"));
+        return Some(syn::parse_quote!(::leptos_routes::__private::chrono::#name));
+    }
+    #[cfg(feature = "num")]
+    if let [name] = segments.as_slice() {
+        let is_non_zero = name.strip_prefix("NonZero").is_some_and(|int| {
+            [
+                "U8", "U16", "U32", "U64", "U128", "Usize", "I8", "I16", "I32", "I64", "I128",
+                "Isize",
+            ]
+            .contains(&int)
+        });
+        if is_non_zero {
+            let name = format_ident!("{name}");
+            return Some(syn::parse_quote!(::core::num::#name));
+        }
+    }
+    None
+}
+
+/// How `materialize` renders an optional parameter given as `None`, selected using
+/// `#[routes(optional_params = "...")]`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OptionalParams {
+    /// Drop the segment including its slash, rendering "/users" for "/users/:page?".
+    #[default]
+    OmitSegment,
+    /// Keep the slash of a trailing optional segment, rendering "/users/" for "/users/:page?".
+    /// Optional segments followed by others are still dropped, avoiding double slashes.
+    KeepTrailingSlash,
+}
+
+impl FromMeta for OptionalParams {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "omit_segment" => Ok(OptionalParams::OmitSegment),
+            "keep_trailing_slash" => Ok(OptionalParams::KeepTrailingSlash),
+            other => Err(darling::Error::custom(format!(
+                "Unknown optional_params \"{other}\". Expected \"omit_segment\" or \"keep_trailing_slash\"."
+            ))),
         }
-        params
     }
 }
 
@@ -81,6 +316,41 @@ impl PathSegments {
         PathSegments { segments }
     }
 
+    /// Names of all parameters (including optional and wildcard parameters) of these segments.
+    pub fn param_names(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().filter_map(|segment| match segment {
+            PathSegment::Static(_) => None,
+            PathSegment::Param(name)
+            | PathSegment::OptionalParam(name)
+            | PathSegment::Wildcard(name) => Some(name.as_str()),
+        })
+    }
+
+    /// Generates a `&[::leptos_routes::matching::Segment]` slice, describing these segments
+    /// for runtime matching, as referenced from a module `depth` levels below the root module.
+    /// Static segments refer to their constant, generated once by `generate_static_segments`.
+    pub fn generate_runtime_pattern(
+        &self,
+        static_segments: &[String],
+        depth: usize,
+    ) -> proc_macro2::TokenStream {
+        let segments = self.segments.iter().map(|segment| match segment {
+            PathSegment::Static(s) => {
+                let supers = module_prefix(depth);
+                let name = static_segment_const(static_segments, s);
+                quote!(::leptos_routes::matching::Segment::Static(#supers __segments::#name))
+            }
+            PathSegment::Param(name) => quote!(::leptos_routes::matching::Segment::Param(#name)),
+            PathSegment::OptionalParam(name) => {
+                quote!(::leptos_routes::matching::Segment::OptionalParam(#name))
+            }
+            PathSegment::Wildcard(name) => {
+                quote!(::leptos_routes::matching::Segment::Wildcard(#name))
+            }
+        });
+        quote!(&[#(#segments),*])
+    }
+
     /// Generates the appropriate tuple-type for these segments.
     pub fn generate_path_type(&self) -> proc_macro2::TokenStream {
         let segment_types = self.segments.iter().map(|segment| match segment {
@@ -96,3 +366,86 @@ impl PathSegments {
         }
     }
 }
+
+/// All distinct static segments of the full paths of the given routes, in sorted order.
+pub fn collect_static_segments(root_route_defs: &[RouteDef]) -> Vec<String> {
+    let mut static_segments = flatten(root_route_defs)
+        .flat_map(|route_def| PathSegments::parse(&route_def.full_path).segments)
+        .filter_map(|segment| match segment {
+            PathSegment::Static(s) => Some(s),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    static_segments.sort();
+    static_segments.dedup();
+    static_segments
+}
+
+/// Generates a hidden `__segments` module, holding one constant for each of the given static
+/// segments. Runtime patterns of all routes share these constants instead of repeating the strings
+/// of the segments their full paths have in common.
+pub fn generate_static_segments(static_segments: &[String]) -> proc_macro2::TokenStream {
+    let consts = static_segments.iter().map(|s| {
+        let name = static_segment_const(static_segments, s);
+        quote!(pub const #name: &str = #s;)
+    });
+    quote! {
+        #[doc(hidden)]
+        pub mod __segments {
+            #(#consts)*
+        }
+    }
+}
+
+/// All distinct full paths of the given routes, in sorted order.
+pub fn collect_patterns(root_route_defs: &[RouteDef]) -> Vec<String> {
+    let mut patterns = flatten(root_route_defs)
+        .map(|route_def| route_def.full_path.clone())
+        .collect::<Vec<_>>();
+    patterns.sort();
+    patterns.dedup();
+    patterns
+}
+
+/// Generates a hidden `__patterns` module, holding the table of the given full path patterns.
+/// Helpers reporting the pattern of a route index into this table instead of repeating the
+/// string, so that each pattern is stored once however many helpers mention it.
+pub fn generate_patterns(patterns: &[String]) -> proc_macro2::TokenStream {
+    let len = patterns.len();
+    quote! {
+        #[doc(hidden)]
+        pub mod __patterns {
+            pub const PATTERNS: [&str; #len] = [#(#patterns),*];
+        }
+    }
+}
+
+/// References the entry of the given full path in the `__patterns` table, from a module nested
+/// `depth` levels below the root module.
+pub fn pattern_ref(patterns: &[String], full_path: &str, depth: usize) -> proc_macro2::TokenStream {
+    let index = patterns
+        .binary_search_by(|p| p.as_str().cmp(full_path))
+        .expect("pattern to be collected");
+    let supers = module_prefix(depth);
+    quote!(#supers __patterns::PATTERNS[#index])
+}
+
+/// The path prefix of the root module from a module nested `depth` levels below it. The root
+/// module is named using `self`, so that items glob-imported into the function bodies of other
+/// routes modules never shadow its own.
+fn module_prefix(depth: usize) -> proc_macro2::TokenStream {
+    match depth {
+        0 => quote!(self::),
+        _ => {
+            let supers = (0..depth).map(|_| quote!(super::));
+            quote!(#(#supers)*)
+        }
+    }
+}
+
+fn static_segment_const(static_segments: &[String], segment: &str) -> syn::Ident {
+    let index = static_segments
+        .binary_search_by(|s| s.as_str().cmp(segment))
+        .expect("static segment to be collected");
+    format_ident!("SEGMENT_{}", index)
+}
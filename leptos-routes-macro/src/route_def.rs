@@ -1,10 +1,15 @@
-use crate::path::PathSegments;
-use crate::route_macro_args::RouteMacroArgs;
-use crate::util::to_pascal_case;
+use crate::path::{ParamType, PathSegment, PathSegments};
+use crate::route_macro_args::{
+    CacheArgs, CrudArgs, PaginationArgs, PanesArgs, ParamConstraint, PrefetchArg, RouteMacroArgs,
+    SecurityArgs, SsrModeArg, TypedParamKind,
+};
+use crate::util::{to_pascal_case, to_snake_case};
 use crate::ModulePath;
 use proc_macro2::Span;
-use quote::format_ident;
+use proc_macro_error2::abort;
+use quote::{format_ident, quote};
 use std::iter::from_fn;
+use syn::ext::IdentExt;
 use syn::spanned::Spanned;
 use syn::{Expr, Item, ItemMod, PathArguments, Visibility};
 use uuid::Uuid;
@@ -22,10 +27,161 @@ pub struct RouteDef {
     /// "/" or "/users"
     pub path: String,
     pub path_segments: PathSegments,
+    /// The full path of this route, including all parent paths, like "/users/:id/details".
+    pub full_path: String,
+
+    /// Whether this route (or any of its parents) should be excluded from indexing.
+    pub noindex: bool,
+
+    /// Regex constraints for the parameters declared in this route's own path.
+    pub constraints: Vec<ParamConstraint>,
+
+    /// Sample values of the parameters of this route and its parents, declared on it or inherited
+    /// from its parents.
+    pub samples: Vec<(String, String)>,
+
+    /// Default values of the optional parameters declared in this route's own path, used when they
+    /// are absent.
+    pub defaults: Vec<(String, String)>,
+
+    /// Types of the parameters declared in this route's own path. Untyped parameters are `&str`s.
+    pub param_types: Vec<(String, ParamType)>,
+
+    /// Enums to generate for parameters declared with `variants(...)`.
+    pub variants_enums: Vec<VariantsEnum>,
+
+    /// Whether this route is paginated through `?page=` and `?per_page=` query parameters.
+    pub pagination: Option<PaginationArgs>,
+
+    /// The type of this route's query string, if declared.
+    pub query_struct: Option<syn::Type>,
+
+    /// The query parameters distinguishing this route from the route it shares its path with,
+    /// which renders it.
+    pub query_variant: Vec<(String, String)>,
+
+    /// A function returning whether leaving this route requires confirmation.
+    pub leave_guard: Option<Expr>,
+
+    /// A function returning a future resolving to whether this route may be rendered, redirecting
+    /// to `guard_redirect` otherwise while rendering `guard_fallback` until it resolves.
+    pub guard: Option<Expr>,
+    pub guard_redirect: Option<syn::LitStr>,
+    pub guard_fallback: Option<Expr>,
+
+    /// The view rendered by an `<ErrorBoundary>` around this route's view.
+    pub error: Option<Expr>,
+
+    /// The view rendered by a `<Suspense>` (or `<Transition>`) around this route's view.
+    pub suspense_fallback: Option<Expr>,
+    pub transition: bool,
+
+    /// Overrides the matching order among sibling routes. Higher priorities are matched first.
+    pub priority: i32,
+
+    /// How responses rendering this route may be cached, declared on it or inherited from its
+    /// nearest parent declaring it.
+    pub cache: Option<CacheArgs>,
+
+    /// The security headers of responses rendering this route, each declared on it or inherited
+    /// from its nearest parent declaring it.
+    pub security: SecurityArgs,
+
+    /// When links to this route prefetch it, declared on it or inherited from its nearest parent
+    /// declaring it.
+    pub prefetch: Option<PrefetchArg>,
+
+    /// How this route is rendered on the server: the most restrictive mode declared on it or any
+    /// of its parents, as `leptos_router` applies the most restrictive mode of all nested routes.
+    pub ssr_mode: SsrModeArg,
+
+    /// Whether this route is deprecated, with an optional note.
+    pub deprecated: Option<Option<String>>,
+
+    /// The runtime feature flag declared on this route itself.
+    pub flag: Option<String>,
+
+    /// The runtime feature flags declared on this route and all of its parents, all of which must
+    /// be enabled for this route to be rendered.
+    pub flags: Vec<String>,
+
+    /// The keyboard shortcut navigating to this route, as space-separated keys like "g u".
+    pub shortcut: Option<String>,
+
+    /// The team owning this route, declared on it or inherited from its nearest parent declaring
+    /// it.
+    pub owner: Option<String>,
+
+    /// The section of the app this route belongs to, declared on it or inherited from its nearest
+    /// parent declaring it.
+    pub section: Option<String>,
+
+    /// The path of the API endpoint serving the resource of this route, relative to the
+    /// `api_prefix`, for routes declaring `crud` and their generated child routes.
+    pub endpoint: Option<String>,
+
+    /// The human-readable title of this route.
+    pub title: Option<String>,
+
+    /// Search keywords of this route, next to its title.
+    pub keywords: Vec<String>,
+
+    /// The message key of this route's label in navigation menus.
+    pub nav_label_key: Option<String>,
+
+    /// The roles permitted to access this route, declared on it or inherited from its nearest
+    /// parent declaring them. Routes without roles are accessible to everyone.
+    pub roles: Vec<String>,
+
+    /// Whether this route is deliberately accessible to everyone, declared on it or inherited from
+    /// its nearest parent declaring it, unless declaring `roles` itself.
+    pub public: bool,
+
+    /// The routes this route may be navigated to from, identified like their `data-route`
+    /// attributes. Routes without them may be navigated to from anywhere.
+    pub allowed_from: Vec<syn::LitStr>,
+
+    /// Former full paths of this route, redirected to it by servers.
+    pub previously: Vec<String>,
+
+    /// The HTTP status code responses rendering this route are sent with.
+    pub status: Option<u16>,
+
+    /// Whether the child routes of this route are ordered steps, navigable between siblings.
+    pub ordered: bool,
+
+    /// The position of this route among the steps of the wizard formed by its parent route.
+    pub step: Option<u32>,
+
+    /// Whether this leaf route is rendered on top of the sibling route rendered before it.
+    pub modal: bool,
+
+    /// Whether the view of this leaf route is kept alive while navigating to its sibling routes.
+    pub keep_alive: bool,
+
+    /// Whether this route is registered together with the other routes of the same path declaring
+    /// `merge`, under a single `<ParentRoute>`.
+    pub merge: bool,
+
+    /// Whether the subtree of this route is registered a second time under the `preview_prefix`.
+    pub previewable: bool,
+
+    /// The view rendered instead of the layout or view of this route under the `preview_prefix`.
+    pub preview: Option<Expr>,
+    pub preview_span: Option<Span>,
+
+    /// The list and detail panes rendered side by side instead of a single view.
+    pub panes: Option<PanesArgs>,
 
     pub layout: Option<Expr>,
     #[expect(unused)]
     pub layout_span: Option<Span>,
+    /// The name of the stack of layouts wrapping this route outside of its own layout.
+    pub layout_stack: Option<syn::LitStr>,
+    /// The captured values bound in the views of this route.
+    pub captures: Vec<syn::Ident>,
+    /// The CSS class added to an element wrapping the layout or view of this route.
+    pub class: Option<String>,
 
     pub fallback: Option<Expr>,
     #[expect(unused)]
@@ -34,6 +190,24 @@ pub struct RouteDef {
     pub view: Option<Expr>,
     pub view_span: Option<Span>,
 
+    /// The view rendered instead of `view` when the `IsBot` context marks the request as made by a
+    /// bot.
+    pub bot_view: Option<Expr>,
+
+    pub structured_data: Option<Expr>,
+    pub structured_data_span: Option<Span>,
+
+    /// Whether this is the `Index` route generated for a parent route declaring an `index` view.
+    pub is_index: bool,
+
+    /// Whether this route is declared with an absolute path, inheriting neither the path, the
+    /// parameters nor any other properties of its parent route. Absolute routes are only nested in
+    /// the module tree, being routed like top-level routes.
+    pub is_absolute: bool,
+
+    /// Whether this route only groups its children under its layout, without a path of its own.
+    pub is_group: bool,
+
     /// Pascal-cased name of the module that had this route annotation.
     pub name: syn::Ident,
     pub parent_struct: Option<(String, syn::Ident)>,
@@ -43,6 +217,120 @@ pub struct RouteDef {
 }
 
 impl RouteDef {
+    /// The regex constraint declared for the given parameter of this route's own path, if any.
+    pub fn constraint_for(&self, param: &str) -> Option<String> {
+        self.constraints
+            .iter()
+            .find(|c| c.param == param)
+            .map(|c| c.regex.clone())
+    }
+
+    /// The default value of the given optional parameter of this route's own path, if declared.
+    pub fn default_for(&self, param: &str) -> Option<String> {
+        self.defaults
+            .iter()
+            .find(|(name, _)| name == param)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// The type of the given parameter of this route's own path.
+    pub fn param_type_for(&self, param: &str) -> ParamType {
+        self.param_types
+            .iter()
+            .find(|(name, _)| name == param)
+            .map(|(_, ty)| ty.clone())
+            .unwrap_or(ParamType::Str)
+    }
+
+    /// The path of this route as declared to `leptos_router`. With `case_insensitive`, static
+    /// segments are declared, and therefore materialized, in lowercase.
+    pub fn declared_path(&self, case_insensitive: bool) -> String {
+        if !case_insensitive {
+            return self.path.clone();
+        }
+        self.path
+            .split('/')
+            .map(|segment| {
+                if segment.starts_with([':', '*']) {
+                    segment.to_string()
+                } else {
+                    segment.to_ascii_lowercase()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Whether any parameter of this route's own path must be validated after being matched.
+    pub fn has_param_checks(&self) -> bool {
+        !self.constraints.is_empty() || !self.param_types.is_empty()
+    }
+
+    /// One score per segment of the full path of this route: 4 for static segments, 3 for
+    /// parameters, 1 for optional parameters and 0 for wildcards, followed by a 2 marking the end of
+    /// the path. Compared lexicographically, more specific routes have higher specificities. The end
+    /// marker lets a route like "/users" take precedence over "/users/:id?" and "/users/*rest".
+    pub fn specificity(&self) -> Vec<u8> {
+        PathSegments::parse(&self.full_path)
+            .segments
+            .iter()
+            .map(|segment| match segment {
+                PathSegment::Static(_) => 4,
+                PathSegment::Param(_) => 3,
+                PathSegment::OptionalParam(_) => 1,
+                PathSegment::Wildcard(_) => 0,
+            })
+            .chain([2])
+            .collect()
+    }
+
+    /// The child routes nested below this route when routing, excluding any routes declared with an
+    /// absolute path and query variants, which the route sharing their path renders.
+    pub fn nested_children(&self) -> Vec<&RouteDef> {
+        self.children
+            .iter()
+            .filter(|child| !child.is_absolute && child.query_variant.is_empty())
+            .collect()
+    }
+
+    /// The `#[deprecated]` attribute of this route's struct and `materialize` function, if the
+    /// route is deprecated.
+    pub fn deprecated_attr(&self) -> Option<proc_macro2::TokenStream> {
+        self.deprecated.as_ref().map(|note| match note {
+            Some(note) => quote!(#[deprecated(note = #note)]),
+            None => quote!(#[deprecated]),
+        })
+    }
+
+    /// The name of the typed params struct generated for this route, like "DetailsParams".
+    pub fn params_struct_name(&self) -> syn::Ident {
+        format_ident!("{}Params", self.name)
+    }
+
+    /// The name of the `Route` enum variant referring to this route, like "RootUsersUserDetails".
+    pub fn variant_name(&self) -> syn::Ident {
+        let struct_name = &self.name;
+        let paths = self.found_in_module_path.without_first();
+
+        let mut variant_name = paths
+            .iter()
+            .next()
+            .cloned()
+            .map(|it| format_ident!("{}", to_pascal_case(&it.to_string())));
+        if variant_name.is_some() {
+            for next in paths.iter().skip(1) {
+                variant_name = Some(format_ident!(
+                    "{}{}",
+                    variant_name.unwrap(),
+                    to_pascal_case(&next.to_string())
+                ));
+            }
+        }
+        variant_name
+            .map(|it| format_ident!("{it}{struct_name}"))
+            .unwrap_or(struct_name.clone())
+    }
+
     pub fn full_module_path_to_struct_def(&self) -> syn::Path {
         let struct_name = &self.name;
         let paths = &self.found_in_module_path.without_first();
@@ -68,83 +356,727 @@ impl RouteDef {
             })
             .unwrap_or(struct_name.clone().into())
     }
+
+    /// The stable identifier of this route in the `data-route` attribute of its views, like
+    /// "root.users.user.details": the modules leading to its struct, followed by the snake-cased
+    /// struct name.
+    pub fn data_route_id(&self) -> String {
+        self.found_in_module_path
+            .without_first()
+            .iter()
+            .map(|module| module.to_string())
+            .chain([to_snake_case(&self.name.to_string())])
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// The query string `materialize` appends for a query variant, like "?tab=archived".
+    pub fn query_variant_suffix(&self) -> Option<String> {
+        if self.query_variant.is_empty() {
+            return None;
+        }
+        let pairs = self
+            .query_variant
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>();
+        Some(format!("?{}", pairs.join("&")))
+    }
+
+    /// The runtime check of a path holding the query parameters of a query variant, or `true` for
+    /// other routes.
+    pub fn query_variant_check(&self, path: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if self.query_variant.is_empty() {
+            return quote!(true);
+        }
+        let pairs = self.query_variant.iter().map(|(name, value)| quote!((#name, #value)));
+        quote!(::leptos_routes::matching::query_matches(#path, &[#(#pairs),*]))
+    }
+
+    /// The name of the `tracing` span of this route, like "route.root.users.user.details".
+    pub fn span_name(&self) -> String {
+        format!("route.{}", self.data_route_id())
+    }
+
+    /// The path of the typed params struct of this route, relative to the root module.
+    pub fn full_module_path_to_params_struct(&self) -> syn::Path {
+        let mut path = self.full_module_path_to_struct_def();
+        path.segments.last_mut().expect("present").ident = self.params_struct_name();
+        path
+    }
+}
+
+/// An enum generated for a parameter declared with `variants(...)`.
+#[derive(Debug)]
+pub struct VariantsEnum {
+    pub name: syn::Ident,
+    /// The allowed values, each becoming one variant.
+    pub variants: Vec<syn::LitStr>,
+}
+
+/// Information a route passes down to its child routes while collecting route definitions.
+pub struct ParentRoute {
+    /// The path declared on the parent route itself, "/" or "/users".
+    pub path: String,
+    pub struct_name: syn::Ident,
+    /// The parent's full path, including all of its own parent paths.
+    pub full_path: String,
+    pub noindex: bool,
+    pub cache: Option<CacheArgs>,
+    pub security: SecurityArgs,
+    pub prefetch: Option<PrefetchArg>,
+    pub ssr_mode: SsrModeArg,
+    pub flags: Vec<String>,
+    pub samples: Vec<(String, String)>,
+    pub owner: Option<String>,
+    pub section: Option<String>,
+    pub roles: Vec<String>,
+    pub public: bool,
 }
 
 pub fn collect_route_definitions(
     module: &ItemMod,
-    parent_path: Option<&str>,
-    parent_struct: Option<&syn::Ident>,
+    parent: Option<&ParentRoute>,
     route_defs: &mut Vec<RouteDef>,
     module_path: ModulePath,
 ) {
     let module_name = &module.ident;
-    let vis = &module.vis;
 
     // Create current module path
     let mut current_module_path = module_path.clone();
     current_module_path.push(module_name.clone());
 
-    let args = match RouteMacroArgs::parse(&module.attrs) {
-        None => {
-            // This module was not annotated with `#[route]`. Skip it and all potential submodules.
-            return;
+    let all_args = RouteMacroArgs::parse_all(&module.attrs);
+    if all_args.is_empty() {
+        // This module was not annotated with `#[route]`. Skip it and all potential submodules.
+        return;
+    }
+
+    // The structs of child routes are generated in this module and referenced by the `Route` enum
+    // of the root module, so the root module must be able to see into it.
+    let has_child_routes = module.content.as_ref().is_some_and(|(_, items)| {
+        items.iter().any(|item| {
+            matches!(item, Item::Mod(child_module)
+                if !RouteMacroArgs::parse_all(&child_module.attrs).is_empty())
+        })
+    });
+    let depth = current_module_path.without_first().len();
+    if has_child_routes && !visible_to_root_module(&module.vis, depth) {
+        abort!(
+            module_name,
+            "The structs of this route's child routes are generated in this module and referenced by the `Route` enum of the routes module, which cannot see into it. Declare this module `pub` or `pub(crate)`."
+        );
+    }
+
+    if all_args.len() > 1 {
+        // Multiple routes declared on one module are siblings, sharing no child routes.
+        if has_child_routes {
+            abort!(
+                all_args[1].route_ident_span,
+                "A module declaring multiple routes must not contain child routes. Move the additional routes into their own module."
+            );
+        }
+        let mut names = Vec::new();
+        for args in &all_args {
+            let name = route_struct_name(module_name, args);
+            if names.contains(&name) {
+                abort!(
+                    args.route_ident_span,
+                    "Multiple routes of one module need distinct struct names. Add a distinct name = \"...\" argument to this route."
+                );
+            }
+            names.push(name);
+        }
+    }
+
+    for mut args in all_args {
+        let index = args.index.take().map(|index| (index, args.index_span.expect("present")));
+        // A fallback taking the unmatched path only renders the paths below this route next to an
+        // index.
+        let renders_own_page = args.fallback.as_ref().is_some_and(|f| !takes_unmatched_path(f));
+        if let (Some((_, index_span)), true) = (&index, renders_own_page) {
+            abort!(
+                *index_span,
+                "\"index\" and \"fallback\" both render the page of this route. Remove one of them."
+            );
+        }
+        let crud = args.crud.take();
+        let mut route_def = create_route_def(module, args, parent, &current_module_path);
+
+        let as_parent = ParentRoute {
+            path: route_def.path.clone(),
+            struct_name: route_def.name.clone(),
+            full_path: route_def.full_path.clone(),
+            noindex: route_def.noindex,
+            cache: route_def.cache,
+            security: route_def.security,
+            prefetch: route_def.prefetch,
+            ssr_mode: route_def.ssr_mode,
+            flags: route_def.flags.clone(),
+            samples: route_def.samples.clone(),
+            owner: route_def.owner.clone(),
+            section: route_def.section.clone(),
+            roles: route_def.roles.clone(),
+            public: route_def.public,
+        };
+        if let Some((_, items)) = &module.content {
+            for item in items.iter() {
+                if let Item::Mod(child_module) = item {
+                    collect_route_definitions(
+                        child_module,
+                        Some(&as_parent),
+                        &mut route_def.children,
+                        current_module_path.clone(),
+                    );
+                }
+            }
+        }
+        for crud_module in crud.iter().flat_map(|crud| crud_modules(&module.vis, crud)) {
+            collect_route_definitions(
+                &crud_module,
+                Some(&as_parent),
+                &mut route_def.children,
+                current_module_path.clone(),
+            );
+        }
+        if let Some(crud) = &crud {
+            // The index and "new" routes relate to the collection of the resource, the "show" and
+            // "edit" routes to a single resource.
+            let collection = route_def.full_path.clone();
+            let member = join_paths(&collection, &format!("/:{}", crud.param));
+            let crud_children = route_def.children.len() - 3;
+            let endpoints = [collection.clone(), member.clone(), member];
+            for (child, endpoint) in route_def.children[crud_children..].iter_mut().zip(endpoints) {
+                child.endpoint = Some(endpoint);
+            }
+            route_def.endpoint = Some(collection);
+        }
+        if route_def.ordered && route_def.children.is_empty() {
+            abort!(
+                route_def.route_ident_span,
+                "\"ordered\" orders the child routes of this route, but it has none. Add child routes or remove the argument."
+            );
+        }
+        if route_def.modal && (!route_def.children.is_empty() || route_def.view.is_none()) {
+            abort!(
+                route_def.route_ident_span,
+                "\"modal\" renders the \"view\" of a leaf route on top of its sibling routes. Add a \"view\" to a route without child routes or remove the argument."
+            );
+        }
+        if route_def.keep_alive && !route_def.children.is_empty() {
+            abort!(
+                route_def.route_ident_span,
+                "\"keep_alive\" keeps the view of a leaf route alive. Move the argument to the child routes or remove it."
+            );
+        }
+        if route_def.merge && route_def.children.is_empty() {
+            abort!(
+                route_def.route_ident_span,
+                "\"merge\" registers the child routes of this route together with those of other routes of the same path, but it has none. Add child routes or remove the argument."
+            );
+        }
+        if let (Some(panes), false) = (&route_def.panes, route_def.children.is_empty()) {
+            abort!(
+                panes.span,
+                "\"panes\" render the view of a leaf route. Move the child routes into the panes or remove the argument."
+            );
+        }
+        let steps = route_def.children.iter().filter(|child| child.step.is_some()).collect::<Vec<_>>();
+        for (i, step) in steps.iter().enumerate() {
+            if step.path_segments.param_names().next().is_some() {
+                abort!(
+                    step.route_ident_span,
+                    "Steps share the parameters of their parent route, as the wizard navigates between them. Move the parameters to the parent route."
+                );
+            }
+            if steps[..i].iter().any(|other| other.step == step.step) {
+                abort!(
+                    step.route_ident_span,
+                    "Another child route already is step {}. Give every step a distinct number.",
+                    step.step.expect("present")
+                );
+            }
         }
-        Some(args) => args,
+        if let Some((index, index_span)) = index {
+            let index_def = create_index_route_def(&route_def, index, index_span);
+            route_def.children.push(index_def);
+        }
+        route_defs.push(route_def);
+    }
+}
+
+/// The modules of the "new", "show" and "edit" child routes of a route declaring `crud`, as if
+/// they were declared in its module. The "new" route is declared first, as it is matched before
+/// the "show" route anyway.
+fn crud_modules(vis: &Visibility, crud: &CrudArgs) -> Vec<ItemMod> {
+    // Child routes must be visible to the `Route` enum.
+    let vis = match vis {
+        Visibility::Inherited => quote!(pub(crate)),
+        Visibility::Restricted(restricted) if restricted.path.is_ident("self") => quote!(pub(crate)),
+        vis => quote!(#vis),
     };
+    let show_path = format!("/:{}", crud.param);
+    let edit_path = format!("/:{}/edit", crud.param);
+    [
+        ("new", "/new", &crud.new),
+        ("show", show_path.as_str(), &crud.show),
+        ("edit", edit_path.as_str(), &crud.edit),
+    ]
+    .into_iter()
+    .map(|(name, path, view)| {
+        let ident = syn::Ident::new(name, crud.span);
+        let view = view.as_ref().map(|view| quote!(, view = #view));
+        syn::parse_quote_spanned! {crud.span=>
+            #[route(#path #view)]
+            #vis mod #ident {}
+        }
+    })
+    .collect()
+}
+
+/// Whether a module with the given visibility, declared `depth` modules below the root routes
+/// module, is visible to the root module. Paths of `pub(in ...)` not made of `super` segments are
+/// assumed to be visible, leaving them to the compiler.
+fn visible_to_root_module(vis: &Visibility, depth: usize) -> bool {
+    match vis {
+        Visibility::Public(_) => true,
+        Visibility::Inherited => depth == 0,
+        Visibility::Restricted(restricted) => {
+            let segments = &restricted.path.segments;
+            if restricted.path.is_ident("self") {
+                depth == 0
+            } else if segments.iter().all(|segment| segment.ident == "super") {
+                segments.len() >= depth
+            } else {
+                true
+            }
+        }
+    }
+}
+
+/// The name of the struct generated for a route, being its declared name or the pascal-cased
+/// module name.
+fn route_struct_name(module_name: &syn::Ident, args: &RouteMacroArgs) -> syn::Ident {
+    args.name
+        .clone()
+        .unwrap_or_else(|| format_ident!("{}", to_pascal_case(&module_name.to_string())))
+}
+
+fn create_route_def(
+    module: &ItemMod,
+    args: RouteMacroArgs,
+    parent: Option<&ParentRoute>,
+    current_module_path: &ModulePath,
+) -> RouteDef {
+    let is_absolute = args.absolute && parent.is_some();
+    let parent = parent.filter(|_| !is_absolute);
+
+    if let (Some(_), None) = (args.step, parent) {
+        abort!(
+            args.route_ident_span,
+            "A \"step\" orders a route among the children of its parent route, but this route has no parent. Remove the argument."
+        );
+    }
+
+    if let (true, None) = (args.modal, parent) {
+        abort!(
+            args.route_ident_span,
+            "\"modal\" renders a route on top of its sibling routes, but this route has no parent. Remove the argument."
+        );
+    }
+
+    if let (true, None) = (args.keep_alive, parent) {
+        abort!(
+            args.route_ident_span,
+            "\"keep_alive\" keeps a view alive while navigating to its sibling routes, but this route has no parent. Remove the argument."
+        );
+    }
+
+    if args.keep_alive && args.modal {
+        abort!(
+            args.route_ident_span,
+            "A \"modal\" route is rendered on top of its siblings and cannot be kept alive. Remove \"keep_alive\" or \"modal\"."
+        );
+    }
 
-    let mut route_def = RouteDef {
+    let path_segments = PathSegments::parse(&args.route_path_segments);
+    if let (Some(panes), None) = (&args.panes, detail_param(&path_segments)) {
+        abort!(
+            panes.span,
+            "The \"detail\" pane is rendered while the trailing optional parameter of the route is present, but its path ends with none. Add one, like \"/mail/:folder/:message?\"."
+        );
+    }
+    for constraint in &args.constraints {
+        if !path_segments.param_names().any(|name| name == constraint.param) {
+            abort!(
+                constraint.span,
+                "Unknown parameter \"{}\". Constraints can only be declared for parameters of this route's own path.",
+                constraint.param
+            );
+        }
+    }
+
+    for (param, _) in &args.samples {
+        if !path_segments.param_names().any(|name| param == name) {
+            abort!(
+                param.span(),
+                "Unknown parameter \"{}\". Samples can only be declared for parameters of this route's own path.",
+                param
+            );
+        }
+    }
+
+    for (param, value) in &args.defaults {
+        let is_optional = path_segments
+            .segments
+            .iter()
+            .any(|seg| matches!(seg, PathSegment::OptionalParam(name) if param == name));
+        if !is_optional {
+            abort!(
+                param.span(),
+                "Unknown optional parameter \"{}\". Defaults can only be declared for optional parameters of this route's own path, like \":{}?\".",
+                param,
+                param
+            );
+        }
+        let constraint = args.constraints.iter().find(|c| param == &c.param);
+        if let Some(constraint) = constraint {
+            let anchored = format!("^(?:{})$", constraint.regex);
+            if !regex::Regex::new(&anchored).is_ok_and(|regex| regex.is_match(value)) {
+                abort!(
+                    param.span(),
+                    "The default \"{}\" does not satisfy the constraint declared for parameter \"{}\".",
+                    value,
+                    param
+                );
+            }
+        }
+    }
+
+    let struct_name = route_struct_name(&module.ident, &args);
+    let mut param_types = Vec::new();
+    let mut variants_enums = Vec::new();
+    for typed_param in &args.typed_params {
+        if !path_segments.param_names().any(|name| name == typed_param.param) {
+            abort!(
+                typed_param.span,
+                "Unknown parameter \"{}\". Types can only be declared for parameters of this route's own path.",
+                typed_param.param
+            );
+        }
+        let ty = match &typed_param.kind {
+            TypedParamKind::Custom(ty) => match &typed_param.format {
+                None => ParamType::custom(*ty.clone()),
+                Some(format) => ParamType::formatted(*ty.clone(), format.value()),
+            },
+            TypedParamKind::Variants(variants) => {
+                let enum_name = format_ident!("{struct_name}{}", to_pascal_case(&typed_param.param));
+                let mut path = current_module_path.without_first().to_vec();
+                path.push(enum_name.clone());
+                variants_enums.push(VariantsEnum {
+                    name: enum_name,
+                    variants: variants.clone(),
+                });
+                ParamType::Variants(path)
+            }
+        };
+        param_types.push((typed_param.param.clone(), ty));
+    }
+
+    RouteDef {
         id: Uuid::new_v4(),
         module_span: module.span(),
         route_ident_span: args.route_ident_span,
         path: args.route_path_segments.clone(),
-        path_segments: PathSegments::parse(&args.route_path_segments),
+        path_segments,
         layout: args.layout,
         layout_span: args.layout_span,
+        layout_stack: args.layout_stack,
+        captures: args.captures,
+        class: args.class,
         fallback: args.fallback,
         fallback_span: args.fallback_span,
         view: args.view,
         view_span: args.view_span,
-        name: format_ident!("{}", to_pascal_case(&module_name.to_string())),
-        parent_struct: match (parent_path, parent_struct) {
-            (Some(parent_path), Some(parent_struct)) => {
-                Some((parent_path.to_owned(), parent_struct.clone()))
-            }
-            (None, None) => None,
-            _ => panic!("Invalid state"), // TODO: phrase
+        bot_view: args.bot_view,
+        structured_data: args.structured_data,
+        structured_data_span: args.structured_data_span,
+        name: struct_name,
+        full_path: join_paths(
+            parent.map(|p| p.full_path.as_str()).unwrap_or("/"),
+            &args.route_path_segments,
+        ),
+        noindex: args.noindex || parent.is_some_and(|p| p.noindex),
+        constraints: args.constraints,
+        samples: parent
+            .map(|p| p.samples.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .chain(args.samples.iter().map(|(param, value)| (param.to_string(), value.clone())))
+            .collect(),
+        defaults: args
+            .defaults
+            .iter()
+            .map(|(param, value)| (param.to_string(), value.clone()))
+            .collect(),
+        param_types,
+        variants_enums,
+        pagination: args.pagination,
+        query_struct: args.query_struct,
+        query_variant: args
+            .query_variant
+            .into_iter()
+            .map(|(name, value)| (name.unraw().to_string(), value))
+            .collect(),
+        leave_guard: args.leave_guard,
+        guard: args.guard,
+        guard_redirect: args.guard_redirect,
+        guard_fallback: args.guard_fallback,
+        error: args.error,
+        suspense_fallback: args.suspense_fallback,
+        transition: args.transition,
+        priority: args.priority,
+        cache: args.cache.or(parent.and_then(|p| p.cache)),
+        security: SecurityArgs {
+            referrer: args.security.referrer.or(parent.and_then(|p| p.security.referrer)),
+            frame: args.security.frame.or(parent.and_then(|p| p.security.frame)),
+        },
+        prefetch: args.prefetch.or(parent.and_then(|p| p.prefetch)),
+        ssr_mode: args
+            .ssr
+            .unwrap_or_default()
+            .max(parent.map(|p| p.ssr_mode).unwrap_or_default()),
+        deprecated: args.deprecated,
+        flags: parent
+            .map(|p| p.flags.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .chain(args.flag.clone())
+            .collect(),
+        flag: args.flag,
+        shortcut: args.shortcut,
+        owner: args.owner.or_else(|| parent.and_then(|p| p.owner.clone())),
+        section: args.section.or_else(|| parent.and_then(|p| p.section.clone())),
+        endpoint: None,
+        title: args.title,
+        keywords: args.keywords,
+        nav_label_key: args.nav_label_key,
+        public: args.public || (args.roles.is_empty() && parent.is_some_and(|p| p.public)),
+        roles: if args.roles.is_empty() {
+            parent.map(|p| p.roles.clone()).unwrap_or_default()
+        } else {
+            args.roles
+        },
+        allowed_from: args.allowed_from,
+        previously: args.previously,
+        status: args.status,
+        ordered: args.ordered,
+        step: args.step,
+        modal: args.modal,
+        keep_alive: args.keep_alive,
+        merge: args.merge,
+        previewable: args.previewable,
+        preview: args.preview,
+        preview_span: args.preview_span,
+        panes: args.panes,
+        parent_struct: parent.map(|p| (p.path.clone(), p.struct_name.clone())),
+        // The struct is generated in the parent module and referenced by the `Route` enum, so it
+        // is made `pub` where the module's own visibility would hide it from the root module.
+        vis: if visible_to_root_module(&module.vis, current_module_path.without_first().len()) {
+            module.vis.clone()
+        } else {
+            syn::parse_quote!(pub)
         },
-        vis: vis.clone(),
         found_in_module_path: current_module_path.clone(),
+        is_index: false,
+        is_absolute,
+        is_group: args.group,
         children: Vec::new(),
-    };
+    }
+}
 
-    if let Some((_, items)) = &module.content {
-        for item in items.iter() {
-            if let Item::Mod(child_module) = item {
-                collect_route_definitions(
-                    child_module,
-                    Some(&args.route_path_segments),
-                    Some(&route_def.name.clone()),
-                    &mut route_def.children,
-                    current_module_path.clone(),
-                );
-            }
-        }
+/// The first of the optional parameters ending the given path, selecting what the "detail" pane of
+/// a route declaring `panes` shows, like "message" of "/mail/:folder/:message?".
+pub fn detail_param(path_segments: &PathSegments) -> Option<&str> {
+    path_segments
+        .segments
+        .iter()
+        .rev()
+        .map_while(|segment| match segment {
+            PathSegment::OptionalParam(name) => Some(name.as_str()),
+            _ => None,
+        })
+        .last()
+}
+
+/// Whether the given fallback view is a closure taking the path not matched by any child route,
+/// like `|path| view! { <SectionNotFound path/> }`.
+pub fn takes_unmatched_path(fallback: &Expr) -> bool {
+    matches!(fallback, Expr::Closure(closure) if closure.inputs.len() == 1)
+}
+
+/// Creates the `Index` route of the given parent route, matching the parent's path exactly.
+fn create_index_route_def(parent: &RouteDef, view: Expr, view_span: Span) -> RouteDef {
+    // The index struct is generated in the module of its parent route.
+    let mut found_in_module_path = parent.found_in_module_path.clone();
+    found_in_module_path.push(format_ident!("index"));
+
+    RouteDef {
+        id: Uuid::new_v4(),
+        module_span: view_span,
+        route_ident_span: view_span,
+        path: String::new(),
+        path_segments: PathSegments::parse(""),
+        full_path: parent.full_path.clone(),
+        noindex: parent.noindex,
+        constraints: Vec::new(),
+        samples: parent.samples.clone(),
+        defaults: Vec::new(),
+        param_types: Vec::new(),
+        variants_enums: Vec::new(),
+        pagination: None,
+        query_struct: None,
+        query_variant: Vec::new(),
+        leave_guard: None,
+        guard: None,
+        guard_redirect: None,
+        guard_fallback: None,
+        error: None,
+        suspense_fallback: None,
+        transition: false,
+        priority: parent.priority,
+        cache: parent.cache,
+        security: parent.security,
+        prefetch: parent.prefetch,
+        ssr_mode: parent.ssr_mode,
+        deprecated: None,
+        flag: None,
+        flags: parent.flags.clone(),
+        shortcut: None,
+        owner: parent.owner.clone(),
+        section: parent.section.clone(),
+        endpoint: parent.endpoint.clone(),
+        title: None,
+        keywords: Vec::new(),
+        nav_label_key: None,
+        roles: parent.roles.clone(),
+        public: parent.public,
+        allowed_from: parent.allowed_from.clone(),
+        previously: Vec::new(),
+        ordered: false,
+        step: None,
+        modal: false,
+        keep_alive: false,
+        merge: false,
+        previewable: false,
+        preview: None,
+        preview_span: None,
+        panes: None,
+        // The index renders the parent's own page.
+        status: parent.status,
+        layout: None,
+        layout_span: None,
+        layout_stack: None,
+        // The index view is declared on its parent route.
+        captures: parent.captures.clone(),
+        class: None,
+        fallback: None,
+        fallback_span: None,
+        view: Some(view),
+        view_span: Some(view_span),
+        bot_view: None,
+        // The index renders the parent's own page, like a fallback would.
+        structured_data: parent.structured_data.clone(),
+        structured_data_span: parent.structured_data_span,
+        is_index: true,
+        is_absolute: false,
+        is_group: false,
+        name: format_ident!("Index"),
+        parent_struct: Some((parent.path.clone(), parent.name.clone())),
+        vis: parent.vis.clone(),
+        found_in_module_path,
+        children: Vec::new(),
+    }
+}
+
+/// Joins a parent path and a child path, both starting with a '/', into one path.
+pub fn join_paths(parent: &str, child: &str) -> String {
+    match (parent, child) {
+        (parent, "") => parent.to_owned(),
+        ("/", child) => child.to_owned(),
+        (parent, "/") => parent.to_owned(),
+        (parent, child) => format!("{parent}{child}"),
     }
-    route_defs.push(route_def);
 }
 
 pub fn flatten(root_route_defs: &[RouteDef]) -> impl Iterator<Item = &RouteDef> {
+    // Children are pushed in reverse, so that routes are yielded in declaration order.
     let mut stack = Vec::new();
-    stack.extend(root_route_defs);
+    stack.extend(root_route_defs.iter().rev());
     from_fn(move || {
         if let Some(node) = stack.pop() {
-            stack.extend(node.children.as_slice());
+            stack.extend(node.children.iter().rev());
             return Some(node);
         }
         None
     })
 }
 
+/// The routes to declare at the top level of a router: all top-level routes, as well as all routes
+/// declared with an absolute path, wherever they are nested in the module tree. Query variants are
+/// rendered by the route sharing their path.
+pub fn routing_roots(root_route_defs: &[RouteDef]) -> Vec<&RouteDef> {
+    root_route_defs
+        .iter()
+        .chain(flatten(root_route_defs).filter(|route_def| route_def.is_absolute))
+        .filter(|route_def| route_def.query_variant.is_empty())
+        .collect()
+}
+
+/// Orders the given routes to be matched in: by descending priority, then by descending
+/// specificity, keeping the declaration order of routes ranking equally. Query variants are
+/// matched before the route sharing their path.
+pub fn sort_by_match_order(route_defs: &mut [&RouteDef]) {
+    route_defs.sort_by(|a, b| {
+        b.priority
+            .cmp(&a.priority)
+            .then_with(|| b.specificity().cmp(&a.specificity()))
+            .then_with(|| a.query_variant.is_empty().cmp(&b.query_variant.is_empty()))
+    });
+}
+
+/// Checks that every query variant among the given routes and their children shares its path with
+/// a sibling route rendering it, having no views or child routes of its own.
+pub fn check_query_variants(siblings: &[RouteDef]) {
+    for route_def in siblings {
+        check_query_variants(&route_def.children);
+        if route_def.query_variant.is_empty() {
+            continue;
+        }
+        let renders = route_def.view.is_some()
+            || route_def.layout.is_some()
+            || route_def.fallback.is_some()
+            || route_def.bot_view.is_some()
+            || route_def.panes.is_some();
+        if renders || !route_def.children.is_empty() {
+            abort!(
+                route_def.route_ident_span,
+                "A \"variant\" is rendered by the route it shares its path with, so it declares no views or child routes of its own. Remove them, reading the query parameters in the view of that route instead."
+            );
+        }
+        let has_base = siblings.iter().any(|sibling| {
+            sibling.query_variant.is_empty() && sibling.full_path == route_def.full_path
+        });
+        if !has_base {
+            abort!(
+                route_def.route_ident_span,
+                "A \"variant\" is rendered by a sibling route of the same path \"{}\", but there is none. Declare a route of that path without \"variant\".",
+                route_def.full_path
+            );
+        }
+    }
+}
+
 pub fn find_parent_of<'a>(
     root_route_defs: &'a [RouteDef],
     current: &'a RouteDef,
@@ -1,6 +1,9 @@
 use proc_macro2::Span;
 use proc_macro_error2::abort;
+use crate::util::unexpected;
 use crate::ExprWrapper;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
 use syn::{Attribute, Expr};
 
 pub struct RouteMacroArgs {
@@ -9,41 +12,388 @@ pub struct RouteMacroArgs {
     /// A path, defined like: "/" or "/users"
     pub route_path_segments: String,
 
+    /// Whether the path does not extend the path of the parent route, defined like: "!/legal" or
+    /// "absolute" or "absolute = true"
+    pub absolute: bool,
+
+    /// Whether this route only groups its children, without a path of its own, defined like:
+    /// "group"
+    pub group: bool,
+
+    /// The name of the generated struct, defined like: "name = "SignIn"". Defaults to the
+    /// pascal-cased module name.
+    pub name: Option<syn::Ident>,
+
     /// A wrapper view, defined like: "wrap=MainLayout" or "wrap=|| view! { <MainLayout/> }"
     pub layout: Option<Expr>,
     pub layout_span: Option<Span>,
 
+    /// The name of a stack of layouts declared in `#[routes(layout_stacks(...))]`, wrapping this
+    /// route outside of its own layout, defined like: "layout_stack = "auth_stack""
+    pub layout_stack: Option<syn::LitStr>,
+
+    /// The values declared in `#[routes(captures(...))]` bound in the views of this route,
+    /// defined like: "captures(user_ctx)"
+    pub captures: Vec<syn::Ident>,
+
+    /// A CSS class added to an element wrapping the layout or view of this route, defined like:
+    /// "class = "theme-admin""
+    pub class: Option<String>,
+
     pub fallback: Option<Expr>,
     pub fallback_span: Option<Span>,
 
+    /// The view of the route's own page, generating an `Index` struct, defined like: "index=UsersList"
+    pub index: Option<Expr>,
+    pub index_span: Option<Span>,
+
     /// The route view, defined like: "view=SomePage" or "view=|| view! { <SomePage/> }"
     pub view: Option<Expr>,
     pub view_span: Option<Span>,
+
+    /// The view rendered instead of `view` for bots and crawlers, defined like:
+    /// "bot_view=StaticSnapshot"
+    pub bot_view: Option<Expr>,
+    pub bot_view_span: Option<Span>,
+
+    /// A function producing JSON-LD for this route, defined like: "structured_data=user_jsonld"
+    pub structured_data: Option<Expr>,
+    pub structured_data_span: Option<Span>,
+
+    /// Whether this route and all of its children should be excluded from indexing, defined like: "noindex"
+    pub noindex: bool,
+
+    /// Whether the child routes of this route are ordered steps, navigable using their generated
+    /// `next_sibling` and `prev_sibling` functions, defined like: "ordered"
+    pub ordered: bool,
+
+    /// The position of this route among the steps of the wizard its parent route forms, defined
+    /// like: "step = 2"
+    pub step: Option<u32>,
+
+    /// Whether this leaf route is rendered on top of the sibling route rendered before it, defined
+    /// like: "modal" or "modal = true"
+    pub modal: bool,
+
+    /// Whether the view of this leaf route is kept alive while navigating to its sibling routes,
+    /// defined like: "keep_alive" or "keep_alive = true"
+    pub keep_alive: bool,
+
+    /// Whether this route is registered together with the other routes of the same path declaring
+    /// `merge`, mounting their shared layout once, defined like: "merge" or "merge = true"
+    pub merge: bool,
+
+    /// Whether the subtree of this route is registered a second time under the `preview_prefix`,
+    /// defined like: "previewable" or "previewable = true"
+    pub previewable: bool,
+
+    /// The view rendered instead of the layout (or view, on leaf routes) of this route when rendered
+    /// under the `preview_prefix`, defined like: "preview = "NewUsersList""
+    pub preview: Option<Expr>,
+    pub preview_span: Option<Span>,
+
+    /// The two panes rendered side by side instead of a single view, defined like:
+    /// "panes(list = "MessageList", detail = "MessageView")"
+    pub panes: Option<PanesArgs>,
+
+    /// The conventional child routes of a resource, defined like:
+    /// "crud(param = "id", views(index = "UserList", show = "User", new = "NewUser", edit = "EditUser"))"
+    pub crud: Option<CrudArgs>,
+
+    /// Regex constraints for parameters of this route, defined like: "constraint(id = r"\d+")"
+    pub constraints: Vec<ParamConstraint>,
+
+    /// Types of parameters of this route, defined like: "params(tab = "SettingsTab")" or
+    /// "params(tab = variants("profile", "security"))"
+    pub typed_params: Vec<TypedParam>,
+
+    /// Sample values of parameters of this route, used by the generated route index page, defined
+    /// like: "sample(id = "42")"
+    pub samples: Vec<(syn::Ident, String)>,
+
+    /// Default values of optional parameters of this route, used when they are absent, defined
+    /// like: "defaults(page = "1")"
+    pub defaults: Vec<(syn::Ident, String)>,
+
+    /// Pagination through query parameters, defined like: "pagination" or "pagination(per_page = 50)"
+    pub pagination: Option<PaginationArgs>,
+
+    /// The type of this route's query string, defined like: "query_struct = "crate::ItemFilters""
+    pub query_struct: Option<syn::Type>,
+
+    /// The query parameters distinguishing this route from the route it shares its path with,
+    /// which renders it, defined like: "variant(tab = "archived")"
+    pub query_variant: Vec<(syn::Ident, String)>,
+
+    /// A function returning whether leaving this route requires confirmation, defined like:
+    /// "leave_guard = "has_unsaved_changes""
+    pub leave_guard: Option<Expr>,
+
+    /// A function returning a future resolving to whether this route may be rendered, defined
+    /// like: "guard = "is_signed_in""
+    pub guard: Option<Expr>,
+    pub guard_span: Option<Span>,
+
+    /// The path navigated to when the guard of this route denies access, defined like:
+    /// "guard_redirect = "/login""
+    pub guard_redirect: Option<syn::LitStr>,
+
+    /// The view rendered while the guard of this route is pending, defined like:
+    /// "guard_fallback = "Spinner""
+    pub guard_fallback: Option<Expr>,
+
+    /// The view rendered when this route's view fails, defined like: "error=route_error" or
+    /// "error=|errors| view! { <RouteError errors/> }"
+    pub error: Option<Expr>,
+
+    /// The view rendered while this route's view is loading, defined like: "suspense_fallback=Spinner"
+    pub suspense_fallback: Option<Expr>,
+
+    /// Whether to keep showing the previous view while loading instead, defined like: "transition"
+    pub transition: bool,
+
+    /// Overrides the matching order among sibling routes, defined like: "priority = 10" or
+    /// "priority = -1". Higher priorities are matched first. Defaults to 0.
+    pub priority: i32,
+
+    /// How responses rendering this route and all of its children may be cached, defined like:
+    /// "cache(max_age = 3600, swr = 86400)"
+    pub cache: Option<CacheArgs>,
+
+    /// The security headers of responses rendering this route and all of its children, defined
+    /// like: "security(referrer = "no-referrer", frame = "deny")"
+    pub security: SecurityArgs,
+
+    /// How this route is rendered on the server, defined like: "ssr = "Async"". One of
+    /// "OutOfOrder", "PartiallyBlocked", "InOrder", "Async" or "Static".
+    pub ssr: Option<SsrModeArg>,
+
+    /// When links to this route prefetch it, defined like: "prefetch = "hover"". One of "hover",
+    /// "visible" or "none".
+    pub prefetch: Option<PrefetchArg>,
+
+    /// Whether this route is deprecated, with an optional note, defined like: "deprecated" or
+    /// "deprecated = "use /new-path""
+    pub deprecated: Option<Option<String>>,
+
+    /// The runtime feature flag this route is only rendered with, defined like:
+    /// "flag = "new_dashboard""
+    pub flag: Option<String>,
+
+    /// The keyboard shortcut navigating to this route, defined like: "shortcut = "g u""
+    pub shortcut: Option<String>,
+
+    /// The team owning this route, defined like: "owner = "team-payments""
+    pub owner: Option<String>,
+
+    /// The section of the app this route belongs to, for middleware, theming or access control,
+    /// defined like: "section = "admin""
+    pub section: Option<String>,
+
+    /// The human-readable title of this route, defined like: "title = "Users""
+    pub title: Option<String>,
+
+    /// Search keywords of this route, defined like: "keywords("people", "members")"
+    pub keywords: Vec<String>,
+
+    /// The message key of this route's label in navigation menus, resolved by the app's
+    /// translations, defined like: "nav(label_key = "menu.users")"
+    pub nav_label_key: Option<String>,
+
+    /// The roles permitted to access this route, defined like: "roles("admin", "editor")"
+    pub roles: Vec<String>,
+
+    /// Whether this route is deliberately accessible to everyone, defined like: "public" or
+    /// "public = true"
+    pub public: bool,
+
+    /// The routes this route may be navigated to from, identified like their `data-route`
+    /// attributes, defined like: "allowed_from("login", "signup")"
+    pub allowed_from: Vec<syn::LitStr>,
+
+    /// The HTTP status code responses rendering this route are sent with, defined like:
+    /// "status = 410"
+    pub status: Option<u16>,
+
+    /// Former full paths of this route, redirected to it after renaming, defined like:
+    /// "previously = "/old/users"". May be given multiple times.
+    pub previously: Vec<String>,
+}
+
+/// The `leptos_router::SsrMode` of a route, ordered from least to most restrictive like the
+/// variants of `SsrMode`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SsrModeArg {
+    #[default]
+    OutOfOrder,
+    PartiallyBlocked,
+    InOrder,
+    Async,
+    Static,
+}
+
+/// When links to a route prefetch it, like the variants of `leptos_routes::prefetch::Prefetch`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefetchArg {
+    None,
+    Hover,
+    Visible,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CacheArgs {
+    /// The number of seconds a response stays fresh.
+    pub max_age: Option<u32>,
+    /// The number of seconds a stale response may still be served while it is revalidated.
+    pub swr: Option<u32>,
+}
+
+/// The security headers of a route, holding the header values declared using `security(...)`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SecurityArgs {
+    /// The value of the `Referrer-Policy` header, like "no-referrer".
+    pub referrer: Option<&'static str>,
+    /// The value of the `X-Frame-Options` header, "DENY" or "SAMEORIGIN".
+    pub frame: Option<&'static str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PanesArgs {
+    /// The pane rendered for the whole path of the route.
+    pub list: Expr,
+    /// The pane rendered while the trailing optional parameter of the route is present.
+    pub detail: Expr,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrudArgs {
+    /// The parameter identifying a single resource, like "id".
+    pub param: String,
+    /// The views of the "show", "new" and "edit" child routes, if declared.
+    pub show: Option<syn::LitStr>,
+    pub new: Option<syn::LitStr>,
+    pub edit: Option<syn::LitStr>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaginationArgs {
+    /// The page size used when no `per_page` query parameter is present.
+    pub per_page: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct TypedParam {
+    pub param: String,
+    pub kind: TypedParamKind,
+    /// A format used for parsing and materializing values, like "%Y-%m-%d".
+    pub format: Option<syn::LitStr>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub enum TypedParamKind {
+    /// A user-provided type implementing `FromStr` and `Display`.
+    Custom(Box<syn::Type>),
+    /// The allowed values of the parameter, from which an enum is generated.
+    Variants(Vec<syn::LitStr>),
+}
+
+#[derive(Debug, Clone)]
+pub struct ParamConstraint {
+    pub param: String,
+    pub regex: String,
+    pub span: Span,
 }
 
 impl RouteMacroArgs {
-    pub fn parse(attrs: &[Attribute]) -> Option<RouteMacroArgs> {
+    /// Parses all `#[route(...)]` attributes, as a module may declare multiple routes.
+    pub fn parse_all(attrs: &[Attribute]) -> Vec<RouteMacroArgs> {
         attrs
             .iter()
-            .find(|attr| attr.path().is_ident("route"))
-            .and_then(|attr| {
+            .filter(|attr| attr.path().is_ident("route"))
+            .map(|attr| {
                 let ident = attr.path().get_ident().unwrap();
 
-                attr.parse_args_with(|input: syn::parse::ParseStream| {
-                    //panic!("Input: {:?}", content);
+                attr.parse_args_with(|input: ParseStream| {
                     let mut path: Option<String> = None;
-                    let mut layout: Option<Expr> = None;
-                    let mut layout_span: Option<Span> = None;
-                    let mut fallback: Option<Expr> = None;
-                    let mut fallback_span: Option<Span> = None;
-                    let mut view: Option<Expr> = None;
-                    let mut view_span: Option<Span> = None;
+                    let mut args = RouteMacroArgs {
+                        route_ident_span: ident.span(),
+                        route_path_segments: String::new(),
+                        absolute: false,
+                        group: false,
+                        name: None,
+                        layout: None,
+                        layout_span: None,
+                        layout_stack: None,
+                        captures: Vec::new(),
+                        class: None,
+                        fallback: None,
+                        fallback_span: None,
+                        index: None,
+                        index_span: None,
+                        view: None,
+                        view_span: None,
+                        bot_view: None,
+                        bot_view_span: None,
+                        structured_data: None,
+                        structured_data_span: None,
+                        noindex: false,
+                        ordered: false,
+                        step: None,
+                        modal: false,
+                        keep_alive: false,
+                        merge: false,
+                        previewable: false,
+                        preview: None,
+                        preview_span: None,
+                        panes: None,
+                        crud: None,
+                        constraints: Vec::new(),
+                        typed_params: Vec::new(),
+                        pagination: None,
+                        query_struct: None,
+                        query_variant: Vec::new(),
+                        leave_guard: None,
+                        guard: None,
+                        guard_span: None,
+                        guard_redirect: None,
+                        guard_fallback: None,
+                        error: None,
+                        suspense_fallback: None,
+                        transition: false,
+                        priority: 0,
+                        cache: None,
+                        security: SecurityArgs::default(),
+                        ssr: None,
+                        prefetch: None,
+                        deprecated: None,
+                        flag: None,
+                        shortcut: None,
+                        owner: None,
+                        section: None,
+                        title: None,
+                        keywords: Vec::new(),
+                        nav_label_key: None,
+                        roles: Vec::new(),
+                        public: false,
+                        allowed_from: Vec::new(),
+                        previously: Vec::new(),
+                        status: None,
+                        samples: Vec::new(),
+                        defaults: Vec::new(),
+                    };
 
                     while !input.is_empty() {
                         let lookahead = input.lookahead1();
                         if lookahead.peek(syn::LitStr) {
                             let lit: syn::LitStr = input.parse()?;
-                            let val = lit.value();
+                            let mut val = lit.value();
+                            if let Some(absolute) = val.strip_prefix('!') {
+                                val = absolute.to_owned();
+                                args.absolute = true;
+                            }
                             if !val.starts_with('/') {
                                 abort!(lit.span(), "Every path must start with a '/'. Add a leading '/'.");
                             }
@@ -56,23 +406,244 @@ impl RouteMacroArgs {
                             path = Some(val);
                         } else if lookahead.peek(syn::Ident) {
                             let ident: syn::Ident = input.parse()?;
-                            if ident == "view" {
+                            if ident == "name" {
                                 let _ = input.parse::<syn::Token![=]>()?;
-                                let lit = input.parse::<syn::Lit>().expect("expect lit");
-                                view = Some(ExprWrapper::from_value(&lit)?.0);
-                                view_span = Some(ident.span());
+                                args.name = Some(input.parse::<syn::LitStr>()?.parse()?);
+                            } else if ident == "view" {
+                                args.view = Some(parse_expr_value(input)?);
+                                args.view_span = Some(ident.span());
+                            } else if ident == "bot_view" {
+                                args.bot_view = Some(parse_expr_value(input)?);
+                                args.bot_view_span = Some(ident.span());
                             } else if ident == "layout" {
-                                let _ = input.parse::<syn::Token![=]>()?;
-                                let lit = input.parse::<syn::Lit>()?;
-                                layout = Some(ExprWrapper::from_value(&lit)?.0);
-                                layout_span = Some(ident.span());
+                                args.layout = Some(parse_expr_value(input)?);
+                                args.layout_span = Some(ident.span());
+                            } else if ident == "layout_stack" {
+                                input.parse::<syn::Token![=]>()?;
+                                args.layout_stack = Some(input.parse::<syn::LitStr>()?);
+                            } else if ident == "captures" {
+                                let content;
+                                syn::parenthesized!(content in input);
+                                let captures = content
+                                    .parse_terminated(<syn::Ident as Parse>::parse, syn::Token![,])?;
+                                if captures.is_empty() {
+                                    abort!(ident.span(), "\"captures\" lists the values declared in #[routes(captures(...))] bound in the views of the route, like captures(user_ctx).");
+                                }
+                                args.captures.extend(captures);
+                            } else if ident == "class" {
+                                input.parse::<syn::Token![=]>()?;
+                                let class = input.parse::<syn::LitStr>()?;
+                                if class.value().split_whitespace().next().is_none() {
+                                    abort!(class.span(), "A class requires at least one CSS class name, like class = \"theme-admin\".");
+                                }
+                                args.class = Some(class.value());
                             } else if ident == "fallback" {
+                                args.fallback = Some(parse_expr_value(input)?);
+                                args.fallback_span = Some(ident.span());
+                            } else if ident == "index" {
+                                if args.index.is_some() && args.crud.is_some() {
+                                    abort!(ident.span(), "\"crud\" declares the index view of this route, which \"index\" already does. Remove one of them.");
+                                } else if args.index.is_some() {
+                                    abort!(ident.span(), "\"index\" is already given for this route. Remove one of them.");
+                                }
+                                args.index = Some(parse_expr_value(input)?);
+                                args.index_span = Some(ident.span());
+                            } else if ident == "structured_data" {
+                                args.structured_data = Some(parse_expr_value(input)?);
+                                args.structured_data_span = Some(ident.span());
+                            } else if ident == "noindex" {
+                                args.noindex = true;
+                            } else if ident == "ordered" {
+                                args.ordered = true;
+                            } else if ident == "modal" {
+                                args.modal = match input.parse::<Option<syn::Token![=]>>()? {
+                                    Some(_) => input.parse::<syn::LitBool>()?.value,
+                                    None => true,
+                                };
+                            } else if ident == "keep_alive" {
+                                args.keep_alive = match input.parse::<Option<syn::Token![=]>>()? {
+                                    Some(_) => input.parse::<syn::LitBool>()?.value,
+                                    None => true,
+                                };
+                            } else if ident == "merge" {
+                                args.merge = match input.parse::<Option<syn::Token![=]>>()? {
+                                    Some(_) => input.parse::<syn::LitBool>()?.value,
+                                    None => true,
+                                };
+                            } else if ident == "previewable" {
+                                args.previewable = match input.parse::<Option<syn::Token![=]>>()? {
+                                    Some(_) => input.parse::<syn::LitBool>()?.value,
+                                    None => true,
+                                };
+                            } else if ident == "preview" {
+                                args.preview = Some(parse_expr_value(input)?);
+                                args.preview_span = Some(ident.span());
+                            } else if ident == "panes" {
+                                args.panes = Some(parse_panes(input, ident.span())?);
+                            } else if ident == "crud" {
+                                let (crud, index) = parse_crud(input, ident.span())?;
+                                if let Some(index) = index {
+                                    if args.index.is_some() {
+                                        abort!(index.span(), "\"crud\" declares the index view of this route, which \"index\" already does. Remove one of them.");
+                                    }
+                                    args.index = Some(ExprWrapper::from_value(&syn::Lit::Str(index))?.0);
+                                    args.index_span = Some(ident.span());
+                                }
+                                args.crud = Some(crud);
+                            } else if ident == "step" {
+                                input.parse::<syn::Token![=]>()?;
+                                args.step = Some(input.parse::<syn::LitInt>()?.base10_parse::<u32>()?);
+                            } else if ident == "constraint" {
+                                args.constraints.extend(parse_constraints(input)?);
+                            } else if ident == "shortcut" {
+                                input.parse::<syn::Token![=]>()?;
+                                let shortcut = input.parse::<syn::LitStr>()?;
+                                if shortcut.value().split_whitespace().next().is_none() {
+                                    abort!(shortcut.span(), "A shortcut requires at least one key, like shortcut = \"g u\".");
+                                }
+                                args.shortcut = Some(shortcut.value());
+                            } else if ident == "owner" {
+                                input.parse::<syn::Token![=]>()?;
+                                let owner = input.parse::<syn::LitStr>()?;
+                                if owner.value().trim().is_empty() {
+                                    abort!(owner.span(), "An owner names the team owning the route, like owner = \"team-payments\".");
+                                }
+                                args.owner = Some(owner.value());
+                            } else if ident == "section" {
+                                input.parse::<syn::Token![=]>()?;
+                                let section = input.parse::<syn::LitStr>()?;
+                                let value = section.value();
+                                let valid = value.starts_with(|c: char| c.is_ascii_lowercase())
+                                    && value.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+                                if !valid {
+                                    abort!(section.span(), "A section is named in snake_case, like section = \"admin\" or section = \"user_settings\".");
+                                }
+                                args.section = Some(value);
+                            } else if ident == "title" {
+                                input.parse::<syn::Token![=]>()?;
+                                args.title = Some(input.parse::<syn::LitStr>()?.value());
+                            } else if ident == "keywords" {
+                                let content;
+                                syn::parenthesized!(content in input);
+                                let keywords = content
+                                    .parse_terminated(<syn::LitStr as Parse>::parse, syn::Token![,])?;
+                                args.keywords.extend(keywords.iter().map(syn::LitStr::value));
+                            } else if ident == "nav" {
+                                args.nav_label_key = Some(parse_nav(input, ident.span())?);
+                            } else if ident == "roles" {
+                                let content;
+                                syn::parenthesized!(content in input);
+                                let roles = content
+                                    .parse_terminated(<syn::LitStr as Parse>::parse, syn::Token![,])?;
+                                if roles.is_empty() {
+                                    abort!(ident.span(), "\"roles\" lists the roles permitted to access the route, like roles(\"admin\", \"editor\").");
+                                }
+                                args.roles.extend(roles.iter().map(syn::LitStr::value));
+                            } else if ident == "public" {
+                                args.public = match input.parse::<Option<syn::Token![=]>>()? {
+                                    Some(_) => input.parse::<syn::LitBool>()?.value,
+                                    None => true,
+                                };
+                            } else if ident == "allowed_from" {
+                                let content;
+                                syn::parenthesized!(content in input);
+                                let routes = content
+                                    .parse_terminated(<syn::LitStr as Parse>::parse, syn::Token![,])?;
+                                if routes.is_empty() {
+                                    abort!(ident.span(), "\"allowed_from\" lists the routes this route may be navigated to from, like allowed_from(\"login\", \"signup\").");
+                                }
+                                args.allowed_from.extend(routes);
+                            } else if ident == "status" {
+                                input.parse::<syn::Token![=]>()?;
+                                let status = input.parse::<syn::LitInt>()?;
+                                match status.base10_parse::<u16>() {
+                                    Ok(code @ 100..=599) => args.status = Some(code),
+                                    _ => abort!(status.span(), "A status must be an HTTP status code from 100 to 599, like status = 410."),
+                                }
+                            } else if ident == "previously" {
+                                input.parse::<syn::Token![=]>()?;
+                                let previously = input.parse::<syn::LitStr>()?;
+                                if !previously.value().starts_with('/') {
+                                    abort!(previously.span(), "A previous path is the full path the route was served at, starting with a '/'. Add a leading '/'.");
+                                }
+                                args.previously.push(previously.value());
+                            } else if ident == "sample" {
+                                args.samples.extend(parse_param_values(input)?);
+                            } else if ident == "defaults" {
+                                args.defaults.extend(parse_param_values(input)?);
+                            } else if ident == "params" {
+                                args.typed_params.extend(parse_typed_params(input)?);
+                            } else if ident == "pagination" {
+                                args.pagination = Some(parse_pagination(input)?);
+                            } else if ident == "query_struct" {
+                                let _ = input.parse::<syn::Token![=]>()?;
+                                args.query_struct = Some(input.parse::<syn::LitStr>()?.parse()?);
+                            } else if ident == "variant" {
+                                let values = parse_param_values(input)?;
+                                if values.is_empty() {
+                                    abort!(ident.span(), "\"variant\" lists the query parameters distinguishing this route, like variant(tab = \"archived\").");
+                                }
+                                // Names and values are neither encoded nor decoded, so they must appear in URLs as they are.
+                                let invalid = values.iter().find(|(_, value)| {
+                                    value.is_empty() || !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                                });
+                                if let Some((name, value)) = invalid {
+                                    abort!(name.span(), "The value \"{}\" of a query variant must be non-empty and consist of letters, digits, '-' and '_' only, as it is not percent-encoded.", value);
+                                }
+                                args.query_variant.extend(values);
+                            } else if ident == "leave_guard" {
+                                args.leave_guard = Some(parse_expr_value(input)?);
+                            } else if ident == "guard" {
+                                args.guard = Some(parse_expr_value(input)?);
+                                args.guard_span = Some(ident.span());
+                            } else if ident == "guard_redirect" {
+                                input.parse::<syn::Token![=]>()?;
+                                let redirect = input.parse::<syn::LitStr>()?;
+                                if !redirect.value().starts_with('/') {
+                                    abort!(redirect.span(), "The guard redirects to a path like guard_redirect = \"/login\", starting with a '/'.");
+                                }
+                                args.guard_redirect = Some(redirect);
+                            } else if ident == "guard_fallback" {
+                                args.guard_fallback = Some(parse_expr_value(input)?);
+                            } else if ident == "error" {
+                                args.error = Some(parse_expr_value(input)?);
+                            } else if ident == "suspense_fallback" {
+                                args.suspense_fallback = Some(parse_expr_value(input)?);
+                            } else if ident == "transition" {
+                                args.transition = true;
+                            } else if ident == "priority" {
                                 let _ = input.parse::<syn::Token![=]>()?;
-                                let lit = input.parse::<syn::Lit>()?;
-                                fallback = Some(ExprWrapper::from_value(&lit)?.0);
-                                fallback_span = Some(ident.span());
+                                let negative = input.parse::<Option<syn::Token![-]>>()?.is_some();
+                                let priority = input.parse::<syn::LitInt>()?.base10_parse::<i32>()?;
+                                args.priority = if negative { -priority } else { priority };
+                            } else if ident == "group" {
+                                args.group = true;
+                            } else if ident == "absolute" {
+                                args.absolute = match input.parse::<Option<syn::Token![=]>>()? {
+                                    Some(_) => input.parse::<syn::LitBool>()?.value,
+                                    None => true,
+                                };
+                            } else if ident == "flag" {
+                                input.parse::<syn::Token![=]>()?;
+                                args.flag = Some(input.parse::<syn::LitStr>()?.value());
+                            } else if ident == "deprecated" {
+                                let note = match input.parse::<Option<syn::Token![=]>>()? {
+                                    Some(_) => Some(input.parse::<syn::LitStr>()?.value()),
+                                    None => None,
+                                };
+                                args.deprecated = Some(note);
+                            } else if ident == "ssr" {
+                                args.ssr = Some(parse_ssr_mode(input)?);
+                            } else if ident == "prefetch" {
+                                args.prefetch = Some(parse_prefetch(input)?);
+                            } else if ident == "cache" {
+                                args.cache = Some(parse_cache(input, ident.span())?);
+                            } else if ident == "security" {
+                                args.security = parse_security(input, ident.span())?;
+                            } else if ident == "split_group" {
+                                abort!(ident.span(), "\"split_group\" is not supported yet, as bundle splitting requires the lazy routes of Leptos 0.8. Remove the argument.");
                             } else {
-                                abort!(ident.span(), "Unexpected ident: \"{}\". Expected one of \"layout\", \"fallback\" or \"view\".", ident.to_string());
+                                abort!(ident.span(), "{}", unexpected("ident", &ident.to_string(), ROUTE_ARGS));
                             }
                         } else {
                             abort!(input.span(), "Unexpected additional macro input. Remove these tokens.");
@@ -82,20 +653,422 @@ impl RouteMacroArgs {
                             let _: syn::Token![,] = input.parse()?;
                         }
                     }
-                    let path = path.expect("expect path to be present");
+                    if args.group {
+                        if path.is_some() {
+                            abort!(args.route_ident_span, "A \"group\" adds no path segment. Remove the path or the \"group\" argument.");
+                        }
+                        if !args.previously.is_empty() {
+                            abort!(args.route_ident_span, "A \"group\" renders no page of its own, which previous paths could redirect to. Move \"previously\" to a child route.");
+                        }
+                        if let Some(span) = args.view_span.or(args.fallback_span).or(args.index_span) {
+                            abort!(span, "A \"group\" renders no page of its own, only grouping its child routes. Move the view to a child route.");
+                        }
+                        path = Some(String::new());
+                    }
+                    if let (Some(_), Some(span)) = (&args.panes, args.view_span) {
+                        abort!(span, "\"panes\" replace the \"view\" of the route. Remove one of them.");
+                    }
+                    if let (Some(span), None) = (args.bot_view_span, &args.view) {
+                        abort!(span, "\"bot_view\" replaces the \"view\" of a leaf route for bots. Add a \"view\" or remove the argument.");
+                    }
+                    if args.public && !args.roles.is_empty() {
+                        abort!(args.route_ident_span, "A \"public\" route is accessible to everyone, but \"roles\" restrict it. Remove one of them.");
+                    }
+                    match (&args.guard_span, &args.guard_redirect, &args.guard_fallback) {
+                        (Some(span), None, _) => {
+                            abort!(*span, "A \"guard\" redirects when denying access. Add a path to redirect to, like guard_redirect = \"/login\".");
+                        }
+                        (None, Some(_), _) | (None, _, Some(_)) => {
+                            abort!(args.route_ident_span, "\"guard_redirect\" and \"guard_fallback\" configure the \"guard\" of the route, which is not declared. Add a \"guard\" or remove the arguments.");
+                        }
+                        _ => {}
+                    }
+                    args.route_path_segments = path.expect("expect path to be present");
 
-                    Ok(RouteMacroArgs {
-                        route_ident_span: ident.span(),
-                        route_path_segments: path,
-                        layout,
-                        layout_span,
-                        fallback,
-                        fallback_span,
-                        view,
-                        view_span,
-                    })
+                    Ok(args)
                 })
-                .ok()
+                .unwrap_or_else(|err| abort!(err.span(), "{}", err))
             })
+            .collect()
+    }
+}
+
+/// All arguments of `#[route(...)]`, as listed in error messages.
+const ROUTE_ARGS: &[&str] = &[
+    "name", "layout", "layout_stack", "captures", "class", "fallback", "index", "view", "bot_view",
+    "structured_data", "noindex", "ordered", "step", "modal", "keep_alive", "merge", "previewable",
+    "preview", "panes", "crud", "constraint", "params", "pagination", "query_struct", "variant",
+    "leave_guard", "guard", "guard_redirect", "guard_fallback", "error", "suspense_fallback",
+    "transition", "priority", "cache", "security", "ssr", "prefetch", "deprecated", "absolute",
+    "group", "flag", "sample", "defaults", "shortcut", "owner", "section", "title", "keywords",
+    "nav", "roles", "public", "allowed_from", "previously", "status",
+];
+
+/// Parses `= "some_expression"`, following an argument name.
+fn parse_expr_value(input: ParseStream) -> syn::Result<Expr> {
+    let _ = input.parse::<syn::Token![=]>()?;
+    let lit = input.parse::<syn::Lit>()?;
+    Ok(ExprWrapper::from_value(&lit)?.0)
+}
+
+/// Parses `(name = "regex", ...)`, following the `constraint` argument name.
+fn parse_constraints(input: ParseStream) -> syn::Result<Vec<ParamConstraint>> {
+    let content;
+    syn::parenthesized!(content in input);
+
+    let mut constraints = Vec::new();
+    while !content.is_empty() {
+        // Parameters may be named like keywords, e.g. "type".
+        let param = syn::Ident::parse_any(&content)?;
+        let _ = content.parse::<syn::Token![=]>()?;
+        let regex = content.parse::<syn::LitStr>()?;
+        if let Err(err) = regex::Regex::new(&regex.value()) {
+            abort!(regex.span(), "Invalid constraint regex: {}", err);
+        }
+        constraints.push(ParamConstraint {
+            param: param.to_string(),
+            regex: regex.value(),
+            span: param.span(),
+        });
+
+        if !content.is_empty() {
+            let _: syn::Token![,] = content.parse()?;
+        }
+    }
+    Ok(constraints)
+}
+
+/// Parses `(id = "42", ...)`, following the `sample` or `defaults` argument name.
+fn parse_param_values(input: ParseStream) -> syn::Result<Vec<(syn::Ident, String)>> {
+    let content;
+    syn::parenthesized!(content in input);
+
+    let mut values = Vec::new();
+    while !content.is_empty() {
+        let param = syn::Ident::parse_any(&content)?;
+        let _ = content.parse::<syn::Token![=]>()?;
+        values.push((param, content.parse::<syn::LitStr>()?.value()));
+
+        if !content.is_empty() {
+            let _: syn::Token![,] = content.parse()?;
+        }
+    }
+    Ok(values)
+}
+
+/// Aborts unless the given parameter format is a valid `chrono` format, which would otherwise make
+/// materializing values panic, or when it formats values containing a '/', like "%D" does, as
+/// values must stay within one path segment.
+fn check_format(format: &syn::LitStr) {
+    use chrono::format::{Item, StrftimeItems};
+
+    let value = format.value();
+    for item in StrftimeItems::new(&value) {
+        match item {
+            Item::Error => {
+                abort!(format.span(), "Invalid format: \"{}\". Use the specifiers of `chrono::format::strftime`, like \"%Y-%m-%d\".", value);
+            }
+            Item::Literal(literal) if literal.contains('/') => {
+                abort!(format.span(), "A parameter format must not produce a '/', like \"%D\" does, as values must stay within one path segment.");
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parses `(name = "Type", other = variants("a", "b"), ...)`, following the `params` argument name.
+/// A `format = "..."` entry following a typed parameter declares the format of that parameter.
+fn parse_typed_params(input: ParseStream) -> syn::Result<Vec<TypedParam>> {
+    let content;
+    syn::parenthesized!(content in input);
+
+    let mut typed_params: Vec<TypedParam> = Vec::new();
+    while !content.is_empty() {
+        let param = syn::Ident::parse_any(&content)?;
+        let _ = content.parse::<syn::Token![=]>()?;
+        let previous_takes_format = typed_params.last().is_some_and(|previous| {
+            previous.format.is_none() && matches!(previous.kind, TypedParamKind::Custom(_))
+        });
+        if param == "format" && previous_takes_format {
+            let format = content.parse::<syn::LitStr>()?;
+            check_format(&format);
+            typed_params.last_mut().expect("present").format = Some(format);
+            if !content.is_empty() {
+                let _: syn::Token![,] = content.parse()?;
+            }
+            continue;
+        }
+        let kind = if content.peek(syn::Ident) {
+            let ident: syn::Ident = content.parse()?;
+            if ident != "variants" {
+                abort!(ident.span(), "Unexpected ident: \"{}\". Expected a type given as a string literal or \"variants(...)\".", ident.to_string());
+            }
+            let variants_content;
+            syn::parenthesized!(variants_content in content);
+            let variants = variants_content
+                .parse_terminated(|input| input.parse::<syn::LitStr>(), syn::Token![,])?
+                .into_iter()
+                .collect::<Vec<_>>();
+            if variants.is_empty() {
+                abort!(ident.span(), "At least one variant is required.");
+            }
+            TypedParamKind::Variants(variants)
+        } else {
+            let ty = content.parse::<syn::LitStr>()?;
+            TypedParamKind::Custom(Box::new(ty.parse()?))
+        };
+        typed_params.push(TypedParam {
+            param: param.to_string(),
+            kind,
+            format: None,
+            span: param.span(),
+        });
+
+        if !content.is_empty() {
+            let _: syn::Token![,] = content.parse()?;
+        }
+    }
+    Ok(typed_params)
+}
+
+/// Parses the optional `(per_page = 50)`, following the `pagination` argument name.
+fn parse_pagination(input: ParseStream) -> syn::Result<PaginationArgs> {
+    let mut args = PaginationArgs { per_page: 20 };
+    if !input.peek(syn::token::Paren) {
+        return Ok(args);
+    }
+
+    let content;
+    syn::parenthesized!(content in input);
+    while !content.is_empty() {
+        let ident: syn::Ident = content.parse()?;
+        let _ = content.parse::<syn::Token![=]>()?;
+        if ident == "per_page" {
+            let per_page = content.parse::<syn::LitInt>()?;
+            args.per_page = per_page.base10_parse()?;
+            if args.per_page == 0 {
+                abort!(per_page.span(), "\"per_page\" must be greater than 0.");
+            }
+        } else {
+            abort!(ident.span(), "Unexpected ident: \"{}\". Expected \"per_page\".", ident.to_string());
+        }
+
+        if !content.is_empty() {
+            let _: syn::Token![,] = content.parse()?;
+        }
+    }
+    Ok(args)
+}
+
+/// Parses `(list = "MessageList", detail = "MessageView")`, following the `panes` argument name.
+fn parse_panes(input: ParseStream, span: Span) -> syn::Result<PanesArgs> {
+    let mut list = None;
+    let mut detail = None;
+
+    let content;
+    syn::parenthesized!(content in input);
+    while !content.is_empty() {
+        let ident: syn::Ident = content.parse()?;
+        let _ = content.parse::<syn::Token![=]>()?;
+        let view = ExprWrapper::from_value(&content.parse::<syn::Lit>()?)?.0;
+        if ident == "list" {
+            list = Some(view);
+        } else if ident == "detail" {
+            detail = Some(view);
+        } else {
+            abort!(ident.span(), "Unexpected ident: \"{}\". Expected \"list\" or \"detail\".", ident.to_string());
+        }
+
+        if !content.is_empty() {
+            let _: syn::Token![,] = content.parse()?;
+        }
+    }
+    match (list, detail) {
+        (Some(list), Some(detail)) => Ok(PanesArgs { list, detail, span }),
+        _ => abort!(span, "\"panes\" requires a \"list\" and a \"detail\" view, like panes(list = \"MessageList\", detail = \"MessageView\")."),
     }
 }
+
+/// Parses `(label_key = "menu.users")`, following the `nav` argument name, returning the key.
+fn parse_nav(input: ParseStream, span: Span) -> syn::Result<String> {
+    let mut label_key = None;
+
+    let content;
+    syn::parenthesized!(content in input);
+    while !content.is_empty() {
+        let ident: syn::Ident = content.parse()?;
+        let _ = content.parse::<syn::Token![=]>()?;
+        let value = content.parse::<syn::LitStr>()?;
+        if ident == "label_key" {
+            if value.value().trim().is_empty() {
+                abort!(value.span(), "A label key names the message of the label in your translations, like label_key = \"menu.users\".");
+            }
+            label_key = Some(value.value());
+        } else {
+            abort!(ident.span(), "Unexpected ident: \"{}\". Expected \"label_key\".", ident.to_string());
+        }
+
+        if !content.is_empty() {
+            let _: syn::Token![,] = content.parse()?;
+        }
+    }
+    match label_key {
+        Some(label_key) => Ok(label_key),
+        None => abort!(span, "\"nav\" requires a \"label_key\", like nav(label_key = \"menu.users\")."),
+    }
+}
+
+/// Parses `(param = "id", views(index = "UserList", show = "User", ...))`, following the `crud`
+/// argument name, returning the index view separately.
+fn parse_crud(input: ParseStream, span: Span) -> syn::Result<(CrudArgs, Option<syn::LitStr>)> {
+    let mut args = CrudArgs {
+        param: "id".to_owned(),
+        show: None,
+        new: None,
+        edit: None,
+        span,
+    };
+    let mut index = None;
+
+    let content;
+    syn::parenthesized!(content in input);
+    while !content.is_empty() {
+        let ident: syn::Ident = content.parse()?;
+        if ident == "param" {
+            let _ = content.parse::<syn::Token![=]>()?;
+            let param = content.parse::<syn::LitStr>()?;
+            let valid = param.value().chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if param.value().is_empty() || !valid {
+                abort!(param.span(), "A \"param\" names the parameter of a single resource, like param = \"id\".");
+            }
+            args.param = param.value();
+        } else if ident == "views" {
+            let views;
+            syn::parenthesized!(views in content);
+            while !views.is_empty() {
+                let view_ident: syn::Ident = views.parse()?;
+                let _ = views.parse::<syn::Token![=]>()?;
+                let view = Some(views.parse::<syn::LitStr>()?);
+                if view_ident == "index" {
+                    index = view;
+                } else if view_ident == "show" {
+                    args.show = view;
+                } else if view_ident == "new" {
+                    args.new = view;
+                } else if view_ident == "edit" {
+                    args.edit = view;
+                } else {
+                    abort!(view_ident.span(), "{}", unexpected("ident", &view_ident.to_string(), &["index", "show", "new", "edit"]));
+                }
+
+                if !views.is_empty() {
+                    let _: syn::Token![,] = views.parse()?;
+                }
+            }
+        } else {
+            abort!(ident.span(), "{}", unexpected("ident", &ident.to_string(), &["param", "views"]));
+        }
+
+        if !content.is_empty() {
+            let _: syn::Token![,] = content.parse()?;
+        }
+    }
+    Ok((args, index))
+}
+
+/// Parses `(max_age = 3600, swr = 86400)`, following the `cache` argument name.
+fn parse_cache(input: ParseStream, span: Span) -> syn::Result<CacheArgs> {
+    let mut args = CacheArgs {
+        max_age: None,
+        swr: None,
+    };
+
+    let content;
+    syn::parenthesized!(content in input);
+    while !content.is_empty() {
+        let ident: syn::Ident = content.parse()?;
+        let _ = content.parse::<syn::Token![=]>()?;
+        let seconds = content.parse::<syn::LitInt>()?.base10_parse()?;
+        if ident == "max_age" {
+            args.max_age = Some(seconds);
+        } else if ident == "swr" {
+            args.swr = Some(seconds);
+        } else {
+            abort!(ident.span(), "Unexpected ident: \"{}\". Expected \"max_age\" or \"swr\".", ident.to_string());
+        }
+
+        if !content.is_empty() {
+            let _: syn::Token![,] = content.parse()?;
+        }
+    }
+    if args.max_age.is_none() && args.swr.is_none() {
+        abort!(span, "\"cache\" requires a \"max_age\" and/or a \"swr\" in seconds, like cache(max_age = 3600, swr = 86400).");
+    }
+    Ok(args)
+}
+
+const REFERRER_POLICIES: &[&str] = &[
+    "no-referrer", "no-referrer-when-downgrade", "origin", "origin-when-cross-origin",
+    "same-origin", "strict-origin", "strict-origin-when-cross-origin", "unsafe-url",
+];
+
+/// Parses `(referrer = "no-referrer", frame = "deny")`, following the `security` argument name.
+fn parse_security(input: ParseStream, span: Span) -> syn::Result<SecurityArgs> {
+    let mut args = SecurityArgs::default();
+
+    let content;
+    syn::parenthesized!(content in input);
+    while !content.is_empty() {
+        let ident: syn::Ident = content.parse()?;
+        let _ = content.parse::<syn::Token![=]>()?;
+        let value = content.parse::<syn::LitStr>()?;
+        if ident == "referrer" {
+            match REFERRER_POLICIES.iter().find(|policy| **policy == value.value()) {
+                Some(policy) => args.referrer = Some(policy),
+                None => abort!(value.span(), "{}", unexpected("referrer policy", &value.value(), REFERRER_POLICIES)),
+            }
+        } else if ident == "frame" {
+            match value.value().as_str() {
+                "deny" => args.frame = Some("DENY"),
+                "sameorigin" => args.frame = Some("SAMEORIGIN"),
+                other => abort!(value.span(), "Unexpected frame option: \"{}\". Expected \"deny\" or \"sameorigin\".", other),
+            }
+        } else {
+            abort!(ident.span(), "Unexpected ident: \"{}\". Expected \"referrer\" or \"frame\".", ident.to_string());
+        }
+
+        if !content.is_empty() {
+            let _: syn::Token![,] = content.parse()?;
+        }
+    }
+    if args.referrer.is_none() && args.frame.is_none() {
+        abort!(span, "\"security\" requires a \"referrer\" and/or a \"frame\" policy, like security(referrer = \"no-referrer\", frame = \"deny\").");
+    }
+    Ok(args)
+}
+
+/// Parses `= "hover"`, following the `prefetch` argument name.
+fn parse_prefetch(input: ParseStream) -> syn::Result<PrefetchArg> {
+    let _ = input.parse::<syn::Token![=]>()?;
+    let prefetch = input.parse::<syn::LitStr>()?;
+    Ok(match prefetch.value().as_str() {
+        "none" => PrefetchArg::None,
+        "hover" => PrefetchArg::Hover,
+        "visible" => PrefetchArg::Visible,
+        other => abort!(prefetch.span(), "{}", unexpected("prefetch mode", other, &["hover", "visible", "none"])),
+    })
+}
+
+/// Parses `= "Async"`, following the `ssr` argument name.
+fn parse_ssr_mode(input: ParseStream) -> syn::Result<SsrModeArg> {
+    let _ = input.parse::<syn::Token![=]>()?;
+    let mode = input.parse::<syn::LitStr>()?;
+    Ok(match mode.value().as_str() {
+        "OutOfOrder" => SsrModeArg::OutOfOrder,
+        "PartiallyBlocked" => SsrModeArg::PartiallyBlocked,
+        "InOrder" => SsrModeArg::InOrder,
+        "Async" => SsrModeArg::Async,
+        "Static" => SsrModeArg::Static,
+        other => abort!(mode.span(), "{}", unexpected("SSR mode", other, &["OutOfOrder", "PartiallyBlocked", "InOrder", "Async", "Static"])),
+    })
+}
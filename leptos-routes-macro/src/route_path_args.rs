@@ -0,0 +1,43 @@
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::Token;
+
+/// The input of the `route_path!` macro: the path to a route struct, followed by named arguments,
+/// like "root::users::User, id = "42"".
+pub struct RoutePathArgs {
+    pub route: syn::Path,
+    pub args: Vec<(syn::Ident, syn::Expr)>,
+}
+
+impl Parse for RoutePathArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let route = input.parse::<syn::Path>()?;
+        let mut args = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let name = syn::Ident::parse_any(input)?;
+            input.parse::<Token![=]>()?;
+            args.push((name, input.parse::<syn::Expr>()?));
+        }
+        Ok(Self { route, args })
+    }
+}
+
+impl RoutePathArgs {
+    /// Expands to a call of the route's `materialize_args` function, constructing its named
+    /// arguments struct, like `root::users::UserArgs`, from the given arguments.
+    pub fn expand(&self) -> proc_macro2::TokenStream {
+        let route = &self.route;
+        let mut args_struct = route.clone();
+        let last = args_struct.segments.last_mut().expect("present");
+        last.ident = format_ident!("{}Args", last.ident, span = last.ident.span());
+        let (names, values): (Vec<_>, Vec<_>) = self.args.iter().cloned().unzip();
+        quote! {
+            #route.materialize_args(#args_struct { #(#names: #values),* })
+        }
+    }
+}
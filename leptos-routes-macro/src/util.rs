@@ -16,6 +16,18 @@ pub fn to_pascal_case(s: &str) -> String {
     result
 }
 
+/// Converts a pascal-cased name like "SignIn" to snake case, like "sign_in".
+pub fn to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            result.push('_');
+        }
+        result.extend(c.to_lowercase());
+    }
+    result
+}
+
 pub fn sanitize_identifier(name: &str) -> String {
     const RUST_KEYWORDS: &[&str] = &[
         "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn",
@@ -30,3 +42,42 @@ pub fn sanitize_identifier(name: &str) -> String {
         name.to_string()
     }
 }
+
+/// The number of edits turning `a` into `b`, counting the swap of two adjacent characters as a
+/// single edit, like in "veiw" for "view".
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let mut rows = vec![(0..=b.len()).collect::<Vec<_>>()];
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (rows[i - 1][j] + 1).min(row[j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+        rows.push(row);
+    }
+    rows[a.len()][b.len()]
+}
+
+/// The error message for an unexpected `value` of the given kind, like "ident", suggesting the
+/// closest of the `expected` values, if any is close enough to be a typo, and listing all of them.
+pub fn unexpected(kind: &str, value: &str, expected: &[&str]) -> String {
+    let suggestion = expected
+        .iter()
+        .map(|candidate| (edit_distance(value, candidate), candidate))
+        .filter(|(distance, _)| *distance <= (value.chars().count() / 3).max(1))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| format!(" Did you mean \"{candidate}\"?"))
+        .unwrap_or_default();
+    let quoted = expected.iter().map(|value| format!("\"{value}\"")).collect::<Vec<_>>();
+    let listed = match quoted.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+        None => String::new(),
+    };
+    format!("Unexpected {kind}: \"{value}\".{suggestion} Expected one of {listed}.")
+}
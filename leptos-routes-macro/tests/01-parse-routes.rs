@@ -42,6 +42,7 @@ pub mod routes {
     }
 }
 
+#[allow(clippy::unit_arg)]
 fn main() {
     use assertr::prelude::*;
     use leptos_router::{OptionalParamSegment, ParamSegment, StaticSegment, WildcardSegment};
@@ -97,4 +98,12 @@ fn main() {
         routes::Route::RootUsersUserWelcome(_) => {}
         routes::Route::RootUsersUserDetails(_) => {}
     }
+
+    // Route structs convert into their `Route` variant, and back.
+    fn accepts_any_route(route: impl Into<routes::Route>) -> routes::Route {
+        route.into()
+    }
+    assert_that(accepts_any_route(routes::root::users::user::Details)).is_equal_to(route);
+    assert_that(routes::root::users::user::Details::try_from(route)).is_equal_to(Ok(routes::root::users::user::Details));
+    assert_that(routes::root::Welcome::try_from(route)).is_equal_to(Err(route));
 }
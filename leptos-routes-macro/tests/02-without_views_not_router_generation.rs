@@ -9,6 +9,7 @@ pub mod routes {
     pub mod root {}
 }
 
+#[allow(clippy::diverging_sub_expression)]
 fn main() {
     // Assumption: `generatedRoutes` is generated but immediately panics using `unimplemented!`.
     assert_that_panic_by(|| {
@@ -132,6 +132,7 @@ fn SomePage() -> impl IntoView {
     }
 }
 
+#[allow(clippy::unit_arg)]
 fn main() {
     fn app() -> impl IntoView {
         view! {
@@ -0,0 +1,69 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/users", layout = "UsersLayout", fallback = "NoUser", structured_data = "users_jsonld")]
+    pub mod users {
+
+        #[route("/:id", layout = "UserLayout", fallback = "User")]
+        pub mod user {
+
+            #[route("/posts/:post_id", view = "Post", structured_data = "post_jsonld")]
+            pub mod post {}
+        }
+    }
+}
+
+fn users_jsonld() -> String {
+    r#"{"@type":"CollectionPage"}"#.to_string()
+}
+
+fn post_jsonld(id: &str, post_id: &str) -> String {
+    format!(r#"{{"@type":"BlogPosting","author":"{id}","identifier":"{post_id}"}}"#)
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <div id="users-layout"> <Outlet/> </div> } }
+#[component]
+fn UserLayout() -> impl IntoView { view! { <div id="user-layout"> <Outlet/> </div> } }
+#[component]
+fn NoUser() -> impl IntoView { view! { "NoUser" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Post() -> impl IntoView { view! { "Post" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    // Parameters of all parent routes are taken in order, starting at the root.
+    assert_that(routes::users::user::Post.materialize("42", "7")).is_equal_to("/users/42/posts/7");
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::Users.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div id="users-layout"><script type="application/ld+json">{"@type":"CollectionPage"}</script>NoUser</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(
+        routes::users::user::Post.materialize("42", "7").as_str(),
+    ));
+    assert_that(app().to_html()).is_equal_to(r#"<div id="users-layout"><div id="user-layout"><script type="application/ld+json">{"@type":"BlogPosting","author":"42","identifier":"7"}</script>Post</div></div>"#);
+
+    // Parameters are read from the URL, and must not close the script element.
+    provide_context::<RequestUrl>(RequestUrl::new("/users/%3C%2Fscript%3E%3Cscript%3Ealert(1)/posts/7"));
+    assert_that(app().to_html()).is_equal_to(r#"<div id="users-layout"><div id="user-layout"><script type="application/ld+json">{"@type":"BlogPosting","author":"\u003c/script>\u003cscript>alert(1)","identifier":"7"}</script>Post</div></div>"#);
+}
@@ -0,0 +1,69 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, noindex_meta, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/welcome", view = "Welcome")]
+        pub mod welcome {}
+
+        // Marking a route `noindex` also excludes all of its child routes.
+        #[route("/admin", layout = "MainLayout", fallback = "Admin", noindex)]
+        pub mod admin {
+
+            #[route("/users/:id", view = "Admin")]
+            pub mod user {}
+
+            #[route("/logs/:page?", view = "Admin")]
+            pub mod logs {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Welcome() -> impl IntoView { view! { "Welcome" } }
+#[component]
+fn Admin() -> impl IntoView { view! { "Admin" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    assert_that(routes::noindex_patterns())
+        .is_equal_to(vec![
+            "/admin".to_string(),
+            "/admin/users/*".to_string(),
+            // An optional parameter also disallows the path without it.
+            "/admin/logs".to_string(),
+            "/admin/logs/*".to_string(),
+        ]);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::Welcome.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Welcome</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(
+        routes::root::admin::User.materialize("42").as_str(),
+    ));
+    assert_that(app().to_html())
+        .is_equal_to(r#"<div><div><meta name="robots" content="noindex">Admin</div></div>"#);
+}
@@ -0,0 +1,72 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }", constraint_fallback = "|| view! { <InvalidParam/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/users/:id", layout = "MainLayout", fallback = "User", constraint(id = r"\d+"))]
+        pub mod user {
+
+            #[route("/files/:kind?/*path", view = "File", constraint(kind = "pdf|txt"))]
+            pub mod file {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn InvalidParam() -> impl IntoView { view! { "InvalidParam" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn File() -> impl IntoView { view! { "File" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    assert_that(routes::Root.matches("/")).is_true();
+    assert_that(routes::Root.matches("/?tab=1#top")).is_true();
+    assert_that(routes::Root.matches("/users")).is_false();
+
+    assert_that(routes::root::User.matches("/users/42")).is_true();
+    assert_that(routes::root::User.matches("/users/bob")).is_false();
+    assert_that(routes::root::User.matches("/users/42/files")).is_false();
+
+    // Constraints of parent routes also apply to child routes.
+    assert_that(routes::root::user::File.matches("/users/42/files/pdf/a/b.pdf")).is_true();
+    assert_that(routes::root::user::File.matches("/users/bob/files/pdf/a/b.pdf")).is_false();
+    // Like in the router, an optional parameter is captured whenever a segment is present.
+    assert_that(routes::root::user::File.matches("/users/42/files/docs/a.doc")).is_false();
+
+    assert_that(routes::Route::from_path("/users/42"))
+        .is_equal_to(Some(routes::Route::RootUser(routes::root::User)));
+    assert_that(routes::Route::from_path("/users/42/files/txt/notes.txt"))
+        .is_equal_to(Some(routes::Route::RootUserFile(routes::root::user::File)));
+    assert_that(routes::Route::from_path("/users/bob")).is_equal_to(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/users/42"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><div>User</div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/users/bob"));
+    assert_that(app().to_html()).is_equal_to(r#"<div>InvalidParam</div>"#);
+}
@@ -0,0 +1,186 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/settings/:tab", layout = "MainLayout", fallback = "Settings", params(tab = variants("profile", "two-factor")))]
+        pub mod settings {
+
+            #[route("/:page", view = "SettingsPage", params(page = "u32"))]
+            pub mod page {}
+        }
+
+        #[route("/reports/:kind", view = "Report", params(kind = "crate::ReportKind"))]
+        pub mod report {}
+
+        // Short type names are available through the `uuid` and `num` features.
+        #[route("/orders/:id/:page?", view = "Report", params(id = "Uuid", page = "NonZeroU32"), constraint(page = "[1-9][0-9]?"))]
+        pub mod order {}
+
+        // Qualified paths are used as they are, even when ending in a short type name.
+        #[route("/tickets/:id", view = "Report", params(id = "crate::ids::Uuid"))]
+        pub mod ticket {}
+
+        #[route("/invoices/:number", view = "Report", params(number = "u32"), structured_data = "crate::invoice_jsonld")]
+        pub mod invoice {}
+
+        // Date and time types are available through the `chrono` feature, optionally with a custom format.
+        #[route("/calendar/:day", layout = "MainLayout", fallback = "Report", params(day = "chrono::NaiveDate", format = "%d.%m.%Y"))]
+        pub mod calendar {
+
+            #[route("/:time", view = "Report", params(time = "NaiveTime"))]
+            pub mod slot {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportKind {
+    Daily,
+}
+
+impl std::fmt::Display for ReportKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("daily")
+    }
+}
+
+impl std::str::FromStr for ReportKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "daily" => Ok(ReportKind::Daily),
+            _ => Err(()),
+        }
+    }
+}
+
+pub mod ids {
+    /// A user type sharing its name with `uuid::Uuid`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Uuid(pub u32);
+
+    impl std::fmt::Display for Uuid {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl std::str::FromStr for Uuid {
+        type Err = std::num::ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.parse().map(Uuid)
+        }
+    }
+}
+
+fn invoice_jsonld(number: u32) -> String {
+    format!(r#"{{"@type":"Invoice","identifier":"{number}"}}"#)
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Settings() -> impl IntoView { view! { "Settings" } }
+#[component]
+fn SettingsPage() -> impl IntoView { view! { "SettingsPage" } }
+#[component]
+fn Report() -> impl IntoView { view! { "Report" } }
+
+fn main() {
+    use routes::root::SettingsTab;
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    assert_that(SettingsTab::ALL).is_equal_to([SettingsTab::Profile, SettingsTab::TwoFactor]);
+    assert_that("two-factor".parse::<SettingsTab>()).is_equal_to(Ok(SettingsTab::TwoFactor));
+
+    assert_that(routes::root::Settings.materialize(SettingsTab::TwoFactor))
+        .is_equal_to("/settings/two-factor");
+    assert_that(routes::root::settings::Page.materialize(SettingsTab::Profile, 3))
+        .is_equal_to("/settings/profile/3");
+    assert_that(routes::root::Report.materialize(ReportKind::Daily)).is_equal_to("/reports/daily");
+
+    assert_that(routes::root::settings::Page.matches("/settings/profile/3")).is_true();
+    assert_that(routes::root::settings::Page.matches("/settings/unknown/3")).is_false();
+    assert_that(routes::root::settings::Page.matches("/settings/profile/three")).is_false();
+    assert_that(routes::root::Report.matches("/reports/weekly")).is_false();
+
+    let id = leptos_routes::__private::uuid::Uuid::nil();
+    assert_that(routes::root::Order.materialize(id, std::num::NonZeroU32::new(2)))
+        .is_equal_to("/orders/00000000-0000-0000-0000-000000000000/2");
+
+    // Raw parameters are converted into a typed params struct, reporting invalid values.
+    assert_that(routes::root::OrderParams::from_params(|name| match name {
+        "id" => Some("00000000-0000-0000-0000-000000000000"),
+        "page" => Some("2"),
+        _ => None,
+    }))
+    .is_equal_to(Ok(routes::root::OrderParams { id, page: std::num::NonZeroU32::new(2) }));
+    assert_that(routes::root::OrderParams::from_params(|name| match name {
+        "id" => Some("not-a-uuid"),
+        _ => None,
+    }))
+    .is_equal_to(Err(routes::ParamError::Invalid { name: "id", value: "not-a-uuid".to_string() }));
+    assert_that(routes::root::OrderParams::from_params(|name| match name {
+        "id" => Some("00000000-0000-0000-0000-000000000000"),
+        "page" => Some("100"),
+        _ => None,
+    }))
+    .is_equal_to(Err(routes::ParamError::Invalid { name: "page", value: "100".to_string() }));
+    assert_that(routes::root::OrderParams::from_params(|_| None))
+        .is_equal_to(Err(routes::ParamError::Missing { name: "id" }));
+
+    assert_that(routes::root::Ticket.materialize(ids::Uuid(7))).is_equal_to("/tickets/7");
+    assert_that(routes::root::TicketParams::from_params(|_| Some("7")))
+        .is_equal_to(Ok(routes::root::TicketParams { id: ids::Uuid(7) }));
+
+    let day = leptos_routes::__private::chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    let time = leptos_routes::__private::chrono::NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+    assert_that(routes::root::Calendar.materialize(day)).is_equal_to("/calendar/01.03.2025");
+    assert_that(routes::root::calendar::Slot.materialize(day, time))
+        .is_equal_to("/calendar/01.03.2025/09:30:00");
+    assert_that(routes::root::Calendar.matches("/calendar/01.03.2025")).is_true();
+    assert_that(routes::root::Calendar.matches("/calendar/2025-03-01")).is_false();
+    assert_that(routes::root::calendar::SlotParams::from_params(|name| match name {
+        "day" => Some("01.03.2025"),
+        "time" => Some("09:30:00"),
+        _ => None,
+    }))
+    .is_equal_to(Ok(routes::root::calendar::SlotParams { day, time }));
+
+    provide_context::<RequestUrl>(RequestUrl::new("/settings/profile/3"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><div>SettingsPage</div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/settings/unknown"));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Err404</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/invoices/12"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><script type="application/ld+json">{"@type":"Invoice","identifier":"12"}</script>Report</div>"#);
+
+    // Values not parsing render the fallback without reading the structured data.
+    provide_context::<RequestUrl>(RequestUrl::new("/invoices/twelve"));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Err404</div>"#);
+}
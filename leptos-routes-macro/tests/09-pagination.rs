@@ -0,0 +1,58 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::Router;
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/items", view = "ItemsPage", pagination)]
+    pub mod items {}
+
+    #[route("/users/:id/posts", view = "PostsPage", pagination(per_page = 50))]
+    pub mod posts {}
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn ItemsPage() -> impl IntoView {
+    let pagination = routes::Items.use_pagination();
+    view! { {move || format!("Items {} {} {}", pagination.page(), pagination.per_page(), pagination.offset())} }
+}
+#[component]
+fn PostsPage() -> impl IntoView {
+    let pagination = routes::Posts.use_pagination();
+    view! { {move || format!("Posts {} {}", pagination.page(), pagination.per_page())} }
+}
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    assert_that(routes::Items.materialize_page(3, 10)).is_equal_to("/items?page=3&per_page=10".to_string());
+    assert_that(routes::Posts.materialize_page("42", 1, 50))
+        .is_equal_to("/users/42/posts?page=1&per_page=50".to_string());
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::Items.materialize_page(3, 10).as_str()));
+    assert_that(app().to_html()).is_equal_to("Items 3 10 20");
+
+    // Absent or invalid query parameters fall back to the first page and the declared page size.
+    provide_context::<RequestUrl>(RequestUrl::new("/items?page=zero"));
+    assert_that(app().to_html()).is_equal_to("Items 1 20 0");
+
+    provide_context::<RequestUrl>(RequestUrl::new("/items?page=4294967295&per_page=4294967295"));
+    assert_that(app().to_html()).is_equal_to("Items 4294967295 4294967295 18446744060824649730");
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::Posts.materialize("42").as_str()));
+    assert_that(app().to_html()).is_equal_to("Posts 1 50");
+}
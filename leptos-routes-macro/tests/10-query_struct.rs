@@ -0,0 +1,38 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ItemFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_price: Option<u32>,
+}
+
+#[routes]
+pub mod routes {
+
+    #[route("/items", query_struct = "crate::ItemFilters")]
+    pub mod items {
+
+        #[route("/:category", query_struct = "crate::ItemFilters")]
+        pub mod category {}
+    }
+}
+
+fn main() {
+    let filters = ItemFilters {
+        search: Some("red shoes".to_string()),
+        max_price: Some(50),
+    };
+
+    assert_that(routes::Items.materialize_with_query(&filters))
+        .is_equal_to("/items?search=red+shoes&max_price=50".to_string());
+    assert_that(routes::items::Category.materialize_with_query("shoes", &ItemFilters::default()))
+        .is_equal_to("/items/shoes".to_string());
+
+    assert_that(routes::Items.parse_query("?search=red+shoes&max_price=50")).is_equal_to(Some(filters));
+    assert_that(routes::Items.parse_query("")).is_equal_to(Some(ItemFilters::default()));
+}
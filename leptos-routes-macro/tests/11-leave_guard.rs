@@ -0,0 +1,64 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[derive(Clone, Copy)]
+struct Draft(RwSignal<String>);
+
+fn has_unsaved_changes() -> bool {
+    expect_context::<Draft>().0.with(|draft| !draft.is_empty())
+}
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/editor", view = "Editor", leave_guard = "crate::has_unsaved_changes")]
+        pub mod editor {}
+
+        #[route("/settings", layout = "MainLayout", leave_guard = "|| true")]
+        pub mod settings {
+
+            #[route("/profile", view = "Profile")]
+            pub mod profile {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Editor() -> impl IntoView { view! { "Editor" } }
+#[component]
+fn Profile() -> impl IntoView { view! { "Profile" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+    provide_context(Draft(RwSignal::new(String::new())));
+
+    // Guards only install their listeners in the browser, leaving server-side rendering unaffected.
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::Editor.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Editor</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(
+        routes::root::settings::Profile.materialize().as_str(),
+    ));
+    assert_that(app().to_html()).is_equal_to(r#"<div><div>Profile</div></div>"#);
+}
@@ -0,0 +1,60 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, inherit_fallback, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "NotFound")]
+    pub mod root {
+
+        // Inherits the "NotFound" fallback of its parent.
+        #[route("/users", layout = "MainLayout")]
+        pub mod users {
+
+            #[route("/:id", layout = "MainLayout", fallback = "User")]
+            pub mod user {
+
+                #[route("/details", view = "Details")]
+                pub mod details {}
+            }
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn NotFound() -> impl IntoView { view! { "NotFound" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Details() -> impl IntoView { view! { "Details" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::Users.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div><div>NotFound</div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::users::User.materialize("42").as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div><div><div>User</div></div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(
+        routes::root::users::user::Details.materialize("42").as_str(),
+    ));
+    assert_that(app().to_html()).is_equal_to(r#"<div><div><div>Details</div></div></div>"#);
+}
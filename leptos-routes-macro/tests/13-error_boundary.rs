@@ -0,0 +1,66 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[derive(Debug, Clone)]
+struct LoadError;
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("could not load")
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+fn route_error(errors: ArcRwSignal<leptos::error::Errors>) -> impl IntoView {
+    move || errors.with(|errors| format!("Error: {}", errors.iter().map(|(_, e)| e.to_string()).collect::<String>()))
+}
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard", error = "crate::route_error")]
+    pub mod root {
+
+        #[route("/broken", view = "Broken")]
+        pub mod broken {}
+
+        #[route("/report", view = "Broken", error = "|_| view! { \"Report unavailable\" }")]
+        pub mod report {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Broken() -> impl IntoView { view! { {Err::<String, _>(LoadError)} } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::Root.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Dashboard</div>"#);
+
+    // The error boundary of the parent route catches errors of its child routes.
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::Broken.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"Error: could not load"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::Report.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Report unavailable</div>"#);
+}
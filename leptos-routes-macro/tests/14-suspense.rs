@@ -0,0 +1,55 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/users", view = "Users", suspense_fallback = "Spinner")]
+        pub mod users {}
+
+        #[route("/reports", view = "Reports", suspense_fallback = "|| view! { \"Loading reports\" }", transition)]
+        pub mod reports {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Spinner() -> impl IntoView { view! { "Loading" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn Reports() -> impl IntoView { view! { "Reports" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::Root.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Dashboard</div>"#);
+
+    // Rendering synchronously, suspense boundaries render their fallback.
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::Users.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Loading</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::Reports.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Loading reports</div>"#);
+}
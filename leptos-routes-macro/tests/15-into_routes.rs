@@ -0,0 +1,72 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Route, Router, Routes};
+use leptos_router::location::RequestUrl;
+use leptos_router::path;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/users", layout = "UsersLayout", fallback = "NoUser")]
+    pub mod users {
+
+        #[route("/:id", view = "User")]
+        pub mod user {}
+    }
+
+    #[route("/about", view = "About")]
+    pub mod about {}
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn NoUser() -> impl IntoView { view! { "NoUser" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn About() -> impl IntoView { view! { "About" } }
+#[component]
+fn Legacy() -> impl IntoView { view! { "Legacy" } }
+
+#[component(transparent)]
+fn UsersRoutes() -> impl leptos_router::MatchNestedRoutes + Clone {
+    routes::Users.into_routes()
+}
+#[component(transparent)]
+fn AboutRoutes() -> impl leptos_router::MatchNestedRoutes + Clone {
+    routes::About.into_routes()
+}
+
+fn main() {
+    // Generated route definitions can be mixed with hand-written ones.
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                <Routes fallback=|| view! { "Err404" }>
+                    <Route path=path!("/legacy") view=Legacy/>
+                    <UsersRoutes/>
+                    <AboutRoutes/>
+                </Routes>
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/legacy"));
+    assert_that(app().to_html()).is_equal_to(r#"Legacy"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::Users.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>NoUser</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::users::User.materialize("42").as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>User</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::About.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"About"#);
+}
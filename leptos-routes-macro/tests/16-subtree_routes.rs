@@ -0,0 +1,63 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Route, Router, Routes};
+use leptos_router::location::RequestUrl;
+use leptos_router::path;
+use leptos_routes::routes;
+use routes::root::UsersRoutes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/users", layout = "UsersLayout", fallback = "NoUser")]
+        pub mod users {
+
+            #[route("/:id", view = "User")]
+            pub mod user {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <section> <Outlet/> </section> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn NoUser() -> impl IntoView { view! { "NoUser" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Legacy() -> impl IntoView { view! { "Legacy" } }
+
+fn main() {
+    // Only the "users" subtree is embedded into this hand-written router.
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                <Routes fallback=|| view! { "Err404" }>
+                    <Route path=path!("/") view=Legacy/>
+                    <UsersRoutes/>
+                </Routes>
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::Root.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"Legacy"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::Users.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<section>NoUser</section>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::users::User.materialize("42").as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<section>User</section>"#);
+}
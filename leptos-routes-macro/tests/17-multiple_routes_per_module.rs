@@ -0,0 +1,53 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos::prelude::*;
+use leptos_router::components::Router;
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/login", view = "Login")]
+    #[route("/signin", name = "SignIn", view = "Login")]
+    pub mod login {}
+
+    #[route("/users/:id", name = "UserById", view = "User")]
+    #[route("/users/by-name/:name", name = "UserByName", view = "User")]
+    pub mod user {}
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn Login() -> impl IntoView { view! { "Login" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    assert_that(routes::Login.materialize()).is_equal_to("/login".to_string());
+    assert_that(routes::SignIn.materialize()).is_equal_to("/signin".to_string());
+    assert_that(routes::UserById.materialize("42")).is_equal_to("/users/42".to_string());
+    assert_that(routes::UserByName.materialize("bob")).is_equal_to("/users/by-name/bob".to_string());
+    assert_that(routes::SignIn.matches("/signin")).is_true();
+    assert_that(routes::Route::from_path("/signin")).is_equal_to(Some(routes::Route::SignIn(routes::SignIn)));
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::Login.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"Login"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::SignIn.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"Login"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::UserByName.materialize("bob").as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"User"#);
+}
@@ -0,0 +1,72 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", index = "Dashboard")]
+    pub mod root {
+
+        #[route("/users", layout = "UsersLayout", index = "UsersList")]
+        pub mod users {
+
+            #[route("/:id", layout = "UsersLayout", index = "User")]
+            pub mod user {
+
+                #[route("/details", view = "Details")]
+                pub mod details {}
+            }
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <section> <Outlet/> </section> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn UsersList() -> impl IntoView { view! { "UsersList" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Details() -> impl IntoView { view! { "Details" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    assert_that(routes::root::Index.materialize()).is_equal_to("/".to_string());
+    assert_that(routes::root::users::Index.materialize()).is_equal_to("/users".to_string());
+    assert_that(routes::root::users::user::Index.materialize("42")).is_equal_to("/users/42".to_string());
+    assert_that(routes::root::users::Index.matches("/users")).is_true();
+    assert_that(routes::root::users::Index.matches("/users/42")).is_false();
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::Index.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Dashboard</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::users::Index.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div><section>UsersList</section></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::users::user::Index.materialize("42").as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div><section><section>User</section></section></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(
+        routes::root::users::user::Details.materialize("42").as_str(),
+    ));
+    assert_that(app().to_html()).is_equal_to(r#"<div><section><section>Details</section></section></div>"#);
+}
@@ -0,0 +1,54 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::hooks::use_location;
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "CurrentRoute")]
+    pub mod root {
+
+        #[route("/users/:id", view = "CurrentRoute", params(id = "u32"))]
+        pub mod user {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn CurrentRoute() -> impl IntoView {
+    let location = use_location();
+    move || format!("{:?}", routes::Route::try_from_location(&location))
+}
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    assert_that(routes::Route::from_path_with_params("/users/42?tab=posts")).is_equal_to(Some((
+        routes::Route::RootUser(routes::root::User),
+        routes::RouteParams::RootUser(routes::root::UserParams { id: 42 }),
+    )));
+    // Values not parsable into the declared parameter type do not match.
+    assert_that(routes::Route::from_path_with_params("/users/bob")).is_equal_to(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::root::User.materialize(42).as_str()));
+    assert_that(app().to_html())
+        .is_equal_to(r#"<div>Some((RootUser(User), RootUser(UserParams { id: 42 })))</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new(routes::Root.materialize().as_str()));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Some((Root(Root), Root(RootParams)))</div>"#);
+}
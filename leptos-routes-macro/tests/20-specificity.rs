@@ -0,0 +1,78 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::Router;
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+// Declaring "/users/:id" before "/users/new" is warned about, unless allowed.
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+#[allow(deprecated)]
+pub mod routes {
+
+    // Declared first, but matched last, being the least specific route.
+    #[route("/*path", view = "NotFound")]
+    pub mod not_found {}
+
+    #[route("/users/:id", view = "User")]
+    pub mod user {}
+
+    #[route("/users/new", view = "NewUser")]
+    pub mod new_user {}
+
+    #[route("/:page", view = "Page")]
+    pub mod page {}
+
+    // Equally specific as "/:page", but matched first due to its priority.
+    #[route("/:lang", view = "Lang", constraint(lang = "en|de"), priority = 1)]
+    pub mod lang {}
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn NotFound() -> impl IntoView { view! { "NotFound" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn NewUser() -> impl IntoView { view! { "NewUser" } }
+#[component]
+fn Page() -> impl IntoView { view! { "Page" } }
+#[component]
+fn Lang() -> impl IntoView { view! { "Lang" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    assert_that(routes::NotFound.specificity()).is_equal_to(&[0, 2][..]);
+    assert_that(routes::User.specificity()).is_equal_to(&[4, 3, 2][..]);
+    assert_that(routes::NewUser.specificity()).is_equal_to(&[4, 4, 2][..]);
+    assert_that(routes::Page.priority()).is_equal_to(0);
+    assert_that(routes::Lang.priority()).is_equal_to(1);
+
+    assert_that(routes::Route::from_path("/users/new")).is_equal_to(Some(routes::Route::NewUser(routes::NewUser)));
+    assert_that(routes::Route::from_path("/users/42")).is_equal_to(Some(routes::Route::User(routes::User)));
+    assert_that(routes::Route::from_path("/en")).is_equal_to(Some(routes::Route::Lang(routes::Lang)));
+    assert_that(routes::Route::from_path("/about")).is_equal_to(Some(routes::Route::Page(routes::Page)));
+    assert_that(routes::Route::from_path("/a/b/c")).is_equal_to(Some(routes::Route::NotFound(routes::NotFound)));
+
+    provide_context::<RequestUrl>(RequestUrl::new("/users/new"));
+    assert_that(app().to_html()).is_equal_to("NewUser");
+
+    provide_context::<RequestUrl>(RequestUrl::new("/users/42"));
+    assert_that(app().to_html()).is_equal_to("User");
+
+    provide_context::<RequestUrl>(RequestUrl::new("/de"));
+    assert_that(app().to_html()).is_equal_to("Lang");
+
+    provide_context::<RequestUrl>(RequestUrl::new("/a/b/c"));
+    assert_that(app().to_html()).is_equal_to("NotFound");
+}
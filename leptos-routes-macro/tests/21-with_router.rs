@@ -0,0 +1,52 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::Outlet;
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, with_router, base = "/app", fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/users", view = "Users")]
+        pub mod users {}
+    }
+}
+
+#[routes(with_views, with_router, fallback = "|| view! { <Err404/> }")]
+pub mod unprefixed {
+
+    #[route("/users", view = "Users")]
+    pub mod users {}
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div id="main-layout"> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+
+fn render(url: &str, app: fn() -> AnyView) -> String {
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new(url));
+    app().to_html()
+}
+
+fn main() {
+    fn app() -> AnyView {
+        routes::generated_router().into_any()
+    }
+    fn unprefixed_app() -> AnyView {
+        unprefixed::generated_router().into_any()
+    }
+
+    assert_that(render("/app/users", app)).is_equal_to(r#"<div id="main-layout">Users</div>"#);
+    assert_that(render("/app", app)).is_equal_to(r#"<div id="main-layout">Dashboard</div>"#);
+    assert_that(render("/users", unprefixed_app)).is_equal_to("Users");
+}
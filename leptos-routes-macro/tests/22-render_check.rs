@@ -0,0 +1,64 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos::either::Either;
+use leptos::prelude::*;
+use leptos_router::components::Outlet;
+use leptos_routes::routes;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod stable {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/users", layout = "UsersLayout", fallback = "NoUser")]
+        pub mod users {
+
+            #[route("/:id", view = "User")]
+            pub mod user {}
+        }
+
+        #[route("/orders/:id", view = "Order", sample(id = "A-7"))]
+        pub mod order {}
+    }
+}
+
+static FLIPPED: AtomicBool = AtomicBool::new(false);
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div id="main-layout"> <Outlet/> </div> } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <div id="users-layout"> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn NoUser() -> impl IntoView { view! { "NoUser" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Order() -> impl IntoView { view! { "Order" } }
+#[component]
+fn Flaky() -> impl IntoView {
+    if FLIPPED.fetch_xor(true, Ordering::SeqCst) {
+        Either::Left(view! { <p>"Flaky"</p> })
+    } else {
+        Either::Right(view! { <span>"Flaky"</span> })
+    }
+}
+
+fn main() {
+    assert_that(stable::RENDER_TEST_PATHS).is_equal_to(
+        &["/", "/users", "/users/1", "/orders/A-7", "/__leptos_routes_unmatched__"][..],
+    );
+    assert_that(leptos_routes::rendering::init_pending_executor().is_ok()).is_true();
+    assert_that(leptos_routes::rendering::init_pending_executor().is_err()).is_true();
+    assert_that(stable::check_renders().is_ok()).is_true();
+
+    let mismatch = leptos_routes::renders::check_path("/users/1", || view! { <Flaky/> }).unwrap_err();
+    assert_that(mismatch.path).is_equal_to("/users/1".to_string());
+    assert_that(mismatch.reason.contains("<span>Flaky</span>")).is_true();
+    assert_that(mismatch.reason.contains("<p>Flaky</p>")).is_true();
+}
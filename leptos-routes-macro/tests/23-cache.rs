@@ -0,0 +1,45 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::cache::CachePolicy;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/welcome", cache(max_age = 3600, swr = 86400))]
+        pub mod welcome {}
+
+        // Child routes inherit the policy of their parent, unless declaring their own.
+        #[route("/docs", index = "Docs", cache(max_age = 600))]
+        pub mod docs {
+
+            #[route("/drafts", cache(swr = 60))]
+            pub mod drafts {}
+
+            #[route("/:page")]
+            pub mod page {}
+        }
+    }
+}
+
+fn main() {
+    use routes::*;
+
+    assert_that(cache_policy(&Route::from_path("/").unwrap())).is_equal_to(CachePolicy::NONE);
+    assert_that(cache_policy(&Route::from_path("/welcome").unwrap())).is_equal_to(CachePolicy {
+        max_age: Some(3600),
+        stale_while_revalidate: Some(86400),
+    });
+    assert_that(cache_policy(&Route::from_path("/docs/intro").unwrap()).cache_control())
+        .is_equal_to(Some("max-age=600".to_string()));
+    assert_that(cache_policy(&Route::from_path("/docs").unwrap()).cache_control())
+        .is_equal_to(Some("max-age=600".to_string()));
+    assert_that(cache_policy(&Route::from_path("/docs/drafts").unwrap()).cache_control())
+        .is_equal_to(Some("stale-while-revalidate=60".to_string()));
+    assert_that(cache_policy(&Route::from_path("/welcome").unwrap()).cache_control())
+        .is_equal_to(Some("max-age=3600, stale-while-revalidate=86400".to_string()));
+    assert_that(CachePolicy::NONE.cache_control()).is_equal_to(None);
+}
@@ -0,0 +1,100 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_router::{PathSegment, RouteList, SsrMode};
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/welcome", view = "Welcome", ssr = "Static")]
+        pub mod welcome {}
+
+        // Child routes are rendered using the most restrictive mode of their own and their parents.
+        #[route("/users", layout = "MainLayout", index = "Users", ssr = "InOrder")]
+        pub mod users {
+
+            #[route("/:id", view = "User", ssr = "Async")]
+            pub mod user {}
+
+            #[route("/*rest", view = "User", ssr = "PartiallyBlocked")]
+            pub mod rest {}
+        }
+
+        // Without a fallback, this route renders no page of its own.
+        #[route("/admin", layout = "MainLayout")]
+        pub mod admin {
+
+            #[route("/:tab?", view = "Admin")]
+            pub mod tab {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Welcome() -> impl IntoView { view! { "Welcome" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Admin() -> impl IntoView { view! { "Admin" } }
+
+fn describe(route_list: RouteList) -> Vec<(String, SsrMode)> {
+    route_list
+        .iter()
+        .map(|listing| {
+            let path = listing
+                .path()
+                .iter()
+                .filter(|segment| !matches!(segment, PathSegment::Unit))
+                .map(|segment| match segment {
+                    PathSegment::Static(s) => s.to_string(),
+                    PathSegment::Param(s) => format!(":{s}"),
+                    PathSegment::OptionalParam(s) => format!(":{s}?"),
+                    PathSegment::Splat(s) => format!("*{s}"),
+                    PathSegment::Unit => unreachable!(),
+                })
+                .filter(|segment| !segment.is_empty())
+                .collect::<Vec<_>>()
+                .join("/");
+            (format!("/{path}"), listing.mode().clone())
+        })
+        .collect()
+}
+
+fn main() {
+    let generated = describe(routes::generated_route_list_with_ssr());
+    assert_that(generated.clone()).is_equal_to(vec![
+        ("/welcome".to_string(), SsrMode::Static(Default::default())),
+        ("/users/:id".to_string(), SsrMode::Async),
+        ("/users".to_string(), SsrMode::InOrder),
+        ("/users/*rest".to_string(), SsrMode::InOrder),
+        ("/admin/:tab?".to_string(), SsrMode::OutOfOrder),
+        ("/".to_string(), SsrMode::OutOfOrder),
+    ]);
+
+    // The generated list equals the one discovered by rendering the app.
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new("/"));
+    let discovered = RouteList::generate(|| {
+        view! {
+            <Router>
+                {routes::generated_routes()}
+            </Router>
+        }
+    })
+    .expect("routes to be discovered");
+    assert_that(describe(discovered)).is_equal_to(generated);
+}
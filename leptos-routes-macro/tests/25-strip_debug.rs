@@ -0,0 +1,59 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, strip_debug, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/users", layout = "MainLayout", fallback = "Users")]
+        pub mod users {
+
+            #[route("/:id", view = "User", params(id = "u32"))]
+            pub mod user {}
+
+            #[route("/:id/users", view = "User")]
+            pub mod user_users {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+
+fn main() {
+    // Each static segment is stored once, however many routes share it.
+    assert_that(routes::__segments::SEGMENT_0).is_equal_to("users");
+
+    assert_that(routes::root::users::User.matches("/users/42")).is_true();
+    assert_that(routes::root::users::User.matches("/users/abc")).is_false();
+    assert_that(routes::root::users::UserUsers.matches("/users/42/users")).is_true();
+
+    // Debug builds keep `Debug` implementations and the `Route` enum.
+    assert_that(format!("{:?}", routes::root::users::User)).is_equal_to("User".to_string());
+    assert_that(routes::Route::from_path("/users/42/users").is_some()).is_true();
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new("/users/42"));
+    assert_that(app().to_html()).is_equal_to("<div><div>User</div></div>");
+}
@@ -0,0 +1,48 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes(deny_unused)]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/welcome")]
+        pub mod welcome {}
+
+        #[route("/users", pagination)]
+        pub mod users {
+
+            #[route("/:id")]
+            pub mod user {}
+        }
+
+        #[route("/legacy")]
+        pub mod legacy {}
+    }
+}
+
+fn main() {
+    assert_that(routes::unused_routes()).is_equal_to(vec![
+        "Root",
+        "root::Welcome",
+        "root::Users",
+        "root::users::User",
+        "root::Legacy",
+    ]);
+
+    // Materializing a child route does not count as using its parents.
+    assert_that(routes::root::users::User.materialize("42")).is_equal_to("/users/42".to_string());
+    assert_that(routes::unused_routes()).is_equal_to(vec![
+        "Root",
+        "root::Welcome",
+        "root::Users",
+        "root::Legacy",
+    ]);
+
+    let _ = routes::Root.materialize();
+    let _ = routes::root::Welcome.materialize();
+    let _ = routes::root::Users.materialize_page(2, 20);
+    assert_that(routes::unused_routes()).is_equal_to(vec!["root::Legacy"]);
+}
@@ -0,0 +1,55 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, log_deprecated, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/terms", view = "Terms")]
+        pub mod terms {}
+
+        #[route("/tos", view = "Terms", deprecated = "use /terms")]
+        pub mod tos {}
+
+        #[route("/old", view = "Terms", deprecated)]
+        pub mod old {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Terms() -> impl IntoView { view! { "Terms" } }
+
+fn main() {
+    // Using a deprecated route struct triggers a deprecation warning.
+    #[expect(deprecated)]
+    let tos = routes::root::Tos.materialize();
+    assert_that(tos.clone()).is_equal_to("/tos".to_string());
+    #[expect(deprecated)]
+    let old = routes::root::Old.materialize();
+    assert_that(old).is_equal_to("/old".to_string());
+
+    // Deprecated routes are still matched and rendered.
+    assert_that(routes::Route::from_path(&tos)).is_equal_to(routes::Route::from_path("/tos"));
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new("/tos"));
+    assert_that(app().to_html()).is_equal_to("<div>Terms</div>");
+}
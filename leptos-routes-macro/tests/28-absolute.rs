@@ -0,0 +1,75 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/users/:id", layout = "UserLayout", fallback = "User")]
+        pub mod user {
+
+            #[route("/details", view = "Details")]
+            pub mod details {}
+
+            #[route("!/legal/terms", view = "Terms")]
+            pub mod terms {}
+
+            #[route("/help", absolute, view = "Help")]
+            pub mod help {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn UserLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Details() -> impl IntoView { view! { "Details" } }
+#[component]
+fn Terms() -> impl IntoView { view! { "Terms" } }
+#[component]
+fn Help() -> impl IntoView { view! { "Help" } }
+
+fn main() {
+    // Nested routes inherit the path and the params of their parent routes.
+    assert_that(routes::root::user::Details.materialize("42")).is_equal_to("/users/42/details".to_string());
+
+    // Absolute routes inherit neither.
+    assert_that(routes::root::user::Terms.materialize()).is_equal_to("/legal/terms".to_string());
+    assert_that(routes::root::user::Help.materialize()).is_equal_to("/help".to_string());
+    assert_that(routes::Route::from_path("/legal/terms").is_some()).is_equal_to(true);
+    assert_that(routes::Route::from_path("/users/42/legal/terms").is_none()).is_equal_to(true);
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    // Absolute routes are not rendered inside the layouts of their parent routes.
+    for (path, expected) in [
+        ("/users/42/details", "<main><div>Details</div></main>"),
+        ("/legal/terms", "Terms"),
+        ("/help", "Help"),
+        ("/users/42/help", "Err404"),
+    ] {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(expected.to_string());
+    }
+}
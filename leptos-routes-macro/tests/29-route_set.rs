@@ -0,0 +1,51 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/welcome")]
+        pub mod welcome {}
+
+        #[route("/admin")]
+        pub mod admin {
+
+            #[route("/users")]
+            pub mod users {}
+        }
+    }
+}
+
+use routes::{root, Route, RouteSet};
+
+// Sets can be built in constant contexts, e.g. as the routes allowed for a role.
+const ADMIN_ONLY: RouteSet = RouteSet::EMPTY
+    .with(Route::RootAdmin(root::Admin))
+    .with(Route::RootAdminUsers(root::admin::Users));
+const GUEST: RouteSet = RouteSet::ALL.intersection(ADMIN_ONLY).without(Route::RootAdmin(root::Admin));
+
+fn main() {
+    assert_that(Route::COUNT).is_equal_to(4);
+    assert_that(Route::Root(routes::Root).index()).is_equal_to(0);
+    assert_that(Route::RootAdminUsers(root::admin::Users).index()).is_equal_to(3);
+
+    assert_that(RouteSet::EMPTY.is_empty()).is_true();
+    assert_that(RouteSet::ALL.len()).is_equal_to(Route::COUNT);
+    assert_that(ADMIN_ONLY.len()).is_equal_to(2);
+    assert_that(ADMIN_ONLY.contains(Route::RootAdmin(root::Admin))).is_true();
+    assert_that(ADMIN_ONLY.contains(Route::RootWelcome(root::Welcome))).is_false();
+    assert_that(GUEST.contains(Route::RootAdminUsers(root::admin::Users))).is_true();
+    assert_that(GUEST.len()).is_equal_to(1);
+
+    let mut visited = RouteSet::default();
+    assert_that(visited.insert(Route::from_path("/welcome").unwrap())).is_true();
+    assert_that(visited.insert(Route::from_path("/welcome").unwrap())).is_false();
+    visited.extend([Route::Root(routes::Root)]);
+    assert_that(visited).is_equal_to([Route::Root(routes::Root), Route::RootWelcome(root::Welcome)].into_iter().collect());
+    assert_that(visited.remove(Route::Root(routes::Root))).is_true();
+    assert_that(visited.union(ADMIN_ONLY).len()).is_equal_to(3);
+}
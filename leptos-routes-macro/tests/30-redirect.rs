@@ -0,0 +1,38 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::redirect::{Redirect, RedirectStatus};
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users/:id")]
+        pub mod user {
+
+            #[route("/details")]
+            pub mod details {}
+        }
+    }
+}
+
+fn main() {
+    let redirect = routes::root::user::Details.redirect("42");
+    assert_that(redirect.location()).is_equal_to("/users/42/details");
+    assert_that(redirect.status()).is_equal_to(RedirectStatus::Found);
+    assert_that(redirect.status_code()).is_equal_to(302);
+
+    let redirect = routes::Root.redirect().permanent();
+    assert_that(redirect.status_code()).is_equal_to(301);
+    assert_that(redirect.into_location()).is_equal_to("/".to_string());
+
+    let redirect = routes::root::User.redirect("7").temporary();
+    assert_that(redirect.status_code()).is_equal_to(307);
+
+    let redirect = routes::root::User
+        .redirect("7")
+        .with_status(RedirectStatus::SeeOther);
+    assert_that(redirect).is_equal_to(Redirect::new("/users/7").with_status(RedirectStatus::SeeOther));
+}
@@ -0,0 +1,24 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes(method_override = "_method")]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users/:id")]
+        pub mod user {
+
+            #[route("/details")]
+            pub mod details {}
+        }
+    }
+}
+
+fn main() {
+    assert_that(routes::root::user::Details.form_action("42")).is_equal_to("/users/42/details".to_string());
+    assert_that(routes::root::User.form_action_with_method("42", "put")).is_equal_to("/users/42?_method=PUT".to_string());
+    assert_that(routes::Root.form_action_with_method("DELETE")).is_equal_to("/?_method=DELETE".to_string());
+}
@@ -0,0 +1,80 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::flags::FlagSet;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/dashboard", view = "Dashboard", flag = "new_dashboard")]
+        pub mod dashboard {}
+
+        #[route("/beta", layout = "BetaLayout", fallback = "Beta", flag = "beta")]
+        pub mod beta {
+
+            #[route("/reports", view = "Reports", flag = "reports")]
+            pub mod reports {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn BetaLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Beta() -> impl IntoView { view! { "Beta" } }
+#[component]
+fn Reports() -> impl IntoView { view! { "Reports" } }
+
+use routes::{root, Route};
+
+fn main() {
+    // Routes are enabled when the flags of the route and all of its parents are enabled.
+    let enabled = routes::enabled_routes(&FlagSet::new().with("reports"));
+    assert_that(enabled.contains(Route::Root(routes::Root))).is_true();
+    assert_that(enabled.contains(Route::RootDashboard(root::Dashboard))).is_false();
+    assert_that(enabled.contains(Route::RootBetaReports(root::beta::Reports))).is_false();
+
+    let enabled = routes::enabled_routes(&["beta", "reports"].into_iter().collect());
+    assert_that(enabled.contains(Route::RootBeta(root::Beta))).is_true();
+    assert_that(enabled.contains(Route::RootBetaReports(root::beta::Reports))).is_true();
+    assert_that(enabled.len()).is_equal_to(3);
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    fn render(path: &str, flags: FlagSet) -> String {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        provide_context(flags);
+        app().to_html()
+    }
+
+    // Disabled routes render the fallback instead.
+    assert_that(render("/dashboard", FlagSet::new())).is_equal_to("<main>Err404</main>".to_string());
+    assert_that(render("/dashboard", FlagSet::new().with("new_dashboard")))
+        .is_equal_to("<main>Dashboard</main>".to_string());
+    assert_that(render("/beta/reports", FlagSet::new().with("reports")))
+        .is_equal_to("<main>Err404</main>".to_string());
+    assert_that(render("/beta/reports", FlagSet::new().with("beta")))
+        .is_equal_to("<main><div>Err404</div></main>".to_string());
+    assert_that(render("/beta/reports", FlagSet::new().with("beta").with("reports")))
+        .is_equal_to("<main><div>Reports</div></main>".to_string());
+}
@@ -0,0 +1,34 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes(host_param = "tenant", host = "example.com")]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users/:id")]
+        pub mod user {
+
+            #[route("/details")]
+            pub mod details {}
+        }
+    }
+}
+
+use routes::{root, Route};
+
+fn main() {
+    assert_that(routes::Root.materialize_absolute("acme")).is_equal_to("https://acme.example.com/".to_string());
+    assert_that(root::user::Details.materialize_absolute("acme", "42"))
+        .is_equal_to("https://acme.example.com/users/42/details".to_string());
+
+    assert_that(Route::from_url("https://acme.example.com/users/42/details?tab=1"))
+        .is_equal_to(Some(("acme".to_string(), Route::RootUserDetails(root::user::Details))));
+    assert_that(Route::from_url("http://acme.example.com:8080"))
+        .is_equal_to(Some(("acme".to_string(), Route::Root(routes::Root))));
+    assert_that(Route::from_url("https://example.com/users/42")).is_equal_to(None);
+    assert_that(Route::from_url("https://acme.other.com/users/42")).is_equal_to(None);
+    assert_that(Route::from_url("https://acme.example.com/unknown")).is_equal_to(None);
+}
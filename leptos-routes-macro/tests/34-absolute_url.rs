@@ -0,0 +1,31 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::absolute::Url;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users/:id")]
+        pub mod user {
+
+            #[route("/details")]
+            pub mod details {}
+        }
+    }
+}
+
+fn main() {
+    let base = Url::parse("https://example.com").unwrap();
+    assert_that(routes::Root.absolute(&base).as_str()).is_equal_to("https://example.com/");
+    assert_that(routes::root::user::Details.absolute(&base, "42").as_str())
+        .is_equal_to("https://example.com/users/42/details");
+
+    // The path of the base is kept, while its query and fragment are dropped.
+    let base = Url::parse("https://example.com/app/?ref=mail#top").unwrap();
+    assert_that(routes::root::User.absolute(&base, "a b").as_str())
+        .is_equal_to("https://example.com/app/users/a%20b");
+}
@@ -0,0 +1,66 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+use std::panic;
+use std::sync::Mutex;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users/:id")]
+        pub mod user {
+
+            #[route("/details/:tab?")]
+            pub mod details {}
+        }
+
+        #[route("/files/*path")]
+        pub mod files {}
+    }
+}
+
+static PANIC_LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+/// The message and line of the panic caused by `f`.
+fn panic_of(f: impl FnOnce() -> String + panic::UnwindSafe) -> (String, u32) {
+    let payload = panic::catch_unwind(f).expect_err("panicked");
+    let message = payload.downcast_ref::<String>().cloned().expect("message");
+    let (file, line) = PANIC_LOCATION.lock().unwrap().take().expect("location");
+    assert_that(file.as_str()).is_equal_to(file!());
+    (message, line)
+}
+
+fn main() {
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().expect("location");
+        *PANIC_LOCATION.lock().unwrap() = Some((location.file().to_string(), location.line()));
+    }));
+
+    // Wildcards may contain slashes.
+    assert_that(routes::root::Files.materialize("a/b.txt")).is_equal_to("/files/a/b.txt".to_string());
+
+    let line = line!() + 1;
+    let (message, at) = panic_of(|| routes::root::User.materialize("a/b"));
+    assert_that(message).is_equal_to(
+        "The value \"a/b\" of parameter \"id\" of route \"/users/:id\" must not contain a '/'.".to_string(),
+    );
+    assert_that(at).is_equal_to(line);
+
+    // Parameters of parent routes are reported at the call site of the child route, too.
+    let line = line!() + 1;
+    let (message, at) = panic_of(|| routes::root::user::Details.materialize("", None));
+    assert_that(message).is_equal_to(
+        "The value of parameter \"id\" of route \"/users/:id\" must not be empty.".to_string(),
+    );
+    assert_that(at).is_equal_to(line);
+
+    let line = line!() + 1;
+    let (message, at) = panic_of(|| routes::root::user::Details.materialize("42", Some("")));
+    assert_that(message).is_equal_to(
+        "The value of parameter \"tab\" of route \"/users/:id/details/:tab?\" must not be empty.".to_string(),
+    );
+    assert_that(at).is_equal_to(line);
+}
@@ -0,0 +1,65 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, route_recorder, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/users", layout = "UsersLayout", index = "Users")]
+        pub mod users {
+
+            #[route("/:id", view = "User")]
+            pub mod user {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+
+use routes::{root, Route, RouteRecorder};
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let recorder = RouteRecorder::new();
+    for path in ["/", "/users/42", "/unknown"] {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        recorder.provide();
+        let _ = app().to_html();
+    }
+
+    recorder.assert_visited(Route::Root(routes::Root));
+    recorder.assert_visited(Route::RootUsersUser(root::users::User));
+    recorder.assert_not_visited(Route::RootUsersIndex(root::users::Index));
+    assert_that(recorder.visited()).is_equal_to(vec![
+        Route::Root(routes::Root),
+        Route::RootUsersUser(root::users::User),
+    ]);
+
+    recorder.clear();
+    assert_that(recorder.visited()).is_equal_to(Vec::new());
+}
@@ -0,0 +1,60 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, data_route, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/users", layout = "UsersLayout", fallback = "Users")]
+        pub mod users {
+
+            #[route("/:id", view = "User", name = "UserProfile")]
+            pub mod user {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Home() -> impl IntoView { view! { <h1>"Home"</h1> } }
+#[component]
+fn Users() -> impl IntoView { view! { <ul></ul> } }
+#[component]
+fn User() -> impl IntoView { view! { <p>"User"</p> } }
+
+fn main() {
+    assert_that(routes::root::users::UserProfile::DATA_ROUTE).is_equal_to("root.users.user_profile");
+    assert_that(routes::DATA_ROUTES).is_equal_to(&[
+        ("root", "/"),
+        ("root.users", "/users"),
+        ("root.users.user_profile", "/users/:id"),
+    ][..]);
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    for (path, expected) in [
+        ("/", r#"<main><h1 data-route="root">Home</h1></main>"#),
+        ("/users", r#"<main><div><ul data-route="root.users"></ul></div></main>"#),
+        ("/users/42", r#"<main><div><p data-route="root.users.user_profile">User</p></div></main>"#),
+    ] {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(expected.to_string());
+    }
+}
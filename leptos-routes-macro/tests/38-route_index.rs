@@ -0,0 +1,62 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, route_index, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/users/:id", layout = "UserLayout", fallback = "User", sample(id = "42"))]
+        pub mod user {
+
+            #[route("/posts/:post/:tab?", view = "Post", sample(post = "7"))]
+            pub mod post {}
+        }
+
+        #[route("/files/*path", view = "Files")]
+        pub mod files {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn UserLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Post() -> impl IntoView { view! { "Post" } }
+#[component]
+fn Files() -> impl IntoView { view! { "Files" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new("/__routes"));
+    assert_that(app().to_html()).is_equal_to(
+        concat!(
+            "<h1>Routes</h1><ul>",
+            r#"<li><a href="/">/</a></li>"#,
+            r#"<li><a href="/users/42">/users/:id</a></li>"#,
+            r#"<li><a href="/users/42/posts/7">/users/:id/posts/:post/:tab?</a></li>"#,
+            "<li>/files/*path</li>",
+            "</ul>",
+        )
+        .to_string(),
+    );
+}
@@ -0,0 +1,30 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::{route_path, routes};
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users/:id")]
+        pub mod user {
+
+            #[route("/posts/:post/:tab?", params(post = "u32"))]
+            pub mod post {}
+        }
+    }
+}
+
+fn main() {
+    assert_that(route_path!(routes::Root)).is_equal_to("/".to_string());
+    assert_that(route_path!(routes::root::User, id = "42")).is_equal_to("/users/42".to_string());
+
+    // Arguments are matched by name, in any order.
+    let id = String::from("42");
+    assert_that(route_path!(routes::root::user::Post, post = 7, tab = Some("comments"), id = &id))
+        .is_equal_to("/users/42/posts/7/comments".to_string());
+    assert_that(route_path!(routes::root::user::Post, id = "42", post = 7, tab = None,))
+        .is_equal_to("/users/42/posts/7".to_string());
+}
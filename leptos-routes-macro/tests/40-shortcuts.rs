@@ -0,0 +1,56 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home", shortcut = "g h")]
+    pub mod root {
+
+        #[route("/users/:page?", view = "Users", shortcut = "g  u")]
+        pub mod users {}
+
+        #[route("/help", view = "Help", shortcut = "?")]
+        pub mod help {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView {
+    routes::use_route_shortcuts();
+    view! { <main> <Outlet/> </main> }
+}
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn Help() -> impl IntoView { view! { "Help" } }
+
+use routes::{root, Route};
+
+fn main() {
+    assert_that(routes::shortcuts()).is_equal_to(vec![
+        ("g h".to_string(), Route::Root(routes::Root)),
+        ("g u".to_string(), Route::RootUsers(root::Users)),
+        ("?".to_string(), Route::RootHelp(root::Help)),
+    ]);
+
+    // The listener is only installed in the browser.
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new("/help"));
+    assert_that(app().to_html()).is_equal_to("<main>Help</main>".to_string());
+}
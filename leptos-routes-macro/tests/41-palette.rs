@@ -0,0 +1,64 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos::prelude::*;
+use leptos_router::components::Outlet;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home", title = "Home")]
+    pub mod root {
+
+        #[route(
+            "/users/:page?",
+            layout = "UsersLayout",
+            fallback = "Users",
+            title = "Users",
+            keywords("people", "Members")
+        )]
+        pub mod users {
+
+            #[route("/:id", view = "User", title = "User")]
+            pub mod user {}
+        }
+
+        #[route("/help", view = "Help")]
+        pub mod help {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> "MainLayout" </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <Outlet/> } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Help() -> impl IntoView { view! { "Help" } }
+
+use routes::{root, Route};
+
+fn main() {
+    // Untitled routes and routes with required parameters are not listed.
+    let entries = routes::searchable_routes();
+    let titles = entries.iter().map(|entry| entry.title).collect::<Vec<_>>();
+    assert_that(titles).is_equal_to(vec!["Home", "Users"]);
+
+    let users = entries[1];
+    assert_that(users.route).is_equal_to(Route::RootUsers(root::Users));
+    assert_that(users.keywords).is_equal_to(&["people", "Members"][..]);
+    assert_that((users.href)()).is_equal_to("/users".to_string());
+    assert_that((entries[0].href)()).is_equal_to("/".to_string());
+
+    assert_that(users.matches("USE")).is_true();
+    assert_that(users.matches(" members ")).is_true();
+    assert_that(users.matches("")).is_true();
+    assert_that(users.matches("help")).is_false();
+}
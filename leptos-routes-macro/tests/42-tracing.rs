@@ -0,0 +1,64 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, tracing, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/users", layout = "UsersLayout", fallback = "Users")]
+        pub mod users {
+
+            #[route("/:id", view = "User", params(id = "u64"))]
+            pub mod user {}
+        }
+
+        pub mod search {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <Outlet/> } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Search() -> impl IntoView { view! { "Search" } }
+
+use routes::root::{self, users};
+
+fn main() {
+    assert_that(routes::Root::SPAN_NAME).is_equal_to("route.root");
+    assert_that(root::Users::SPAN_NAME).is_equal_to("route.root.users");
+    assert_that(users::User::SPAN_NAME).is_equal_to("route.root.users.user");
+
+    // Rendering inside spans leaves the rendered HTML untouched.
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    for (path, html) in [
+        ("/", "<main>Home</main>"),
+        ("/users", "<main>Users</main>"),
+        ("/users/42", "<main>User</main>"),
+    ] {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(html.to_string());
+    }
+}
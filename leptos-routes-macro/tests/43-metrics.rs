@@ -0,0 +1,119 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+use metrics::{
+    Counter, CounterFn, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder,
+    SharedString, Unit,
+};
+use std::sync::{Arc, Mutex};
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/users", layout = "UsersLayout", fallback = "Users")]
+        pub mod users {
+
+            #[route("/:id", view = "User")]
+            pub mod user {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <Outlet/> } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+
+use routes::root::{self, users};
+
+/// Records the metric name and route label of every recorded value.
+#[derive(Default)]
+struct TestRecorder(Arc<Mutex<Vec<(String, String)>>>);
+
+struct TestHandle {
+    name: String,
+    route: String,
+    records: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl TestHandle {
+    fn push(&self) {
+        self.records.lock().unwrap().push((self.name.clone(), self.route.clone()));
+    }
+}
+
+impl CounterFn for TestHandle {
+    fn increment(&self, _value: u64) { self.push() }
+    fn absolute(&self, _value: u64) { self.push() }
+}
+
+impl HistogramFn for TestHandle {
+    fn record(&self, _value: f64) { self.push() }
+}
+
+impl TestRecorder {
+    fn handle(&self, key: &Key) -> Arc<TestHandle> {
+        let route = key.labels().find(|label| label.key() == "route").unwrap();
+        Arc::new(TestHandle {
+            name: key.name().to_string(),
+            route: route.value().to_string(),
+            records: self.0.clone(),
+        })
+    }
+}
+
+impl Recorder for TestRecorder {
+    fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+        Counter::from_arc(self.handle(key))
+    }
+    fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+        Gauge::noop()
+    }
+    fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+        Histogram::from_arc(self.handle(key))
+    }
+}
+
+fn main() {
+    assert_that(routes::Root::PATTERN).is_equal_to("/");
+    assert_that(root::Users::PATTERN).is_equal_to("/users");
+    assert_that(users::User::PATTERN).is_equal_to("/users/:id");
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    let recorder = TestRecorder::default();
+    let html = metrics::with_local_recorder(&recorder, || {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new("/users/42"));
+        app().to_html()
+    });
+    assert_that(html).is_equal_to("<main>User</main>".to_string());
+
+    use leptos_routes::metrics::{ROUTE_MATCHED, ROUTE_RENDER_DURATION};
+    assert_that(recorder.0.lock().unwrap().clone()).is_equal_to(vec![
+        (ROUTE_MATCHED.to_string(), users::User::PATTERN.to_string()),
+        (ROUTE_RENDER_DURATION.to_string(), users::User::PATTERN.to_string()),
+    ]);
+}
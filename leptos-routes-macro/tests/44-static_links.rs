@@ -0,0 +1,47 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::links::fill_href;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users/:page?")]
+        pub mod users {
+
+            #[route("/:id")]
+            pub mod user {
+
+                #[route("/details")]
+                pub mod details {}
+            }
+        }
+
+        #[route("/files/*path")]
+        pub mod files {}
+    }
+}
+
+fn main() {
+    let links = routes::static_link_map();
+    assert_that(links.into_iter().collect::<Vec<_>>()).is_equal_to(vec![
+        ("root", "/"),
+        ("root.files", "/files/{*path}"),
+        ("root.users", "/users/{page?}"),
+        ("root.users.user", "/users/{page?}/{id}"),
+        ("root.users.user.details", "/users/{page?}/{id}/details"),
+    ]);
+
+    let details = routes::static_link_map()["root.users.user.details"];
+    assert_that(fill_href(details, &[("id", "42")]))
+        .is_equal_to(Some("/users/42/details".to_string()));
+    assert_that(fill_href(details, &[("id", "42"), ("page", "2")]))
+        .is_equal_to(Some("/users/2/42/details".to_string()));
+    assert_that(fill_href(details, &[("page", "2")])).is_equal_to(None);
+    assert_that(fill_href("/files/{*path}", &[("path", "a/b.txt")]))
+        .is_equal_to(Some("/files/a/b.txt".to_string()));
+    assert_that(fill_href("/", &[])).is_equal_to(Some("/".to_string()));
+}
@@ -0,0 +1,36 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::prefetch::Prefetch;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/welcome", prefetch = "visible")]
+        pub mod welcome {}
+
+        // Child routes inherit the mode of their parent, unless declaring their own.
+        #[route("/docs", prefetch = "hover")]
+        pub mod docs {
+
+            #[route("/drafts", prefetch = "none")]
+            pub mod drafts {}
+
+            #[route("/:page")]
+            pub mod page {}
+        }
+    }
+}
+
+fn main() {
+    use routes::*;
+
+    assert_that(prefetch(&Route::from_path("/").unwrap())).is_equal_to(Prefetch::None);
+    assert_that(prefetch(&Route::from_path("/welcome").unwrap())).is_equal_to(Prefetch::Visible);
+    assert_that(prefetch(&Route::from_path("/docs").unwrap())).is_equal_to(Prefetch::Hover);
+    assert_that(prefetch(&Route::from_path("/docs/intro").unwrap())).is_equal_to(Prefetch::Hover);
+    assert_that(prefetch(&Route::from_path("/docs/drafts").unwrap()).as_str()).is_equal_to("none");
+}
@@ -0,0 +1,44 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos_routes::bench::criterion::Criterion;
+use leptos_routes::routes;
+use std::time::Duration;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users/:page?")]
+        pub mod users {
+
+            #[route("/:id", params(id = "u64"), sample(id = "42"))]
+            pub mod user {
+
+                #[route("/details")]
+                pub mod details {}
+            }
+        }
+
+        // Left out, lacking a sample value of its required parameter.
+        #[route("/teams/:team")]
+        pub mod team {}
+    }
+}
+
+fn main() {
+    assert_that(routes::BENCH_URLS).is_equal_to(
+        &["/", "/users", "/users/42", "/users/42/details"][..],
+    );
+    for url in routes::BENCH_URLS {
+        assert_that(routes::Route::from_path(url).is_some()).is_true();
+    }
+
+    let mut criterion = Criterion::default()
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(1))
+        .measurement_time(Duration::from_millis(10))
+        .without_plots();
+    routes::bench_match_path(&mut criterion);
+}
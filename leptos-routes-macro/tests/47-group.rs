@@ -0,0 +1,100 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        // Shares the `AccountLayout` without adding a path segment.
+        #[route(group, layout = "AccountLayout")]
+        pub mod account {
+
+            #[route("/profile", view = "Profile")]
+            pub mod profile {}
+
+            #[route("/settings/:tab", view = "Settings")]
+            pub mod settings {}
+        }
+
+        // Without a layout, a group only nests its children.
+        #[route(group)]
+        pub mod legal {
+
+            #[route("/terms", view = "Terms")]
+            pub mod terms {}
+        }
+
+        #[route("/admin", layout = "AdminLayout")]
+        pub mod admin {
+
+            #[route(group, layout = "ReportsLayout")]
+            pub mod reports {
+
+                #[route("/sales", view = "Sales")]
+                pub mod sales {}
+            }
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn AccountLayout() -> impl IntoView { view! { <section> <Outlet/> </section> } }
+#[component]
+fn Profile() -> impl IntoView { view! { "Profile" } }
+#[component]
+fn Settings() -> impl IntoView { view! { "Settings" } }
+#[component]
+fn Terms() -> impl IntoView { view! { "Terms" } }
+#[component]
+fn AdminLayout() -> impl IntoView { view! { <Outlet/> } }
+#[component]
+fn ReportsLayout() -> impl IntoView { view! { <article> <Outlet/> </article> } }
+#[component]
+fn Sales() -> impl IntoView { view! { "Sales" } }
+
+use routes::root::{account, admin, legal};
+use routes::Route;
+
+fn main() {
+    assert_that(account::Profile.materialize()).is_equal_to("/profile".to_string());
+    assert_that(account::Settings.materialize("privacy"))
+        .is_equal_to("/settings/privacy".to_string());
+    assert_that(legal::Terms.materialize()).is_equal_to("/terms".to_string());
+    assert_that(admin::reports::Sales.materialize()).is_equal_to("/admin/sales".to_string());
+
+    // Groups are never matched themselves.
+    assert_that(Route::from_path("/")).is_equal_to(Some(Route::Root(routes::Root)));
+    assert_that(Route::from_path("/profile"))
+        .is_equal_to(Some(Route::RootAccountProfile(account::Profile)));
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    for (path, html) in [
+        ("/", "<main>Home</main>"),
+        ("/profile", "<main><section>Profile</section></main>"),
+        ("/settings/privacy", "<main><section>Settings</section></main>"),
+        ("/terms", "<main>Terms</main>"),
+        ("/admin/sales", "<main><article>Sales</article></main>"),
+    ] {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(html.to_string());
+    }
+}
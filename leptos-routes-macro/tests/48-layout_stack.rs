@@ -0,0 +1,70 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(
+    with_views,
+    fallback = "|| view! { <Err404/> }",
+    layout_stacks(shell("AppShell", "Section"))
+)]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        // The stack wraps the route outside of its own layout.
+        #[route("/users", layout_stack = "shell", layout = "UsersLayout", fallback = "Users")]
+        pub mod users {
+
+            #[route("/:id", view = "User")]
+            pub mod user {}
+        }
+
+        #[route("/settings", layout_stack = "shell", view = "Settings")]
+        pub mod settings {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn AppShell() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Section() -> impl IntoView { view! { <section> <Outlet/> </section> } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <ul> <Outlet/> </ul> } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Settings() -> impl IntoView { view! { "Settings" } }
+
+fn main() {
+    assert_that(routes::root::users::User.materialize("42")).is_equal_to("/users/42".to_string());
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    for (path, html) in [
+        ("/", "<main>Home</main>"),
+        ("/users", "<main><div><section><ul>Users</ul></section></div></main>"),
+        ("/users/42", "<main><div><section><ul>User</ul></section></div></main>"),
+        ("/settings", "<main><div><section>Settings</section></div></main>"),
+    ] {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(html.to_string());
+    }
+}
@@ -0,0 +1,46 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::Outlet;
+use leptos_router::location::RequestUrl;
+use leptos_routes::config::RoutesConfig;
+use leptos_routes::flags::FlagSet;
+use leptos_routes::routes;
+
+#[routes(with_views, with_router, base = "/app", fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/beta", view = "Beta", flag = "beta")]
+        pub mod beta {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Beta() -> impl IntoView { view! { "Beta" } }
+
+fn render(url: &str, config: RoutesConfig) -> String {
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new(url));
+    routes::generated_router_with(config).to_html()
+}
+
+fn main() {
+    let beta = || FlagSet::new().with("beta");
+
+    // The declared base is used unless overridden.
+    assert_that(render("/app/beta", RoutesConfig::new())).is_equal_to("<main>Err404</main>");
+    assert_that(render("/app/beta", RoutesConfig::new().with_flags(beta())))
+        .is_equal_to("<main>Beta</main>");
+    assert_that(render("/v2/beta", RoutesConfig::new().with_flags(beta()).with_base("/v2")))
+        .is_equal_to("<main>Beta</main>");
+    assert_that(render("/app/beta", RoutesConfig::new().with_base("/v2"))).is_equal_to("Err404");
+}
@@ -0,0 +1,48 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users/:id", params(id = "u32"))]
+        pub mod user {}
+
+        #[route("/tags/:tag", constraint(tag = "^[a-z]+$"))]
+        pub mod tag {}
+    }
+}
+
+use routes::{root, Route, RouteError, RouteParams};
+
+fn main() {
+    assert_that(Route::parse_path("/users/42?tab=posts")).is_equal_to(Ok((
+        Route::RootUser(root::User),
+        RouteParams::RootUser(root::UserParams { id: 42 }),
+    )));
+
+    let no_match = Route::parse_path("/teams/1").unwrap_err();
+    assert_that(no_match.clone()).is_equal_to(RouteError::NoMatch { path: "/teams/1".to_owned() });
+    assert_that(no_match.to_string()).is_equal_to(r#"no route matches "/teams/1""#.to_owned());
+
+    let invalid = Route::parse_path("/users/bob").unwrap_err();
+    assert_that(invalid.clone()).is_equal_to(RouteError::InvalidParam {
+        route: Route::RootUser(root::User),
+        name: "id",
+        value: "bob".to_owned(),
+    });
+    assert_that(invalid.to_string())
+        .is_equal_to(r#"invalid value "bob" for parameter "id" of route RootUser(User)"#.to_owned());
+
+    assert_that(Route::parse_path("/tags/Rust").unwrap_err()).is_equal_to(RouteError::InvalidParam {
+        route: Route::RootTag(root::Tag),
+        name: "tag",
+        value: "Rust".to_owned(),
+    });
+
+    // `from_path_with_params` keeps returning `None` for all errors.
+    assert_that(Route::from_path_with_params("/users/bob")).is_equal_to(None);
+}
@@ -0,0 +1,40 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+use uuid::Uuid;
+
+#[routes]
+pub mod routes {
+
+    #[route("/users/:page?")]
+    pub mod users {
+
+        #[route("/:id")]
+        pub mod user {
+
+            #[route("/details")]
+            pub mod details {}
+        }
+    }
+
+    #[route("/orgs/:org", params(org = "Uuid"))]
+    pub mod org {
+
+        #[route("/members/:member")]
+        pub mod member {}
+    }
+}
+
+fn main() {
+    let id: u64 = 42;
+    assert_that(routes::users::user::Details.materialize(None, id))
+        .is_equal_to("/users/42/details".to_string());
+    assert_that(routes::users::user::Details.materialize(Some("2"), "42"))
+        .is_equal_to("/users/2/42/details".to_string());
+    assert_that(routes::users::user::Details.materialize(None, String::from("42")))
+        .is_equal_to("/users/42/details".to_string());
+
+    let org = Uuid::nil();
+    assert_that(routes::org::Member.materialize(org, 'x'))
+        .is_equal_to(format!("/orgs/{org}/members/x"));
+}
@@ -0,0 +1,85 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/:lang?")]
+        pub mod lang {}
+
+        #[route("/:tenant?/docs/:section?/:page?")]
+        pub mod docs {
+
+            #[route("/edit")]
+            pub mod edit {}
+        }
+    }
+
+    #[route("/users/:page?")]
+    pub mod users {
+
+        #[route("/:id")]
+        pub mod user {}
+    }
+}
+
+#[routes(optional_params = "keep_trailing_slash")]
+pub mod trailing {
+
+    #[route("/users/:page?")]
+    pub mod users {
+
+        #[route("/:id/:tab?")]
+        pub mod user {}
+    }
+
+    #[route("/:lang?")]
+    pub mod lang {}
+}
+
+fn main() {
+    assert_that(routes::root::Lang.materialize(None)).is_equal_to("/".to_string());
+    assert_that(routes::root::Lang.materialize(Some("de"))).is_equal_to("/de".to_string());
+
+    let docs = routes::root::Docs;
+    assert_that(docs.materialize(None, None, None)).is_equal_to("/docs".to_string());
+    assert_that(docs.materialize(Some("acme"), None, Some("intro")))
+        .is_equal_to("/acme/docs/intro".to_string());
+    assert_that(docs.materialize(None, Some("api"), None)).is_equal_to("/docs/api".to_string());
+    assert_that(routes::root::docs::Edit.materialize(None, None, None))
+        .is_equal_to("/docs/edit".to_string());
+    assert_that(routes::root::docs::Edit.materialize(Some("acme"), None, Some("intro")))
+        .is_equal_to("/acme/docs/intro/edit".to_string());
+
+    assert_that(routes::users::User.materialize(None, "42")).is_equal_to("/users/42".to_string());
+
+    assert_that(trailing::Users.materialize(None)).is_equal_to("/users/".to_string());
+    assert_that(trailing::Users.materialize(Some("2"))).is_equal_to("/users/2".to_string());
+    assert_that(trailing::users::User.materialize(None, "42", None))
+        .is_equal_to("/users/42/".to_string());
+    assert_that(trailing::users::User.materialize(None, "42", Some("posts")))
+        .is_equal_to("/users/42/posts".to_string());
+    assert_that(trailing::Lang.materialize(None)).is_equal_to("/".to_string());
+
+    // Writing paths produces the same paths as materializing them.
+    let written = |write: &dyn Fn(&mut String) -> std::fmt::Result| {
+        let mut path = String::new();
+        write(&mut path).unwrap();
+        path
+    };
+    assert_that(written(&|out| docs.materialize_into(out, None, None, None)))
+        .is_equal_to("/docs".to_string());
+    let edit = routes::root::docs::Edit;
+    assert_that(written(&|out| edit.materialize_into(out, Some("acme"), None, Some("intro"))))
+        .is_equal_to("/acme/docs/intro/edit".to_string());
+    assert_that(written(&|out| trailing::Users.materialize_into(out, None)))
+        .is_equal_to("/users/".to_string());
+    assert_that(written(&|out| trailing::users::User.materialize_into(out, None, "42", None)))
+        .is_equal_to("/users/42/".to_string());
+    assert_that(written(&|out| trailing::Lang.materialize_into(out, None)))
+        .is_equal_to("/".to_string());
+}
@@ -0,0 +1,56 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::matching::normalize_path;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users")]
+        pub mod users {
+
+            #[route("/:name")]
+            pub mod user {}
+        }
+    }
+}
+
+#[routes(optional_params = "keep_trailing_slash")]
+pub mod trailing {
+
+    #[route("/users/:page?")]
+    pub mod users {}
+}
+
+fn main() {
+    use routes::{Route, RouteParams};
+
+    assert_that(Route::normalize("//users///caf%C3%A9/")).is_equal_to("/users/café".to_string());
+    assert_that(Route::normalize("")).is_equal_to("/".to_string());
+    assert_that(Route::normalize("///")).is_equal_to("/".to_string());
+    assert_that(Route::normalize("/users/?q=a%20b#top"))
+        .is_equal_to("/users?q=a%20b#top".to_string());
+    assert_that(Route::normalize("/files/a%2Fb")).is_equal_to("/files/a%2Fb".to_string());
+    assert_that(Route::normalize("/files/a%3Fb%23c")).is_equal_to("/files/a%3Fb%23c".to_string());
+    assert_that(Route::normalize("/broken/%zz%4")).is_equal_to("/broken/%zz%4".to_string());
+    assert_that(Route::normalize("/signed/%+1%-1")).is_equal_to("/signed/%+1%-1".to_string());
+    assert_that(Route::normalize("/invalid/%FF")).is_equal_to("/invalid/%FF".to_string());
+    assert_that(trailing::Route::normalize("/users//")).is_equal_to("/users/".to_string());
+    assert_that(normalize_path("/users/%41", false)).is_equal_to("/users/A".to_string());
+
+    assert_that(Route::from_path("//users//"))
+        .is_equal_to(Some(Route::RootUsers(routes::root::Users)));
+    assert_that(Route::from_path("/users/J%C3%BCrgen/"))
+        .is_equal_to(Some(Route::RootUsersUser(routes::root::users::User)));
+    let (_, params) = Route::from_path_with_params("/users/a%3Fb").unwrap();
+    assert_that(params).is_equal_to(RouteParams::RootUsersUser(routes::root::users::UserParams {
+        name: "a%3Fb".to_string(),
+    }));
+    let (_, params) = Route::from_path_with_params("/users//J%C3%BCrgen").unwrap();
+    assert_that(params).is_equal_to(RouteParams::RootUsersUser(routes::root::users::UserParams {
+        name: "Jürgen".to_string(),
+    }));
+}
@@ -0,0 +1,47 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes(case_insensitive)]
+pub mod routes {
+
+    #[route("/Summer-Sale")]
+    pub mod sale {
+
+        #[route("/:code")]
+        pub mod code {}
+    }
+
+    #[route("/users/:name", constraint(name = "[a-zA-Z]+"))]
+    pub mod user {}
+}
+
+#[routes]
+pub mod sensitive {
+
+    #[route("/users")]
+    pub mod users {}
+}
+
+fn main() {
+    use routes::{Route, RouteParams};
+
+    assert_that(routes::Sale.materialize()).is_equal_to("/summer-sale".to_string());
+    assert_that(routes::Sale::MATERIALIZED_PATH).is_equal_to("/summer-sale");
+    assert_that(routes::sale::Code.materialize("XMAS")).is_equal_to("/summer-sale/XMAS".to_string());
+    let mut written = String::new();
+    routes::sale::Code.materialize_into(&mut written, "XMAS").unwrap();
+    assert_that(written).is_equal_to("/summer-sale/XMAS".to_string());
+
+    assert_that(routes::Sale.matches("/SUMMER-sale")).is_equal_to(true);
+    assert_that(routes::User.matches("/Users/Alice")).is_equal_to(true);
+    assert_that(routes::User.matches("/Users/42")).is_equal_to(false);
+    assert_that(Route::from_path("/summer-SALE/XMAS"))
+        .is_equal_to(Some(Route::SaleCode(routes::sale::Code)));
+    assert_that(Route::from_path_with_params("/USERS/Alice").map(|(_, params)| params)).is_equal_to(
+        Some(RouteParams::User(routes::UserParams { name: "Alice".to_string() })),
+    );
+
+    assert_that(sensitive::Users.matches("/Users")).is_equal_to(false);
+    assert_that(sensitive::Route::from_path("/USERS")).is_equal_to(None);
+}
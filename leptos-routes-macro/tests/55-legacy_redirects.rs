@@ -0,0 +1,31 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/members", previously = "/old/users", previously = "/people")]
+        pub mod members {
+
+            #[route("/:id", previously = "/old/users/:id")]
+            pub mod member {}
+        }
+
+        #[route("/about")]
+        pub mod about {}
+    }
+}
+
+fn main() {
+    use routes::Route;
+
+    assert_that(routes::legacy_redirects()).is_equal_to(vec![
+        ("/old/users".to_string(), Route::RootMembers(routes::root::Members)),
+        ("/people".to_string(), Route::RootMembers(routes::root::Members)),
+        ("/old/users/:id".to_string(), Route::RootMembersMember(routes::root::members::Member)),
+    ]);
+}
@@ -0,0 +1,87 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route(
+            "/docs",
+            layout = "MainLayout",
+            fallback = "|path| view! { <SectionNotFound path/> }"
+        )]
+        pub mod docs {
+
+            #[route("/intro", view = "Intro")]
+            pub mod intro {}
+        }
+
+        #[route(
+            "/blog",
+            layout = "MainLayout",
+            index = "Posts",
+            fallback = "|path: String| view! { <SectionNotFound path/> }"
+        )]
+        pub mod blog {
+
+            #[route("/:slug", view = "Post")]
+            pub mod post {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Intro() -> impl IntoView { view! { "Intro" } }
+#[component]
+fn Posts() -> impl IntoView { view! { "Posts" } }
+#[component]
+fn Post() -> impl IntoView { view! { "Post" } }
+#[component]
+fn SectionNotFound(path: String) -> impl IntoView { format!("Not found: {path}") }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/docs/intro"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><div>Intro</div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/docs/missing/page"));
+    assert_that(app().to_html())
+        .is_equal_to(r#"<div><div>Not found: missing/page</div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/docs"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><div>Not found: </div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/blog"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><div>Posts</div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/blog/hello"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><div>Post</div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/blog/hello/comments"));
+    assert_that(app().to_html())
+        .is_equal_to(r#"<div><div>Not found: hello/comments</div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/elsewhere"));
+    assert_that(app().to_html()).is_equal_to(r#"Err404"#);
+}
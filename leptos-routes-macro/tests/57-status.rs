@@ -0,0 +1,36 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+use leptos_routes::status::StatusCode;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/summer-sale", status = 410)]
+        pub mod summer_sale {}
+
+        #[route("/beta", status = 503, index = "Beta")]
+        pub mod beta {
+
+            #[route("/signup")]
+            pub mod signup {}
+        }
+
+        #[route("/about")]
+        pub mod about {}
+    }
+}
+
+fn main() {
+    use routes::{status_for, Route};
+
+    assert_that(status_for(&Route::from_path("/summer-sale").unwrap()))
+        .is_equal_to(Some(StatusCode::GONE));
+    assert_that(status_for(&Route::from_path("/beta").unwrap()))
+        .is_equal_to(Some(StatusCode::SERVICE_UNAVAILABLE));
+    assert_that(status_for(&Route::from_path("/beta/signup").unwrap())).is_equal_to(None);
+    assert_that(status_for(&Route::from_path("/about").unwrap())).is_equal_to(None);
+}
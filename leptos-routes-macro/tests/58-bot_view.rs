@@ -0,0 +1,67 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::bot::IsBot;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/products", view = "Products", bot_view = "StaticSnapshot")]
+        pub mod products {}
+
+        #[route("/about", view = "About")]
+        pub mod about {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Products() -> impl IntoView { view! { "Products" } }
+#[component]
+fn StaticSnapshot() -> impl IntoView { view! { "StaticSnapshot" } }
+#[component]
+fn About() -> impl IntoView { view! { "About" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    assert_that(IsBot::from_user_agent(
+        "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+    ))
+    .is_equal_to(IsBot(true));
+    assert_that(IsBot::from_user_agent(
+        "Mozilla/5.0 (X11; Linux x86_64; rv:128.0) Gecko/20100101 Firefox/128.0",
+    ))
+    .is_equal_to(IsBot(false));
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/products"));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Products</div>"#);
+
+    provide_context(IsBot(false));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Products</div>"#);
+
+    provide_context(IsBot(true));
+    assert_that(app().to_html()).is_equal_to(r#"<div>StaticSnapshot</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/about"));
+    assert_that(app().to_html()).is_equal_to(r#"<div>About</div>"#);
+}
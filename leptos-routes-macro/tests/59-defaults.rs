@@ -0,0 +1,36 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/articles/:page?", defaults(page = "1"), constraint(page = r"\d+"), params(page = "u32"))]
+        pub mod articles {}
+
+        #[route("/search/:sort?", defaults(sort = "relevance"))]
+        pub mod search {}
+    }
+}
+
+fn main() {
+    use routes::{Route, RouteParams};
+
+    assert_that(routes::root::Articles.materialize(None)).is_equal_to("/articles".to_string());
+    assert_that(routes::root::Articles.materialize(Some(3))).is_equal_to("/articles/3".to_string());
+
+    assert_that(Route::from_path_with_params("/articles")).is_equal_to(Some((
+        Route::RootArticles(routes::root::Articles),
+        RouteParams::RootArticles(routes::root::ArticlesParams { page: 1 }),
+    )));
+    assert_that(Route::from_path_with_params("/articles/7")).is_equal_to(Some((
+        Route::RootArticles(routes::root::Articles),
+        RouteParams::RootArticles(routes::root::ArticlesParams { page: 7 }),
+    )));
+
+    let params = routes::root::SearchParams::from_params(|_| None).unwrap();
+    assert_that(params.sort).is_equal_to("relevance".to_string());
+}
@@ -0,0 +1,46 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users")]
+        pub mod users {
+
+            #[route("/:id")]
+            pub mod user {
+
+                #[route("/details")]
+                pub mod details {}
+
+                #[route("/edit")]
+                pub mod edit {}
+            }
+        }
+
+        #[route("/settings")]
+        pub mod settings {}
+    }
+}
+
+fn main() {
+    use routes::root::users::user::{Details, Edit};
+    use routes::root::users::User;
+    use routes::Route;
+
+    assert_that(Details.materialize_relative_to(&Route::from(Edit), "42"))
+        .is_equal_to("../details".to_string());
+    assert_that(Details.materialize_relative_to(&Route::from(User), "42"))
+        .is_equal_to("details".to_string());
+    assert_that(User.materialize_relative_to(&Route::from(Details), "42"))
+        .is_equal_to("..".to_string());
+    assert_that(Details.materialize_relative_to(&Route::from(Details), "42"))
+        .is_equal_to(".".to_string());
+    assert_that(Details.materialize_relative_to(&Route::from(routes::root::Settings), "42"))
+        .is_equal_to("../users/42/details".to_string());
+    assert_that(Route::from(Details).full_path()).is_equal_to("/users/:id/details");
+}
@@ -0,0 +1,43 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/signup", ordered, index = "Welcome")]
+        pub mod signup {
+
+            #[route("/account")]
+            pub mod account {}
+
+            #[route("/profile")]
+            pub mod profile {}
+
+            #[route("/confirm")]
+            pub mod confirm {}
+        }
+
+        #[route("/about")]
+        pub mod about {}
+    }
+}
+
+fn main() {
+    use routes::root::signup::{Account, Confirm, Profile};
+    use routes::Route;
+
+    assert_that(Account.prev_sibling()).is_equal_to(None);
+    assert_that(Account.next_sibling()).is_equal_to(Some(Route::RootSignupProfile(Profile)));
+    assert_that(Profile.prev_sibling()).is_equal_to(Some(Route::RootSignupAccount(Account)));
+    assert_that(Profile.next_sibling()).is_equal_to(Some(Route::RootSignupConfirm(Confirm)));
+    assert_that(Confirm.next_sibling()).is_equal_to(None);
+
+    assert_that(Route::from(Profile).next_sibling()).is_equal_to(Some(Route::from(Confirm)));
+    assert_that(Route::from(Profile).prev_sibling()).is_equal_to(Some(Route::from(Account)));
+    assert_that(Route::from(routes::root::About).next_sibling()).is_equal_to(None);
+    assert_that(Route::from(routes::root::signup::Index).prev_sibling()).is_equal_to(None);
+}
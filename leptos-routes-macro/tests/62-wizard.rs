@@ -0,0 +1,87 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/signup/:plan", layout = "SignupLayout")]
+        pub mod signup {
+
+            #[route("/profile", view = "Profile", step = 2)]
+            pub mod profile {}
+
+            #[route("/account", view = "Account", step = 1)]
+            pub mod account {}
+
+            #[route("/confirm", view = "Confirm", step = 3)]
+            pub mod confirm {}
+
+            #[route("/done", view = "Done")]
+            pub mod done {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn SignupLayout() -> impl IntoView {
+    let wizard = routes::root::Signup.use_wizard();
+    let progress = move || format!("{:.2}", wizard.progress());
+    view! { <p>{progress}</p> <Outlet/> }
+}
+#[component]
+fn Account() -> impl IntoView { view! { "Account" } }
+#[component]
+fn Profile() -> impl IntoView { view! { "Profile" } }
+#[component]
+fn Confirm() -> impl IntoView { view! { "Confirm" } }
+#[component]
+fn Done() -> impl IntoView { view! { "Done" } }
+
+fn main() {
+    use routes::root::signup::{Account, Confirm, Profile, WizardStep};
+
+    assert_that(WizardStep::ALL).is_equal_to([
+        WizardStep::Account(Account),
+        WizardStep::Profile(Profile),
+        WizardStep::Confirm(Confirm),
+    ]);
+    assert_that(WizardStep::from_path("/signup/pro/profile")).is_equal_to(Some(WizardStep::Profile(Profile)));
+    assert_that(WizardStep::from_path("/signup/pro/done")).is_equal_to(None);
+    assert_that(WizardStep::Profile(Profile).number()).is_equal_to(2);
+    assert_that(WizardStep::Profile(Profile).next()).is_equal_to(Some(WizardStep::Confirm(Confirm)));
+    assert_that(WizardStep::Profile(Profile).prev()).is_equal_to(Some(WizardStep::Account(Account)));
+    assert_that(WizardStep::Account(Account).prev()).is_equal_to(None);
+    assert_that(WizardStep::Confirm(Confirm).next()).is_equal_to(None);
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/signup/pro/account"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><p>0.33</p>Account</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/signup/pro/confirm"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><p>1.00</p>Confirm</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/signup/pro/done"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><p>0.00</p>Done</div>"#);
+}
@@ -0,0 +1,69 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/photos", layout = "Outlet", index = "Gallery")]
+        pub mod photos {
+
+            #[route("/favorites", view = "Favorites")]
+            pub mod favorites {}
+
+            #[route("/:id", view = "Preview", modal)]
+            pub mod photo {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Gallery() -> impl IntoView { view! { "Gallery" } }
+#[component]
+fn Favorites() -> impl IntoView { view! { "Favorites" } }
+#[component]
+fn Preview() -> impl IntoView { view! { "Preview" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+    // Shared across the routers rendered below, like one router navigating between paths.
+    provide_context(routes::ModalBackground::new());
+
+    // Loaded directly, the modal is rendered on its own.
+    provide_context::<RequestUrl>(RequestUrl::new("/photos/7"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><!><div role="dialog" aria-modal="true">Preview</div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/photos/favorites"));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Favorites</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/photos/7"));
+    assert_that(app().to_html())
+        .is_equal_to(r#"<div>Favorites<div role="dialog" aria-modal="true">Preview</div></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/photos"));
+    assert_that(app().to_html()).is_equal_to(r#"<div>Gallery</div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/photos/7"));
+    assert_that(app().to_html())
+        .is_equal_to(r#"<div>Gallery<div role="dialog" aria-modal="true">Preview</div></div>"#);
+}
@@ -0,0 +1,57 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::hooks::use_params_map;
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/mail/:folder/:message?", panes(list = "MessageList", detail = "MessageView"))]
+        pub mod mail {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn MessageList() -> impl IntoView {
+    let params = use_params_map();
+    move || params.with(|params| format!("List of {}", params.get("folder").unwrap_or_default()))
+}
+#[component]
+fn MessageView() -> impl IntoView {
+    let params = use_params_map();
+    move || params.with(|params| format!("Message {}", params.get("message").unwrap_or_default()))
+}
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/mail/inbox/42"));
+    assert_that(app().to_html()).is_equal_to(
+        r#"<div><div data-pane="list">List of inbox</div><div data-pane="detail">Message 42</div></div>"#,
+    );
+
+    provide_context::<RequestUrl>(RequestUrl::new("/mail/inbox"));
+    assert_that(app().to_html()).is_equal_to(
+        r#"<div><div data-pane="list">List of inbox</div><div data-pane="detail"><!></div></div>"#,
+    );
+}
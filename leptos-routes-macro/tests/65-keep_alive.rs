@@ -0,0 +1,70 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::hooks::use_params_map;
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/docs", layout = "Outlet", index = "DocList")]
+        pub mod docs {
+
+            #[route("/:id/edit", view = "Editor", keep_alive)]
+            pub mod edit {}
+
+            #[route("/:id/preview", view = "Preview")]
+            pub mod preview {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn DocList() -> impl IntoView { view! { "Docs" } }
+#[component]
+fn Editor() -> impl IntoView {
+    let params = use_params_map();
+    move || params.with(|params| format!("Editing {}", params.get("id").unwrap_or_default()))
+}
+#[component]
+fn Preview() -> impl IntoView {
+    let params = use_params_map();
+    move || params.with(|params| format!("Preview of {}", params.get("id").unwrap_or_default()))
+}
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/docs/42/edit"));
+    assert_that(app().to_html()).is_equal_to(
+        r#"<div><div style="display:contents;">Editing 42</div><!><!><!></div>"#,
+    );
+
+    provide_context::<RequestUrl>(RequestUrl::new("/docs/42/preview"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><!>Preview of 42<!><!></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/docs"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><!><!>Docs<!></div>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/docs/42/unknown"));
+    assert_that(app().to_html()).is_equal_to(r#"<div><!><!><!>Err404</div>"#);
+}
@@ -0,0 +1,34 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/billing", owner = "team-payments")]
+        pub mod billing {
+
+            #[route("/invoices")]
+            pub mod invoices {}
+
+            #[route("/disputes", owner = "team-risk")]
+            pub mod disputes {}
+        }
+
+        #[route("/about")]
+        pub mod about {}
+    }
+}
+
+fn main() {
+    use routes::Route;
+
+    assert_that(routes::owners()).is_equal_to(vec![
+        (Route::RootBilling(routes::root::Billing), "team-payments"),
+        (Route::RootBillingInvoices(routes::root::billing::Invoices), "team-payments"),
+        (Route::RootBillingDisputes(routes::root::billing::Disputes), "team-risk"),
+    ]);
+}
@@ -0,0 +1,54 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/admin", layout = "Outlet", index = "Dashboard", class = "theme-admin")]
+        pub mod admin {
+
+            #[route("/audit", view = "Audit", class = "dense")]
+            pub mod audit {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Audit() -> impl IntoView { view! { "Audit" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/admin"));
+    assert_that(app().to_html()).is_equal_to(r#"<main><div class="theme-admin">Dashboard</div></main>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/admin/audit"));
+    assert_that(app().to_html())
+        .is_equal_to(r#"<main><div class="theme-admin"><div class="dense">Audit</div></div></main>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/"));
+    assert_that(app().to_html()).is_equal_to(r#"<main>Home</main>"#);
+}
@@ -0,0 +1,69 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::hooks::use_params_map;
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }", preview_prefix = "/preview")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/users", layout = "UsersLayout", preview = "NewUsersLayout", index = "UserList", previewable)]
+        pub mod users {
+
+            #[route("/:id", view = "User", preview = "NewUser")]
+            pub mod user {}
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <section> <Outlet/> </section> } }
+#[component]
+fn NewUsersLayout() -> impl IntoView { view! { <article> <Outlet/> </article> } }
+#[component]
+fn UserList() -> impl IntoView { view! { "Users" } }
+#[component]
+fn User() -> impl IntoView {
+    let params = use_params_map();
+    move || params.with(|params| format!("User {}", params.get("id").unwrap_or_default()))
+}
+#[component]
+fn NewUser() -> impl IntoView {
+    let params = use_params_map();
+    move || params.with(|params| format!("New user {}", params.get("id").unwrap_or_default()))
+}
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/users/42"));
+    assert_that(app().to_html()).is_equal_to(r#"<main><section>User 42</section></main>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/preview/users/42"));
+    assert_that(app().to_html()).is_equal_to(r#"<article>New user 42</article>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/preview/users"));
+    assert_that(app().to_html()).is_equal_to(r#"<article>Users</article>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/preview"));
+    assert_that(app().to_html()).is_equal_to(r#"Err404"#);
+}
@@ -0,0 +1,32 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::external_routes;
+
+#[external_routes]
+pub mod external {
+
+    #[url("https://docs.example.com/:page")]
+    pub mod docs {}
+
+    #[url("https://github.com/:owner/:repo/issues/:number?")]
+    pub mod issues {}
+
+    pub mod status {
+
+        #[url("https://status.example.com/")]
+        pub mod home {}
+    }
+}
+
+fn main() {
+    assert_that(external::Docs.materialize("intro")).is_equal_to("https://docs.example.com/intro".to_string());
+    assert_that(external::Docs.origin()).is_equal_to("https://docs.example.com");
+    assert_that(external::Docs::TEMPLATE).is_equal_to("https://docs.example.com/:page");
+
+    assert_that(external::Issues.materialize("leptos-rs", "leptos", Some("42")))
+        .is_equal_to("https://github.com/leptos-rs/leptos/issues/42".to_string());
+    assert_that(external::Issues.materialize("leptos-rs", "leptos", None))
+        .is_equal_to("https://github.com/leptos-rs/leptos/issues".to_string());
+
+    assert_that(external::status::Home.materialize()).is_equal_to("https://status.example.com/".to_string());
+}
@@ -0,0 +1,68 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    // Expands into the "/users" index and the "/users/new", "/users/:id" and "/users/:id/edit"
+    // child routes.
+    #[route(
+        "/users",
+        layout = "Outlet",
+        crud(param = "id", views(index = "UserList", show = "User", new = "NewUser", edit = "EditUser"))
+    )]
+    pub mod users {
+
+        #[route("/:id/avatar", view = "Avatar")]
+        pub mod avatar {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn UserList() -> impl IntoView { view! { "UserList" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn NewUser() -> impl IntoView { view! { "NewUser" } }
+#[component]
+fn EditUser() -> impl IntoView { view! { "EditUser" } }
+#[component]
+fn Avatar() -> impl IntoView { view! { "Avatar" } }
+
+fn main() {
+    use routes::*;
+
+    assert_that(users::Index.materialize()).is_equal_to("/users".to_string());
+    assert_that(Route::from_path("/users/new")).is_equal_to(Some(Route::UsersNew(users::New)));
+    assert_that(Route::from_path("/users/42")).is_equal_to(Some(Route::UsersShow(users::Show)));
+    assert_that(Route::from_path("/users/42/edit")).is_equal_to(Some(Route::UsersEdit(users::Edit)));
+    assert_that(users::Show.materialize("42")).is_equal_to("/users/42".to_string());
+    assert_that(users::Edit.materialize("42")).is_equal_to("/users/42/edit".to_string());
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    for (path, html) in [
+        ("/users", "UserList"),
+        ("/users/new", "NewUser"),
+        ("/users/42", "User"),
+        ("/users/42/edit", "EditUser"),
+        ("/users/42/avatar", "Avatar"),
+    ] {
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(html.to_string());
+    }
+}
@@ -0,0 +1,28 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes(api_prefix = "/api")]
+pub mod routes {
+
+    #[route("/orgs/:org")]
+    pub mod org {
+
+        #[route("/projects", crud(param = "project"))]
+        pub mod projects {}
+
+        #[route("/settings")]
+        pub mod settings {}
+    }
+}
+
+fn main() {
+    use routes::*;
+
+    assert_that(resource_endpoints()).is_equal_to(vec![
+        (Route::OrgProjects(org::Projects), "/api/orgs/:org/projects"),
+        (Route::OrgProjectsNew(org::projects::New), "/api/orgs/:org/projects"),
+        (Route::OrgProjectsShow(org::projects::Show), "/api/orgs/:org/projects/:project"),
+        (Route::OrgProjectsEdit(org::projects::Edit), "/api/orgs/:org/projects/:project"),
+    ]);
+}
@@ -0,0 +1,41 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/welcome")]
+        pub mod welcome {}
+
+        // Child routes inherit the section of their parent, unless declaring their own.
+        #[route("/admin", section = "admin")]
+        pub mod admin {
+
+            #[route("/users/:id")]
+            pub mod user {}
+
+            #[route("/billing", section = "billing_admin")]
+            pub mod billing {}
+        }
+    }
+}
+
+fn main() {
+    use routes::*;
+
+    assert_that(Route::RootWelcome(root::Welcome).section()).is_equal_to(None);
+    assert_that(Route::RootAdmin(root::Admin).section()).is_equal_to(Some(Section::Admin));
+    assert_that(Route::RootAdminUser(root::admin::User).section()).is_equal_to(Some(Section::Admin));
+    assert_that(Route::RootAdminBilling(root::admin::Billing).section())
+        .is_equal_to(Some(Section::BillingAdmin));
+    assert_that(Section::BillingAdmin.as_str()).is_equal_to("billing_admin");
+
+    assert_that(Route::Root(routes::Root).is_leaf()).is_equal_to(false);
+    assert_that(Route::RootWelcome(root::Welcome).is_leaf()).is_equal_to(true);
+    assert_that(Route::RootAdmin(root::Admin).has_params()).is_equal_to(false);
+    assert_that(Route::RootAdminUser(root::admin::User).has_params()).is_equal_to(true);
+}
@@ -0,0 +1,86 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/account", view = "Account")]
+        pub mod account {
+
+            // Both "/settings" routes are registered once, with the layout declared on either.
+            #[route("!/settings", merge, layout = "SettingsLayout", fallback = "Settings")]
+            pub mod settings {
+
+                #[route("/profile", view = "Profile")]
+                pub mod profile {}
+            }
+        }
+
+        #[route("/security", view = "Security")]
+        pub mod security {
+
+            #[route("!/settings", merge)]
+            pub mod settings {
+
+                #[route("/security", view = "SecuritySettings")]
+                pub mod security {}
+            }
+        }
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Account() -> impl IntoView { view! { "Account" } }
+#[component]
+fn Security() -> impl IntoView { view! { "Security" } }
+#[component]
+fn SettingsLayout() -> impl IntoView { view! { <div> <Outlet/> </div> } }
+#[component]
+fn Settings() -> impl IntoView { view! { "Settings" } }
+#[component]
+fn Profile() -> impl IntoView { view! { "Profile" } }
+#[component]
+fn SecuritySettings() -> impl IntoView { view! { "SecuritySettings" } }
+
+fn main() {
+    assert_that(routes::root::account::settings::Profile.materialize())
+        .is_equal_to("/settings/profile".to_string());
+    assert_that(routes::root::security::settings::Security.materialize())
+        .is_equal_to("/settings/security".to_string());
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    // The children of both routes are rendered inside the one shared layout.
+    for (path, expected) in [
+        ("/settings/profile", "<div>Profile</div>"),
+        ("/settings/security", "<div>SecuritySettings</div>"),
+        ("/settings", "<div>Settings</div>"),
+        ("/security", "<main>Security</main>"),
+    ] {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(expected.to_string());
+    }
+
+    // Each merged route still provides the definitions of its own child routes.
+    let _ = routes::root::security::Settings.into_routes();
+}
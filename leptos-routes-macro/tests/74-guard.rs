@@ -0,0 +1,79 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos::task::{any_spawner, CustomExecutor, Executor};
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/account", view = "Account", guard = "crate::is_signed_in", guard_redirect = "/login", guard_fallback = "Spinner")]
+        pub mod account {}
+
+        // A guard on a route with children spans all of its child routes.
+        #[route("/admin", layout = "Outlet", guard = "crate::is_admin", guard_redirect = "/")]
+        pub mod admin {
+
+            #[route("/users", view = "Users")]
+            pub mod users {}
+        }
+    }
+}
+
+async fn is_signed_in() -> bool {
+    true
+}
+
+async fn is_admin() -> bool {
+    false
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Spinner() -> impl IntoView { view! { "Loading" } }
+#[component]
+fn Account() -> impl IntoView { view! { "Account" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+
+/// Never runs spawned futures, keeping the guards pending.
+struct Pending;
+
+impl CustomExecutor for Pending {
+    fn spawn(&self, _fut: any_spawner::PinnedFuture<()>) {}
+    fn spawn_local(&self, _fut: any_spawner::PinnedLocalFuture<()>) {}
+    fn poll_local(&self) {}
+}
+
+fn main() {
+    Executor::init_custom_executor(Pending).unwrap();
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    // Pending guards render their "guard_fallback", or nothing.
+    for (path, expected) in [
+        ("/", "<main>Home</main>"),
+        ("/account", "<main>Loading</main>"),
+        ("/admin/users", "<main><!></main>"),
+    ] {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(expected.to_string());
+    }
+}
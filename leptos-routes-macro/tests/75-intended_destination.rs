@@ -0,0 +1,72 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos::task::{any_spawner, CustomExecutor, Executor};
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/login", view = "Login")]
+        pub mod login {}
+
+        #[route("/account", view = "Account", guard = "crate::is_signed_in", guard_redirect = "/login")]
+        pub mod account {}
+    }
+}
+
+async fn is_signed_in() -> bool {
+    false
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Account() -> impl IntoView { view! { "Account" } }
+#[component]
+fn Login() -> impl IntoView {
+    routes::intended_destination().unwrap_or_else(|| "none".to_string())
+}
+
+/// Never runs spawned futures, keeping the guards pending.
+struct Pending;
+
+impl CustomExecutor for Pending {
+    fn spawn(&self, _fut: any_spawner::PinnedFuture<()>) {}
+    fn spawn_local(&self, _fut: any_spawner::PinnedLocalFuture<()>) {}
+    fn poll_local(&self) {}
+}
+
+fn main() {
+    Executor::init_custom_executor(Pending).unwrap();
+
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    // Only paths of this app matched by a route are resumed.
+    for (path, expected) in [
+        ("/login?next=%2Faccount%3Ftab%3Dbilling", "<main>/account?tab=billing</main>"),
+        ("/login?next=%2Funknown", "<main>none</main>"),
+        ("/login?next=%2F%2Fexample.com%2Faccount", "<main>none</main>"),
+        ("/login?next=https%3A%2F%2Fexample.com", "<main>none</main>"),
+        ("/login", "<main>none</main>"),
+    ] {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(expected.to_string());
+    }
+}
@@ -0,0 +1,48 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/dashboard", title = "Dashboard")]
+        pub mod dashboard {}
+
+        // Child routes inherit the roles of their parent, unless declaring their own.
+        #[route("/admin", title = "Admin", roles("admin"))]
+        pub mod admin {
+
+            #[route("/users", title = "Users")]
+            pub mod users {}
+
+            #[route("/reports", title = "Reports", roles("admin", "analyst"))]
+            pub mod reports {}
+
+            #[route("/users/:id", title = "User")]
+            pub mod user {}
+        }
+    }
+}
+
+fn titles(items: Vec<routes::NavItem>) -> Vec<&'static str> {
+    items.into_iter().map(|item| item.title).collect()
+}
+
+fn main() {
+    use routes::*;
+
+    assert_that(Route::RootDashboard(root::Dashboard).roles()).is_equal_to(&[] as &[&str]);
+    assert_that(Route::RootAdminUsers(root::admin::Users).roles()).is_equal_to(&["admin"] as &[&str]);
+    assert_that(Route::RootAdminReports(root::admin::Reports).permits(&["analyst"])).is_equal_to(true);
+    assert_that(Route::RootAdminUsers(root::admin::Users).permits(&["analyst"])).is_equal_to(false);
+
+    // Routes with required parameters are not listed.
+    assert_that(titles(nav_items())).is_equal_to(vec!["Dashboard", "Admin", "Users", "Reports"]);
+    assert_that(titles(nav_items_for(&["admin"]))).is_equal_to(vec!["Dashboard", "Admin", "Users", "Reports"]);
+    assert_that(titles(nav_items_for(&["analyst"]))).is_equal_to(vec!["Dashboard", "Reports"]);
+    assert_that(titles(nav_items_for(&[]))).is_equal_to(vec!["Dashboard"]);
+    assert_that((nav_items()[0].href)()).is_equal_to("/dashboard".to_string());
+}
@@ -0,0 +1,32 @@
+// Every leaf route declares an access decision, so that the audit warns about none of them.
+#![deny(deprecated)]
+
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes(audit_access)]
+pub mod routes {
+
+    #[route("/", public)]
+    pub mod root {
+
+        #[route("/about")]
+        pub mod about {}
+
+        // Declaring roles below a public route restricts it again.
+        #[route("/admin", roles("admin"))]
+        pub mod admin {
+
+            #[route("/users")]
+            pub mod users {}
+        }
+    }
+}
+
+fn main() {
+    use routes::*;
+
+    assert_that(Route::RootAbout(root::About).roles()).is_equal_to(&[] as &[&str]);
+    assert_that(Route::RootAdminUsers(root::admin::Users).permits(&[])).is_equal_to(false);
+}
@@ -0,0 +1,47 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/legal/Terms")]
+        pub mod terms {}
+
+        #[route(group)]
+        pub mod account {
+
+            #[route("/settings")]
+            pub mod settings {}
+        }
+
+        #[route("/users/:id")]
+        pub mod user {}
+    }
+}
+
+// Fully static routes can be materialized in const contexts.
+const FOOTER_LINKS: [&str; 2] = [
+    routes::Root.materialize_const(),
+    routes::root::Terms::MATERIALIZED_PATH,
+];
+
+fn describe(path: &str) -> &'static str {
+    match path {
+        routes::root::account::Settings::MATERIALIZED_PATH => "settings",
+        _ => "other",
+    }
+}
+
+fn main() {
+    assert_that(FOOTER_LINKS).is_equal_to(["/", "/legal/Terms"]);
+    assert_that(routes::root::Terms.materialize_const().to_string())
+        .is_equal_to(routes::root::Terms.materialize());
+    assert_that(routes::root::account::Settings.materialize_const().to_string())
+        .is_equal_to(routes::root::account::Settings.materialize());
+    assert_that(routes::root::Account.materialize_const()).is_equal_to("/");
+    assert_that(describe("/settings")).is_equal_to("settings");
+}
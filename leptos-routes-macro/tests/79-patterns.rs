@@ -0,0 +1,43 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route(group)]
+        pub mod account {
+
+            #[route("/settings")]
+            pub mod settings {}
+        }
+
+        #[route("/users/:id")]
+        pub mod user {
+
+            #[route("/details")]
+            pub mod details {}
+        }
+    }
+}
+
+fn main() {
+    // Routes sharing a full path, like groups and their parent, share one entry of the table.
+    assert_that(routes::__patterns::PATTERNS).is_equal_to([
+        "/",
+        "/settings",
+        "/users/:id",
+        "/users/:id/details",
+    ]);
+
+    assert_that(routes::Root::PATTERN).is_equal_to("/");
+    assert_that(routes::root::Account::PATTERN).is_equal_to("/");
+    assert_that(routes::root::user::Details::PATTERN).is_equal_to("/users/:id/details");
+    assert_that(routes::Route::from(routes::root::user::Details).full_path())
+        .is_equal_to("/users/:id/details");
+    assert_that(routes::root::user::Details.materialize_relative_to(&routes::root::User.into(), "42"))
+        .is_equal_to("details");
+}
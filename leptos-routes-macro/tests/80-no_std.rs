@@ -0,0 +1,91 @@
+#![no_std]
+
+extern crate alloc;
+// Only linked for the panic handler and entry point of this test binary.
+extern crate std;
+
+use alloc::string::String;
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use core::fmt;
+use leptos_routes::routes;
+
+#[routes(no_std, deny_unused)]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users/:id", params(id = "u32"))]
+        pub mod user {
+
+            #[route("/files/*path")]
+            pub mod files {}
+        }
+
+        #[route("/archive/:year?")]
+        pub mod archive {}
+
+        #[route("/:lang?")]
+        pub mod lang {}
+    }
+}
+
+/// A fixed-size buffer, as used in environments without an allocator at hand.
+struct Buf {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buf {
+    fn new() -> Self {
+        Self { bytes: [0; 64], len: 0 }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl fmt::Write for Buf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn materialized_into(write: impl FnOnce(&mut Buf) -> fmt::Result) -> String {
+    let mut buf = Buf::new();
+    write(&mut buf).unwrap();
+    String::from(buf.as_str())
+}
+
+fn main() {
+    assert_that(materialized_into(|buf| routes::Root.materialize_into(buf)))
+        .is_equal_to(routes::Root.materialize());
+    assert_that(materialized_into(|buf| routes::root::User.materialize_into(buf, 42)))
+        .is_equal_to(routes::root::User.materialize(42));
+    assert_that(materialized_into(|buf| {
+        routes::root::user::Files.materialize_into(buf, 42, "docs/a.txt")
+    }))
+    .is_equal_to("/users/42/files/docs/a.txt");
+    assert_that(materialized_into(|buf| routes::root::Archive.materialize_into(buf, Some("2024"))))
+        .is_equal_to(routes::root::Archive.materialize(Some("2024")));
+    assert_that(materialized_into(|buf| routes::root::Archive.materialize_into(buf, None)))
+        .is_equal_to(routes::root::Archive.materialize(None));
+    assert_that(materialized_into(|buf| routes::root::Lang.materialize_into(buf, None)))
+        .is_equal_to("/");
+
+    // Writers reject paths not fitting into them.
+    let mut small = Buf { bytes: [0; 64], len: 60 };
+    assert_that(routes::root::User.materialize_into(&mut small, 42)).is_equal_to(Err(fmt::Error));
+
+    assert_that(routes::root::User.matches("/users/42")).is_equal_to(true);
+    assert_that(routes::Route::from_path("/users/42/files/a/b"))
+        .is_equal_to(Some(routes::Route::from(routes::root::user::Files)));
+
+    // Writing the path of a route counts as using it.
+    assert_that(routes::unused_routes().contains(&"root::user::Files")).is_equal_to(false);
+}
@@ -0,0 +1,26 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/", title = "Home")]
+    pub mod root {
+
+        #[route("/users/:id", title = "The \"user\"")]
+        pub mod user {
+
+            #[route("/files/:page?")]
+            pub mod files {}
+        }
+    }
+}
+
+fn main() {
+    assert_that(routes::routes_json()).is_equal_to(String::from(concat!(
+        r#"[{"name":"Root","path":"/","params":[],"title":"Home"},"#,
+        r#"{"name":"RootUser","path":"/users/:id","params":[{"name":"id","optional":false}],"title":"The \"user\""},"#,
+        r#"{"name":"RootUserFiles","path":"/users/:id/files/:page?","params":[{"name":"id","optional":false},{"name":"page","optional":true}],"title":null}]"#,
+    )));
+}
@@ -0,0 +1,45 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes(case_insensitive)]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/About")]
+        pub mod about {}
+
+        #[route(group)]
+        pub mod account {
+
+            #[route("/settings")]
+            pub mod settings {}
+        }
+
+        #[route("/users/:id", sample(id = "42"))]
+        pub mod user {
+
+            #[route("/posts/:post")]
+            pub mod post {}
+
+            #[route("/files/:page?")]
+            pub mod files {}
+        }
+    }
+}
+
+fn main() {
+    // Groups share the path of their parent, and routes lacking a sample value for a required
+    // parameter are left out.
+    assert_that(routes::precache_urls()).is_equal_to(
+        &[
+            "/",
+            "/about",
+            "/settings",
+            "/users/42",
+            "/users/42/files",
+        ][..],
+    );
+}
@@ -0,0 +1,46 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::manifest::ManifestScopeError;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/app")]
+        pub mod app {
+
+            #[route("/inbox")]
+            pub mod inbox {}
+        }
+    }
+}
+
+use routes::Route;
+
+fn main() {
+    assert_that(routes::check_manifest_scope("/app/inbox?source=pwa", "/app/"))
+        .is_equal_to(Ok(Route::from(routes::root::app::Inbox)));
+    assert_that(routes::check_manifest_scope("https://example.com/app", "https://example.com/"))
+        .is_equal_to(Ok(Route::from(routes::root::App)));
+
+    assert_that(routes::check_manifest_scope("/start", "/")).is_equal_to(Err(
+        ManifestScopeError::StartUrlNotMatched { start_url: "/start".to_string() },
+    ));
+    assert_that(routes::check_manifest_scope("/app", "/pwa/")).is_equal_to(Err(
+        ManifestScopeError::ScopeNotMatched { scope: "/pwa/".to_string() },
+    ));
+    assert_that(routes::check_manifest_scope("/", "/app/")).is_equal_to(Err(
+        ManifestScopeError::StartUrlOutOfScope {
+            start_url: "/".to_string(),
+            scope: "/app/".to_string(),
+        },
+    ));
+    assert_that(routes::check_manifest_scope("https://example.com/app", "https://other.com/"))
+        .is_equal_to(Err(ManifestScopeError::StartUrlOutOfScope {
+            start_url: "https://example.com/app".to_string(),
+            scope: "https://other.com/".to_string(),
+        }));
+}
@@ -0,0 +1,56 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+use std::cell::RefCell;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }", on_not_found = "log_404")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/users", view = "Users")]
+        pub mod users {}
+    }
+}
+
+thread_local! {
+    static NOT_FOUND: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn log_404(url: &str) {
+    NOT_FOUND.with(|not_found| not_found.borrow_mut().push(url.to_string()));
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+
+    let _ = Owner::new_root(None);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/users"));
+    assert_that(app().to_html()).is_equal_to(r#"<main>Users</main>"#);
+
+    provide_context::<RequestUrl>(RequestUrl::new("/missing?ref=mail"));
+    assert_that(app().to_html()).is_equal_to(r#"Err404"#);
+
+    assert_that(NOT_FOUND.with(|not_found| not_found.borrow().clone()))
+        .is_equal_to(vec![String::from("/missing?ref=mail")]);
+}
@@ -0,0 +1,52 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/users/:page?", view = "Users")]
+        pub mod users {}
+
+        #[route("/user/:id", view = "User")]
+        pub mod user {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn User() -> impl IntoView {
+    let back = move |_| routes::back_or(Route::RootUsers(root::Users));
+    view! { <button on:click=back>"Back"</button> }
+}
+
+use routes::{root, Route};
+
+fn main() {
+    // The previous location is only tracked in the browser.
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new("/user/42"));
+    assert_that(app().to_html()).is_equal_to("<main><button>Back</button></main>".to_string());
+
+    let _: fn(Route) = routes::back_or;
+}
@@ -0,0 +1,64 @@
+use assertr::assert_that;
+use assertr::prelude::{BoolAssertions, PartialEqAssertions};
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_routes::routes;
+use leptos_routes::titles::{title_element, TitleMismatch};
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod titled {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard", title = "Dashboard")]
+    pub mod root {
+
+        #[route("/users/:id", view = "User", sample(id = "42"))]
+        pub mod user {}
+
+        #[route("/help/:topic", view = "Help", title = "Help")]
+        pub mod help {}
+    }
+}
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod untitled {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/settings", view = "Settings", title = "Settings")]
+        pub mod settings {}
+
+        #[route("/about", view = "About")]
+        pub mod about {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { <title>"Dashboard | App"</title> "Dashboard" } }
+#[component]
+fn User() -> impl IntoView { view! { <title>"User | App"</title> "User" } }
+#[component]
+fn Help() -> impl IntoView { view! { <title>"Help | App"</title> "Help" } }
+#[component]
+fn Settings() -> impl IntoView { view! { <title>"Preferences | App"</title> "Settings" } }
+#[component]
+fn About() -> impl IntoView { view! { "About" } }
+
+fn main() {
+    assert_that(titled::TITLE_TEST_PATHS).is_equal_to(
+        &[("/", Some("Dashboard")), ("/users/42", None), ("/help/1", Some("Help"))][..],
+    );
+    assert_that(titled::check_titles(title_element).is_ok()).is_true();
+
+    assert_that(untitled::check_titles(title_element).unwrap_err()).is_equal_to(TitleMismatch {
+        path: "/settings".to_string(),
+        reason: "the applied title \"Preferences | App\" does not contain the declared title \"Settings\"".to_string(),
+    });
+    let app = || view! { <Router> {untitled::generated_routes()} </Router> };
+    let mismatch = leptos_routes::titles::check_path("/about", None, app, title_element);
+    assert_that(mismatch.unwrap_err().reason).is_equal_to("no title was applied".to_string());
+}
@@ -0,0 +1,49 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::labels::LabelResolver;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users", nav(label_key = "menu.users"))]
+        pub mod users {}
+
+        #[route("/reports", title = "Reports", nav(label_key = "menu.reports"))]
+        pub mod reports {}
+
+        #[route("/help", title = "Help")]
+        pub mod help {}
+    }
+}
+
+/// An adapter of a translation library, here a fixed German catalog.
+struct Catalog;
+
+impl LabelResolver for Catalog {
+    fn resolve(&self, key: &str) -> Option<String> {
+        match key {
+            "menu.users" => Some("Benutzer".to_string()),
+            _ => None,
+        }
+    }
+}
+
+fn main() {
+    use routes::*;
+
+    let items = nav_items();
+    assert_that(items.iter().map(|item| item.label_key).collect::<Vec<_>>())
+        .is_equal_to(vec![Some("menu.users"), Some("menu.reports"), None]);
+    assert_that(items[0].title).is_equal_to("menu.users");
+
+    // Keys without a message fall back to the title.
+    let labels = items.iter().map(|item| item.label(&Catalog)).collect::<Vec<_>>();
+    assert_that(labels).is_equal_to(vec!["Benutzer".to_string(), "Reports".to_string(), "Help".to_string()]);
+
+    let uppercase = |key: &str| Some(key.to_uppercase());
+    assert_that(items[1].label(&uppercase)).is_equal_to("MENU.REPORTS".to_string());
+}
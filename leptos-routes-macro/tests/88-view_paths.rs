@@ -0,0 +1,57 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+mod pages {
+    use leptos::prelude::*;
+
+    #[component]
+    pub fn Users() -> impl IntoView { view! { "Users" } }
+}
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        // Bare paths are checked to resolve, others like closures are left as they are.
+        #[route("/users", view = "pages::Users")]
+        pub mod users {}
+
+        #[route("/about", view = "crate::About")]
+        pub mod about {}
+
+        #[route("/help", view = "|| view! { <Help/> }")]
+        pub mod help {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn About() -> impl IntoView { view! { "About" } }
+#[component]
+fn Help() -> impl IntoView { view! { "Help" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    for (path, expected) in [("/", "Home"), ("/users", "Users"), ("/about", "About"), ("/help", "Help")] {
+        let _ = Owner::new_root(None);
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(format!("<main>{expected}</main>"));
+    }
+}
@@ -0,0 +1,61 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[derive(Debug, Clone, Default)]
+pub struct UserContext {
+    name: String,
+}
+
+#[routes(
+    with_views,
+    fallback = "|| view! { <Err404/> }",
+    captures(user_ctx = "use_context::<UserContext>().unwrap_or_default()", greeting = "String::from(\"Hello\")")
+)]
+pub mod routes {
+
+    #[route(
+        "/",
+        layout = "move || view! { <main data-user=user_ctx.name> <Outlet/> </main> }",
+        fallback = "move || view! { {greeting} }",
+        captures(user_ctx, greeting)
+    )]
+    pub mod root {
+
+        #[route("/profile", view = "move || view! { <Profile name=user_ctx.name/> }", captures(user_ctx))]
+        pub mod profile {}
+
+        #[route("/help", view = "Help")]
+        pub mod help {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn Profile(name: String) -> impl IntoView { view! { <p>{name}</p> } }
+#[component]
+fn Help() -> impl IntoView { view! { "Help" } }
+
+fn main() {
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    for (path, expected) in [
+        ("/", r#"<main data-user="alice">Hello</main>"#),
+        ("/profile", r#"<main data-user="alice"><p>alice</p></main>"#),
+        ("/help", r#"<main data-user="alice">Help</main>"#),
+    ] {
+        let _ = Owner::new_root(None);
+        provide_context(UserContext { name: "alice".to_string() });
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        assert_that(app().to_html()).is_equal_to(expected.to_string());
+    }
+}
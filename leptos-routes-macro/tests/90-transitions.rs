@@ -0,0 +1,88 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Welcome")]
+    pub mod root {
+
+        #[route("/login", view = "Login")]
+        pub mod login {}
+
+        #[route("/signup", view = "Signup")]
+        pub mod signup {}
+
+        #[route("/account", view = "Account", allowed_from("login", "root.signup"))]
+        pub mod account {}
+
+        #[route("/cart", layout = "Cart", index = "CartItems")]
+        pub mod cart {
+
+            #[route("/edit", view = "CartEdit")]
+            pub mod edit {}
+        }
+
+        #[route("/checkout", view = "Checkout", allowed_from("cart"))]
+        pub mod checkout {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Welcome() -> impl IntoView { view! { "Welcome" } }
+#[component]
+fn Login() -> impl IntoView { view! { "Login" } }
+#[component]
+fn Signup() -> impl IntoView { view! { "Signup" } }
+#[component]
+fn Account() -> impl IntoView { view! { "Account" } }
+#[component]
+fn Cart() -> impl IntoView { view! { <Outlet/> } }
+#[component]
+fn CartItems() -> impl IntoView { view! { "CartItems" } }
+#[component]
+fn CartEdit() -> impl IntoView { view! { "CartEdit" } }
+#[component]
+fn Checkout() -> impl IntoView { view! { "Checkout" } }
+
+use routes::{can_transition, root, Route};
+
+fn main() {
+    let login = Route::RootLogin(root::Login);
+    let signup = Route::RootSignup(root::Signup);
+    let account = Route::RootAccount(root::Account);
+    let checkout = Route::RootCheckout(root::Checkout);
+    assert_that(can_transition(&login, &account)).is_equal_to(true);
+    assert_that(can_transition(&signup, &account)).is_equal_to(true);
+    assert_that(can_transition(&checkout, &account)).is_equal_to(false);
+    assert_that(can_transition(&account, &login)).is_equal_to(true);
+    assert_that(can_transition(&account, &account)).is_equal_to(false);
+
+    // The cart renders its own page as its index route.
+    let cart = Route::from_path("/cart").unwrap();
+    let cart_edit = Route::from_path("/cart/edit").unwrap();
+    assert_that(can_transition(&cart, &checkout)).is_equal_to(true);
+    assert_that(can_transition(&cart_edit, &checkout)).is_equal_to(false);
+
+    // Navigating is only possible in the browser.
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new("/account"));
+    assert_that(app().to_html()).is_equal_to("<main>Account</main>".to_string());
+
+    let _: fn(&str) -> bool = routes::navigate_if_allowed;
+}
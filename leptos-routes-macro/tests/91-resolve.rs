@@ -0,0 +1,67 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::Outlet;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/users", layout = "UsersLayout", fallback = "UsersList")]
+        pub mod users {
+
+            #[route("/:id", view = "User")]
+            pub mod user {}
+        }
+
+        #[route("!/print/:id", view = "Print")]
+        pub mod print {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <section> <Outlet/> </section> } }
+#[component]
+fn UsersList() -> impl IntoView { view! { "UsersList" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn Print() -> impl IntoView { view! { "Print" } }
+
+use routes::root::users;
+use routes::{resolve, root, Route, RouteParams};
+
+fn main() {
+    // No reactive owner is created.
+    let resolved = resolve("/users/42").unwrap();
+    assert_that(resolved.route).is_equal_to(Route::RootUsersUser(users::User));
+    assert_that(resolved.params).is_equal_to(RouteParams::RootUsersUser(users::UserParams {
+        id: "42".to_string(),
+    }));
+    assert_that(resolved.layout_chain.to_vec()).is_equal_to(vec![
+        Route::Root(routes::Root),
+        Route::RootUsers(root::Users),
+    ]);
+
+    let resolved = resolve("/").unwrap();
+    assert_that(resolved.layout_chain.to_vec()).is_equal_to(vec![Route::Root(routes::Root)]);
+
+    // Absolute routes are rendered outside of the layouts of their parents.
+    let resolved = resolve("/print/7").unwrap();
+    assert_that(resolved.route).is_equal_to(Route::RootPrint(root::Print));
+    assert_that(resolved.params).is_equal_to(RouteParams::RootPrint(root::PrintParams {
+        id: "7".to_string(),
+    }));
+    assert_that(resolved.layout_chain.to_vec()).is_equal_to(Vec::<Route>::new());
+
+    assert_that(resolve("/unknown/a/b").is_none()).is_equal_to(true);
+}
@@ -0,0 +1,67 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::Outlet;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/users", layout = "UsersLayout", fallback = "UsersList")]
+        pub mod users {
+
+            #[route("/new", view = "NewUser")]
+            pub mod new {}
+
+            #[route("/:id", view = "User")]
+            pub mod user {}
+        }
+
+        #[route("/settings", view = "Settings")]
+        pub mod settings {}
+
+        #[route("!/print/:id", view = "Print")]
+        pub mod print {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn UsersLayout() -> impl IntoView { view! { <section> <Outlet/> </section> } }
+#[component]
+fn UsersList() -> impl IntoView { view! { "UsersList" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+#[component]
+fn NewUser() -> impl IntoView { view! { "NewUser" } }
+#[component]
+fn Settings() -> impl IntoView { view! { "Settings" } }
+#[component]
+fn Print() -> impl IntoView { view! { "Print" } }
+
+use routes::root::users;
+use routes::{common_layout_depth, root, Route};
+
+fn main() {
+    let user = Route::RootUsersUser(users::User);
+    let new_user = Route::RootUsersNew(users::New);
+    let settings = Route::RootSettings(root::Settings);
+    let print = Route::RootPrint(root::Print);
+
+    assert_that(user.layout_chain().len()).is_equal_to(2);
+    assert_that(common_layout_depth(&user, &new_user)).is_equal_to(2);
+    assert_that(common_layout_depth(&user, &settings)).is_equal_to(1);
+    assert_that(common_layout_depth(&settings, &user)).is_equal_to(1);
+    assert_that(common_layout_depth(&user, &user)).is_equal_to(2);
+
+    // Absolute routes are rendered outside of the layouts of their parents.
+    assert_that(common_layout_depth(&user, &print)).is_equal_to(0);
+}
@@ -0,0 +1,75 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }", namespace = "Admin")]
+pub mod admin {
+
+    #[route("/admin", layout = "AdminLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/users", view = "Users")]
+        pub mod users {}
+    }
+}
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }", namespace = "Site")]
+pub mod site {
+
+    #[route("/", layout = "SiteLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/about", view = "About")]
+        pub mod about {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn AdminLayout() -> impl IntoView { view! { <main class="admin"> <Outlet/> </main> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn Users() -> impl IntoView { view! { "Users" } }
+#[component]
+fn SiteLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn About() -> impl IntoView { view! { "About" } }
+
+// The prefixed names of both routes modules do not clash when glob-importing them.
+use admin::*;
+use site::*;
+
+fn main() {
+    assert_that(AdminRoute::from_path("/admin/users"))
+        .is_equal_to(Some(AdminRoute::RootUsers(admin::root::Users)));
+    assert_that(SiteRoute::from_path("/about"))
+        .is_equal_to(Some(SiteRoute::RootAbout(site::root::About)));
+    assert_that(admin_resolve("/admin").map(|resolved| resolved.route))
+        .is_equal_to(Some(AdminRoute::Root(admin::Root)));
+    let _: Option<SiteResolvedRoute> = site_resolve("/");
+
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new("/admin/users"));
+    let app = view! {
+        <Router>
+            { admin_generated_routes() }
+        </Router>
+    };
+    assert_that(app.to_html()).is_equal_to(r#"<main class="admin">Users</main>"#.to_string());
+
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new("/about"));
+    let app = view! {
+        <Router>
+            { site_generated_routes() }
+        </Router>
+    };
+    assert_that(app.to_html()).is_equal_to("<main>About</main>".to_string());
+}
@@ -0,0 +1,29 @@
+use leptos_routes::routes;
+
+// The note summarizing the route tree is reported as a warning, silenced like the others.
+#[routes(stats(max_routes = 10, max_depth = 4, max_params = 2, max_wildcards = 1))]
+#[allow(deprecated)]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {
+
+        #[route("/users")]
+        pub mod users {
+
+            #[route("/:id")]
+            pub mod user {
+
+                #[route("/posts/:post")]
+                pub mod post {}
+            }
+        }
+
+        #[route("/files/*path")]
+        pub mod files {}
+    }
+}
+
+fn main() {
+    let _ = routes::root::users::user::Post.materialize("1", "2");
+}
@@ -0,0 +1,80 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::{Outlet, Router};
+use leptos_router::location::RequestUrl;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Home")]
+    pub mod root {
+
+        #[route("/inbox", view = "Inbox")]
+        #[route("/inbox", name = "ArchivedInbox", variant(tab = "archived"))]
+        #[route("/inbox", name = "StarredInbox", variant(tab = "starred", sort = "date"))]
+        pub mod inbox {}
+
+        #[route("/folders/:folder", view = "Folder")]
+        #[route("/folders/:folder", name = "UnreadFolder", variant(filter = "unread"))]
+        pub mod folder {}
+    }
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Home() -> impl IntoView { view! { "Home" } }
+#[component]
+fn Inbox() -> impl IntoView {
+    let tab = leptos_router::hooks::use_query_map().with_untracked(|query| query.get("tab"));
+    view! { {format!("Inbox {}", tab.unwrap_or_default())} }
+}
+#[component]
+fn Folder() -> impl IntoView { view! { "Folder" } }
+
+use routes::{root, Route, RouteParams};
+
+fn main() {
+    // Query variants are distinct routes, materialized with their query parameters.
+    assert_that(root::ArchivedInbox.materialize()).is_equal_to("/inbox?tab=archived".to_string());
+    assert_that(root::StarredInbox::MATERIALIZED_PATH).is_equal_to("/inbox?tab=starred&sort=date");
+    assert_that(root::UnreadFolder.materialize("work")).is_equal_to("/folders/work?filter=unread".to_string());
+    let mut out = String::new();
+    root::UnreadFolder.materialize_into(&mut out, "work").unwrap();
+    assert_that(out).is_equal_to("/folders/work?filter=unread".to_string());
+
+    // Paths are matched by their query parameters, in any order and next to others.
+    assert_that(Route::from_path("/inbox")).is_equal_to(Some(Route::RootInbox(root::Inbox)));
+    assert_that(Route::from_path("/inbox?tab=archived"))
+        .is_equal_to(Some(Route::RootArchivedInbox(root::ArchivedInbox)));
+    assert_that(Route::from_path("/inbox?page=2&tab=archived#top"))
+        .is_equal_to(Some(Route::RootArchivedInbox(root::ArchivedInbox)));
+    assert_that(Route::from_path("/inbox?sort=date&tab=starred"))
+        .is_equal_to(Some(Route::RootStarredInbox(root::StarredInbox)));
+    assert_that(Route::from_path("/inbox?tab=starred"))
+        .is_equal_to(Some(Route::RootInbox(root::Inbox)));
+    assert_that(Route::from_path("/inbox?tab=other"))
+        .is_equal_to(Some(Route::RootInbox(root::Inbox)));
+    assert_that(Route::from_path_with_params("/folders/work?filter=unread")).is_equal_to(Some((
+        Route::RootUnreadFolder(root::UnreadFolder),
+        RouteParams::RootUnreadFolder(root::UnreadFolderParams { folder: "work".to_string() }),
+    )));
+    assert_that(root::Inbox.matches("/inbox?tab=archived")).is_equal_to(true);
+    assert_that(root::ArchivedInbox.matches("/inbox")).is_equal_to(false);
+
+    // The route sharing their path renders them, reading the query parameters.
+    fn app() -> impl IntoView {
+        view! {
+            <Router>
+                { routes::generated_routes() }
+            </Router>
+        }
+    }
+    let _ = Owner::new_root(None);
+    provide_context::<RequestUrl>(RequestUrl::new("/inbox?tab=archived"));
+    assert_that(app().to_html()).is_equal_to("<main>Inbox archived</main>".to_string());
+}
@@ -0,0 +1,51 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {}
+
+    #[route("/account", security(referrer = "no-referrer", frame = "deny"))]
+    pub mod account {
+
+        #[route("/embed", security(frame = "sameorigin"))]
+        pub mod embed {}
+
+        #[route("/settings")]
+        pub mod settings {}
+    }
+
+    #[route("/share", security(referrer = "strict-origin"))]
+    pub mod share {}
+
+    #[route("/checkout", security(referrer = "same-origin"))]
+    pub mod checkout {}
+}
+
+use routes::{security_headers_for, Route};
+
+fn main() {
+    let headers = |route: Route| security_headers_for(&route).to_vec();
+
+    assert_that(headers(Route::Root(routes::Root))).is_equal_to(vec![]);
+    assert_that(headers(Route::Account(routes::Account))).is_equal_to(vec![
+        ("Referrer-Policy", "no-referrer"),
+        ("X-Frame-Options", "DENY"),
+    ]);
+    // Child routes inherit each policy they do not declare themselves.
+    assert_that(headers(Route::AccountEmbed(routes::account::Embed))).is_equal_to(vec![
+        ("Referrer-Policy", "no-referrer"),
+        ("X-Frame-Options", "SAMEORIGIN"),
+    ]);
+    assert_that(headers(Route::AccountSettings(routes::account::Settings))).is_equal_to(vec![
+        ("Referrer-Policy", "no-referrer"),
+        ("X-Frame-Options", "DENY"),
+    ]);
+    assert_that(headers(Route::Share(routes::Share)))
+        .is_equal_to(vec![("Referrer-Policy", "strict-origin")]);
+    assert_that(headers(Route::Checkout(routes::Checkout)))
+        .is_equal_to(vec![("Referrer-Policy", "same-origin")]);
+}
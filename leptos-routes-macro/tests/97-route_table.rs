@@ -0,0 +1,47 @@
+use assertr::assert_that;
+use assertr::prelude::PartialEqAssertions;
+use leptos::prelude::*;
+use leptos_router::components::Outlet;
+use leptos_routes::routes;
+
+#[routes(with_views, fallback = "|| view! { <Err404/> }")]
+pub mod routes {
+
+    #[route("/", layout = "MainLayout", fallback = "Dashboard")]
+    pub mod root {
+
+        #[route("/users/:id", view = "User")]
+        pub mod user {}
+    }
+}
+
+#[routes]
+pub mod plain {
+
+    #[route("/welcome")]
+    pub mod welcome {}
+}
+
+#[component]
+fn Err404() -> impl IntoView { view! { "Err404" } }
+#[component]
+fn MainLayout() -> impl IntoView { view! { <main> <Outlet/> </main> } }
+#[component]
+fn Dashboard() -> impl IntoView { view! { "Dashboard" } }
+#[component]
+fn User() -> impl IntoView { view! { "User" } }
+
+fn main() {
+    // The table documented on the routes module links every route's struct.
+    assert_that(routes::ROUTE_TABLE).is_equal_to(
+        "| Pattern | Route | View | Layout |\n\
+         |---|---|---|---|\n\
+         | `/` | [`Root`](routes::Root) | `Dashboard` | `MainLayout` |\n\
+         | `/users/:id` | [`root::User`](routes::root::User) | `User` |  |",
+    );
+    assert_that(plain::ROUTE_TABLE).is_equal_to(
+        "| Pattern | Route |\n\
+         |---|---|\n\
+         | `/welcome` | [`Welcome`](plain::Welcome) |",
+    );
+}
@@ -0,0 +1,14 @@
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/users", layout = "Users", index = "UserList", index = "UserOverview")]
+    pub mod users {
+
+        #[route("/:id", view = "User")]
+        pub mod user {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/reports/:day", params(day = "NaiveDate", format = "%Y-%Q"))]
+    pub mod report {}
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/")]
+    mod root {
+
+        // Private leaf modules, private modules directly in the routes module and modules
+        // visible to the routes module are fine.
+        #[route("/welcome")]
+        mod welcome {}
+
+        #[route("/settings")]
+        pub(super) mod settings {
+
+            #[route("/profile")]
+            mod profile {}
+        }
+
+        // The routes module cannot see the struct of the child route generated in here.
+        #[route("/users")]
+        mod users {
+
+            #[route("/:id")]
+            pub mod user {}
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/inbox", view = "Inbox")]
+    #[route("/inbox", name = "ArchivedInbox", variant(tab = "a b"))]
+    pub mod inbox {}
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/", veiw = "Home")]
+    pub mod root {}
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/", view = "Home")]
+    pub mod root {
+
+        #[route("/gone", view = "Gone", status = "410")]
+        pub mod gone {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use leptos_routes::routes;
+
+#[routes(with_veiws, fallback = "|| ()")]
+pub mod routes {
+
+    #[route("/")]
+    pub mod root {}
+}
+
+fn main() {}
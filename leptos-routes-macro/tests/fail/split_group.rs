@@ -0,0 +1,14 @@
+use leptos_routes::routes;
+
+#[routes]
+pub mod routes {
+
+    #[route("/", view = "Home")]
+    pub mod root {
+
+        #[route("/admin", view = "Admin", split_group = "admin")]
+        pub mod admin {}
+    }
+}
+
+fn main() {}
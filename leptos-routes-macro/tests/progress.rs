@@ -5,4 +5,106 @@ fn tests() {
     t.pass("tests/02-without_views_not_router_generation.rs");
     t.pass("tests/03-with_views.rs");
     t.pass("tests/04-with_views_simple.rs");
+    t.pass("tests/05-structured_data.rs");
+    t.pass("tests/06-noindex.rs");
+    t.pass("tests/07-constraints.rs");
+    t.pass("tests/08-typed_params.rs");
+    t.pass("tests/09-pagination.rs");
+    t.pass("tests/10-query_struct.rs");
+    t.pass("tests/11-leave_guard.rs");
+    t.pass("tests/12-inherit_fallback.rs");
+    t.pass("tests/13-error_boundary.rs");
+    t.pass("tests/14-suspense.rs");
+    t.pass("tests/15-into_routes.rs");
+    t.pass("tests/16-subtree_routes.rs");
+    t.pass("tests/17-multiple_routes_per_module.rs");
+    t.pass("tests/18-index.rs");
+    t.pass("tests/19-route_from_location.rs");
+    t.pass("tests/20-specificity.rs");
+    t.pass("tests/21-with_router.rs");
+    t.pass("tests/22-render_check.rs");
+    t.pass("tests/23-cache.rs");
+    t.pass("tests/24-route_list.rs");
+    t.pass("tests/25-strip_debug.rs");
+    t.pass("tests/26-deny_unused.rs");
+    t.pass("tests/27-deprecated.rs");
+    t.pass("tests/28-absolute.rs");
+    t.pass("tests/29-route_set.rs");
+    t.pass("tests/30-redirect.rs");
+    t.pass("tests/31-form_action.rs");
+    t.pass("tests/32-flags.rs");
+    t.pass("tests/33-host_param.rs");
+    t.pass("tests/34-absolute_url.rs");
+    t.pass("tests/35-param_assertions.rs");
+    t.pass("tests/36-route_recorder.rs");
+    t.pass("tests/37-data_route.rs");
+    t.pass("tests/38-route_index.rs");
+    t.pass("tests/39-route_path.rs");
+    t.pass("tests/40-shortcuts.rs");
+    t.pass("tests/41-palette.rs");
+    t.pass("tests/42-tracing.rs");
+    t.pass("tests/43-metrics.rs");
+    t.pass("tests/44-static_links.rs");
+    t.pass("tests/45-prefetch.rs");
+    t.pass("tests/46-bench.rs");
+    t.pass("tests/47-group.rs");
+    t.pass("tests/48-layout_stack.rs");
+    t.pass("tests/49-routes_config.rs");
+    t.pass("tests/50-route_error.rs");
+    t.pass("tests/51-display_params.rs");
+    t.pass("tests/52-optional_params.rs");
+    t.pass("tests/53-normalize.rs");
+    t.pass("tests/54-case_insensitive.rs");
+    t.pass("tests/55-legacy_redirects.rs");
+    t.pass("tests/56-unmatched_path_fallback.rs");
+    t.pass("tests/57-status.rs");
+    t.pass("tests/58-bot_view.rs");
+    t.pass("tests/59-defaults.rs");
+    t.pass("tests/60-materialize_relative_to.rs");
+    t.pass("tests/61-ordered.rs");
+    t.pass("tests/62-wizard.rs");
+    t.pass("tests/63-modal.rs");
+    t.pass("tests/64-panes.rs");
+    t.pass("tests/65-keep_alive.rs");
+    t.pass("tests/66-owners.rs");
+    t.pass("tests/67-class.rs");
+    t.pass("tests/68-preview.rs");
+    t.pass("tests/69-external_routes.rs");
+    t.pass("tests/70-crud.rs");
+    t.pass("tests/71-resource_endpoints.rs");
+    t.pass("tests/72-sections.rs");
+    t.pass("tests/73-merge.rs");
+    t.pass("tests/74-guard.rs");
+    t.pass("tests/75-intended_destination.rs");
+    t.pass("tests/76-roles.rs");
+    t.pass("tests/77-access_audit.rs");
+    t.pass("tests/78-materialize_const.rs");
+    t.pass("tests/79-patterns.rs");
+    t.pass("tests/80-no_std.rs");
+    t.pass("tests/81-routes_json.rs");
+    t.pass("tests/82-precache.rs");
+    t.pass("tests/83-manifest_scope.rs");
+    t.pass("tests/84-on_not_found.rs");
+    t.pass("tests/85-back_or.rs");
+    t.pass("tests/86-title_check.rs");
+    t.pass("tests/87-nav_label_key.rs");
+    t.pass("tests/88-view_paths.rs");
+    t.pass("tests/89-captures.rs");
+    t.pass("tests/90-transitions.rs");
+    t.pass("tests/91-resolve.rs");
+    t.pass("tests/92-common_layout_depth.rs");
+    t.pass("tests/93-namespace.rs");
+    t.pass("tests/94-stats.rs");
+    t.pass("tests/95-query_variant.rs");
+    t.pass("tests/96-security_headers.rs");
+    t.pass("tests/97-route_table.rs");
+    t.compile_fail("tests/fail/private_route_module.rs");
+    t.compile_fail("tests/fail/invalid_param_format.rs");
+    t.compile_fail("tests/fail/slash_param_format.rs");
+    t.compile_fail("tests/fail/routes_argument_typo.rs");
+    t.compile_fail("tests/fail/route_argument_typo.rs");
+    t.compile_fail("tests/fail/query_variant_value.rs");
+    t.compile_fail("tests/fail/route_argument_value.rs");
+    t.compile_fail("tests/fail/duplicate_index.rs");
+    t.compile_fail("tests/fail/split_group.rs");
 }
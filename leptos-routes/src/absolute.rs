@@ -0,0 +1,14 @@
+//! Absolute URLs of routes, returned by the generated `absolute` functions.
+
+pub use url::Url;
+
+/// The URL of the given materialized path below the path of `base`, replacing any query string or
+/// hash fragment of `base`. A base of "https://example.com/app" and a path of "/users/42" result
+/// in "https://example.com/app/users/42".
+pub fn absolute_url(base: &Url, path: &str) -> Url {
+    let mut url = base.clone();
+    url.set_path(&format!("{}{}", base.path().trim_end_matches('/'), path));
+    url.set_query(None);
+    url.set_fragment(None);
+    url
+}
@@ -0,0 +1,63 @@
+//! Going back in the browser's history only while staying inside the app, used by the generated
+//! `back_or` function.
+
+use leptos::prelude::{document, window, Effect, Get, With};
+use leptos_router::hooks::{use_location, use_navigate};
+use std::cell::RefCell;
+
+#[derive(Default)]
+struct Locations {
+    previous: Option<String>,
+    current: Option<String>,
+    going_back: bool,
+}
+
+thread_local! {
+    static LOCATIONS: RefCell<Locations> = RefCell::default();
+}
+
+/// Tracks the path and query string of the router's location while the calling component is
+/// mounted, remembering the location navigated away from. For the initially loaded page, this is
+/// the `document.referrer` if it has the same origin as the page.
+///
+/// After going back using `back_or`, the previous location is unknown until the next navigation.
+pub fn track_locations() {
+    let location = use_location();
+    Effect::new(move |_| {
+        let url = location.pathname.get() + &location.query.with(|query| query.to_query_string());
+        LOCATIONS.with_borrow_mut(|locations| {
+            let last = locations.current.replace(url);
+            locations.previous = match (last, locations.going_back) {
+                (_, true) => None,
+                (Some(last), false) => Some(last),
+                (None, false) => same_origin_referrer(),
+            };
+            locations.going_back = false;
+        });
+    });
+}
+
+/// The path and query string of the `document.referrer`, if it has the same origin as the page.
+fn same_origin_referrer() -> Option<String> {
+    let origin = window().location().origin().ok()?;
+    let referrer = document().referrer();
+    let path = referrer.strip_prefix(&origin)?;
+    path.starts_with('/').then(|| path.to_string())
+}
+
+/// Goes back in the browser's history if the location tracked as previous by `track_locations`
+/// is accepted by `is_app_path`, or navigates to the `fallback` path otherwise. Must be called
+/// inside a `<Router>`.
+pub fn back_or(is_app_path: impl Fn(&str) -> bool, fallback: &str) {
+    let previous = LOCATIONS.with_borrow(|locations| locations.previous.clone());
+    if previous.is_some_and(|previous| is_app_path(&previous)) {
+        if let Ok(history) = window().history() {
+            LOCATIONS.with_borrow_mut(|locations| locations.going_back = true);
+            if history.back().is_ok() {
+                return;
+            }
+            LOCATIONS.with_borrow_mut(|locations| locations.going_back = false);
+        }
+    }
+    use_navigate()(fallback, Default::default());
+}
@@ -0,0 +1,30 @@
+//! Benchmarks of route matching, run by the generated `bench_match_path` function.
+
+pub use criterion;
+
+use criterion::{Criterion, Throughput};
+use std::hint::black_box;
+
+/// A path not matched by any route, unless a wildcard route catches it.
+const UNMATCHED_PATH: &str = "/__leptos_routes/unmatched/path";
+
+/// Benchmarks matching all of the given URLs using `from_path`, and matching a path no route
+/// matches, in the "match_path" benchmark group.
+pub fn bench_match_path<R>(
+    c: &mut Criterion,
+    urls: &[&str],
+    from_path: impl Fn(&str) -> Option<R>,
+) {
+    let mut group = c.benchmark_group("match_path");
+    group.throughput(Throughput::Elements(urls.len() as u64));
+    group.bench_function("all", |b| {
+        b.iter(|| {
+            for url in urls {
+                black_box(from_path(black_box(url)));
+            }
+        })
+    });
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("unmatched", |b| b.iter(|| from_path(black_box(UNMATCHED_PATH))));
+    group.finish();
+}
@@ -0,0 +1,28 @@
+//! Detection of bots and crawlers, selecting the `bot_view` of routes declaring one.
+
+/// Whether the current request was made by a bot or crawler. Provide it as context on the server,
+/// e.g. using `IsBot::from_user_agent`, and the generated router renders the `bot_view` of routes
+/// declaring one instead of their `view`. Without it, the `view` is rendered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsBot(pub bool);
+
+/// Substrings of user agents sent by common bots and crawlers, compared ignoring case.
+const BOT_MARKERS: &[&str] = &[
+    "bot",
+    "crawler",
+    "spider",
+    "slurp",
+    "facebookexternalhit",
+    "embedly",
+    "preview",
+    "headless",
+];
+
+impl IsBot {
+    /// Guesses whether the given `User-Agent` header belongs to a bot or crawler, like Googlebot or
+    /// Bingbot, by looking for markers common to their user agents.
+    pub fn from_user_agent(user_agent: &str) -> Self {
+        let user_agent = user_agent.to_ascii_lowercase();
+        Self(BOT_MARKERS.iter().any(|marker| user_agent.contains(marker)))
+    }
+}
@@ -0,0 +1,41 @@
+//! HTTP caching policies of routes, returned by the generated `cache_policy` function.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// How responses rendering a route may be cached, declared using `cache(max_age = 3600, swr = 86400)`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CachePolicy {
+    /// The number of seconds a response stays fresh.
+    pub max_age: Option<u32>,
+    /// The number of seconds a stale response may still be served while it is revalidated.
+    pub stale_while_revalidate: Option<u32>,
+}
+
+impl CachePolicy {
+    /// The policy of routes not declaring (or nested below a route declaring) `cache(...)`.
+    pub const NONE: CachePolicy = CachePolicy {
+        max_age: None,
+        stale_while_revalidate: None,
+    };
+
+    /// The value of the `Cache-Control` header for this policy, like
+    /// "max-age=3600, stale-while-revalidate=86400". Returns `None` for `CachePolicy::NONE`,
+    /// leaving the header up to the server.
+    pub fn cache_control(&self) -> Option<String> {
+        let directives = [
+            self.max_age.map(|seconds| format!("max-age={seconds}")),
+            self.stale_while_revalidate
+                .map(|seconds| format!("stale-while-revalidate={seconds}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if directives.is_empty() {
+            None
+        } else {
+            Some(directives.join(", "))
+        }
+    }
+}
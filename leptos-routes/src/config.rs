@@ -0,0 +1,38 @@
+//! Per-deployment configuration of the generated routes, passed to the generated
+//! `generated_routes_with` and `generated_router_with` functions.
+
+use crate::flags::FlagSet;
+use alloc::borrow::Cow;
+
+/// Configuration of the generated routes, known only at runtime.
+///
+/// ```ignore
+/// let config = RoutesConfig::new()
+///     .with_flags(FlagSet::new().with("beta"))
+///     .with_base("/app");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RoutesConfig {
+    /// The enabled feature flags, provided as context to the routes declaring a `flag`. Without
+    /// flags, any `FlagSet` already provided as context is used.
+    pub flags: Option<FlagSet>,
+    /// The base path of the `<Router>` rendered by `generated_router_with`, overriding the `base`
+    /// declared on `#[routes(...)]`.
+    pub base: Option<Cow<'static, str>>,
+}
+
+impl RoutesConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_flags(mut self, flags: FlagSet) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    pub fn with_base(mut self, base: impl Into<Cow<'static, str>>) -> Self {
+        self.base = Some(base.into());
+        self
+    }
+}
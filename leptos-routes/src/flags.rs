@@ -0,0 +1,42 @@
+//! Runtime feature flags, enabling routes declared with `flag = "..."`.
+
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+
+/// The set of enabled feature flags. Routes declaring a `flag` (or nested below a route declaring
+/// one) are only rendered by the generated router while their flags are enabled in the `FlagSet`
+/// provided as context, and are only contained in the `RouteSet` returned by the generated
+/// `enabled_routes` function for a set enabling them.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FlagSet(BTreeSet<Cow<'static, str>>);
+
+impl FlagSet {
+    /// A set with no flags enabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This set, additionally enabling the given flag.
+    pub fn with(mut self, flag: impl Into<Cow<'static, str>>) -> Self {
+        self.enable(flag);
+        self
+    }
+
+    pub fn enable(&mut self, flag: impl Into<Cow<'static, str>>) {
+        self.0.insert(flag.into());
+    }
+
+    pub fn disable(&mut self, flag: &str) {
+        self.0.remove(flag);
+    }
+
+    pub fn is_enabled(&self, flag: &str) -> bool {
+        self.0.contains(flag)
+    }
+}
+
+impl<F: Into<Cow<'static, str>>> FromIterator<F> for FlagSet {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        Self(iter.into_iter().map(Into::into).collect())
+    }
+}
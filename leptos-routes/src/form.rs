@@ -0,0 +1,12 @@
+//! Form actions of routes, used by the generated `form_action_with_method` functions.
+
+use alloc::format;
+use alloc::string::String;
+
+/// Appends the `method` to submit a form with as the query parameter `param` to the given action,
+/// letting servers override the `POST` method of the HTML form. The method is uppercased, like
+/// "PUT" or "DELETE".
+pub fn with_method_override(action: String, param: &str, method: &str) -> String {
+    let separator = if action.contains('?') { '&' } else { '?' };
+    format!("{action}{separator}{param}={}", method.to_ascii_uppercase())
+}
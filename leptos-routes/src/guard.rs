@@ -0,0 +1,115 @@
+//! Asking for confirmation before navigating away from a route, used by routes declaring a
+//! `leave_guard`.
+
+use leptos::prelude::{window, Effect, Owner, StoredValue, WithValue};
+use leptos::reactive::owner::on_cleanup;
+use leptos::wasm_bindgen::closure::Closure;
+use leptos::wasm_bindgen::JsCast;
+use std::sync::Arc;
+
+/// The message shown when asking whether to leave a guarded route.
+pub const LEAVE_CONFIRMATION: &str =
+    "You have unsaved changes. Do you really want to leave this page?";
+
+/// Asks for confirmation before navigating away from the guarded route while `guard` returns
+/// `true`. Navigation is prevented when the confirmation is declined.
+///
+/// Guards clicks on links leading to other origins or to paths for which `within` returns `false`,
+/// as well as reloading or closing the page. `within` tells whether a path still renders the
+/// guarded route, like the paths of its child routes. Going back or forward in the history and
+/// navigating programmatically are not guarded.
+///
+/// `guard` is called with the owner of the calling component, so it may access context. Listeners
+/// are only installed in the browser and removed when the calling component is unmounted.
+pub fn use_leave_guard(
+    guard: impl Fn() -> bool + Send + Sync + 'static,
+    within: impl Fn(&str) -> bool + Send + Sync + 'static,
+) {
+    let within: Arc<dyn Fn(&str) -> bool> = Arc::new(within);
+    let owner = Owner::current();
+    let guard: Arc<dyn Fn() -> bool> = Arc::new(move || match &owner {
+        Some(owner) => owner.with(&guard),
+        None => guard(),
+    });
+
+    Effect::new(move |_| {
+        let click = {
+            let guard = guard.clone();
+            let within = within.clone();
+            Closure::<dyn Fn(web_sys::Event)>::new(move |ev: web_sys::Event| {
+                let Some(ev) = ev.dyn_ref::<web_sys::MouseEvent>() else {
+                    return;
+                };
+                // Mirrors the checks `leptos_router` performs before navigating on a click.
+                if ev.default_prevented()
+                    || ev.button() != 0
+                    || ev.meta_key()
+                    || ev.alt_key()
+                    || ev.ctrl_key()
+                    || ev.shift_key()
+                {
+                    return;
+                }
+                let anchor = ev
+                    .composed_path()
+                    .iter()
+                    .find_map(|target| target.dyn_into::<web_sys::HtmlAnchorElement>().ok());
+                let Some(anchor) = anchor else {
+                    return;
+                };
+                if !anchor.target().is_empty() || anchor.has_attribute("download") {
+                    return;
+                }
+                // Navigating within the guarded route, like to its child routes or changing only
+                // the query string or hash fragment, does not leave it. Links to other origins
+                // always do, whatever their path.
+                let same_origin =
+                    window().location().origin().is_ok_and(|origin| origin == anchor.origin());
+                if same_origin && within(&anchor.pathname()) {
+                    return;
+                }
+                if guard() && !window().confirm_with_message(LEAVE_CONFIRMATION).unwrap_or(true) {
+                    // The router does not navigate on clicks with a prevented default.
+                    ev.prevent_default();
+                }
+            })
+        };
+        let before_unload = {
+            let guard = guard.clone();
+            Closure::<dyn Fn(web_sys::Event)>::new(move |ev: web_sys::Event| {
+                if guard() {
+                    ev.prevent_default();
+                    if let Some(ev) = ev.dyn_ref::<web_sys::BeforeUnloadEvent>() {
+                        ev.set_return_value(LEAVE_CONFIRMATION);
+                    }
+                }
+            })
+        };
+
+        // Listening in the capture phase, this runs before the router's own click listener.
+        let _ = window().add_event_listener_with_callback_and_bool(
+            "click",
+            click.as_ref().unchecked_ref(),
+            true,
+        );
+        let _ = window().add_event_listener_with_callback(
+            "beforeunload",
+            before_unload.as_ref().unchecked_ref(),
+        );
+
+        let listeners = StoredValue::new_local((click, before_unload));
+        on_cleanup(move || {
+            listeners.with_value(|(click, before_unload)| {
+                let _ = window().remove_event_listener_with_callback_and_bool(
+                    "click",
+                    click.as_ref().unchecked_ref(),
+                    true,
+                );
+                let _ = window().remove_event_listener_with_callback(
+                    "beforeunload",
+                    before_unload.as_ref().unchecked_ref(),
+                );
+            });
+        });
+    });
+}
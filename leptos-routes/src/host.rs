@@ -0,0 +1,30 @@
+//! Host-aware URLs of multi-tenant apps, serving each tenant on its own subdomain, used by the
+//! code generated with `#[routes(host_param = "...", host = "...")]`.
+
+use alloc::format;
+use alloc::string::String;
+
+/// The absolute URL of the given path on the subdomain of the given tenant, like
+/// "https://acme.example.com/users".
+pub fn tenant_url(tenant: &str, host: &str, path: &str) -> String {
+    format!("https://{tenant}.{host}{path}")
+}
+
+/// Splits an absolute URL on a subdomain of the given host into the tenant and the path, which
+/// includes any query string or hash fragment. Returns `None` for URLs of other hosts, or without
+/// a subdomain.
+///
+/// Any port is ignored, and the path of a URL without one is "/".
+pub fn split_tenant_url<'a>(url: &'a str, host: &str) -> Option<(&'a str, &'a str)> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+    let hostname = authority.split_once(':').map_or(authority, |(hostname, _)| hostname);
+    let tenant = hostname.strip_suffix(host)?.strip_suffix('.')?;
+    if tenant.is_empty() || tenant.contains('.') {
+        return None;
+    }
+    Some((tenant, if path.starts_with('/') { path } else { "/" }))
+}
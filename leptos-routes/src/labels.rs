@@ -0,0 +1,17 @@
+//! Resolving the message keys of navigation labels declared using `nav(label_key = "...")`, used
+//! by the generated `NavItem::label`.
+
+use alloc::string::String;
+
+/// Resolves message keys to localized text, adapting translation libraries like `leptos_i18n` or
+/// `fluent` to the generated `NavItem::label`. Implemented for functions taking a key.
+pub trait LabelResolver {
+    /// The localized text of the given key, or `None` if there is no message for it.
+    fn resolve(&self, key: &str) -> Option<String>;
+}
+
+impl<F: Fn(&str) -> Option<String>> LabelResolver for F {
+    fn resolve(&self, key: &str) -> Option<String> {
+        self(key)
+    }
+}
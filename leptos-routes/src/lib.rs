@@ -1 +1,69 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub use leptos_routes_macro::*;
+
+#[cfg(feature = "url")]
+pub mod absolute;
+#[cfg(feature = "back")]
+pub mod back;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod bot;
+pub mod cache;
+pub mod config;
+pub mod flags;
+pub mod form;
+#[cfg(feature = "leave_guard")]
+pub mod guard;
+pub mod host;
+pub mod labels;
+pub mod links;
+pub mod manifest;
+pub mod matching;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod params;
+pub mod prefetch;
+#[cfg(feature = "query")]
+pub mod query;
+pub mod redirect;
+#[cfg(any(feature = "render-tests", feature = "title-tests"))]
+pub mod rendering;
+#[cfg(feature = "render-tests")]
+pub mod renders;
+#[cfg(feature = "shortcuts")]
+pub mod shortcuts;
+#[cfg(feature = "status")]
+pub mod status;
+#[cfg(feature = "title-tests")]
+pub mod titles;
+
+/// Items used by the code generated by the `routes` macro. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub extern crate alloc;
+    #[cfg(feature = "std")]
+    pub extern crate std;
+    #[cfg(feature = "chrono")]
+    pub use chrono;
+    #[cfg(feature = "constraints")]
+    pub use regex;
+    #[cfg(feature = "tracing")]
+    pub use tracing;
+    #[cfg(feature = "uuid")]
+    pub use uuid;
+    #[cfg(feature = "wasm-bindgen")]
+    pub use wasm_bindgen;
+
+    /// The items of the standard prelude provided by `alloc`, imported into the modules of routes
+    /// declared with `#[routes(no_std)]`.
+    pub mod alloc_prelude {
+        pub use alloc::borrow::ToOwned;
+        pub use alloc::boxed::Box;
+        pub use alloc::string::{String, ToString};
+        pub use alloc::vec::Vec;
+        pub use alloc::{format, vec};
+    }
+}
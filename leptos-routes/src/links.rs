@@ -0,0 +1,80 @@
+//! Plain link templates of all routes, as listed by the generated `static_link_map()`, for server
+//! templates rendering navigation for clients without JavaScript, and relative links between
+//! routes, as generated by `materialize_relative_to`.
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Fills the placeholders of a link template from `static_link_map()`, like "/users/{id}", with
+/// the given values. Segments of optional and wildcard parameters, written as "{name?}" and
+/// "{*name}", are dropped when no value is given. Returns `None` if a required parameter has no
+/// value.
+///
+/// Values are inserted as given and must already be percent-encoded.
+pub fn fill_href(template: &str, values: &[(&str, &str)]) -> Option<String> {
+    let value_of = |name: &str| {
+        values
+            .iter()
+            .find(|(param, _)| *param == name)
+            .map(|(_, value)| *value)
+    };
+    let mut href = String::new();
+    for segment in template.split('/').filter(|segment| !segment.is_empty()) {
+        let Some(placeholder) = segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) else {
+            href.push('/');
+            href.push_str(segment);
+            continue;
+        };
+        let (name, required) = match placeholder.strip_suffix('?') {
+            Some(name) => (name, false),
+            None => match placeholder.strip_prefix('*') {
+                Some(name) => (name, false),
+                None => (placeholder, true),
+            },
+        };
+        match value_of(name) {
+            Some(value) => {
+                href.push('/');
+                href.push_str(value);
+            }
+            None if required => return None,
+            None => {}
+        }
+    }
+    if href.is_empty() {
+        href.push('/');
+    }
+    Some(href)
+}
+
+/// Computes the href of the materialized path `to_path` of the route `to_pattern`, relative to the
+/// route `from_pattern`, like "../details". Hrefs are resolved like `leptos_router` resolves
+/// relative links within a route's view: every segment of `from_pattern` is one level, left using
+/// "..".
+///
+/// Leading segments both patterns share are left out, up to the first optional or wildcard
+/// parameter, whose presence is unknown. Returns "." when both paths are the same.
+pub fn relative_href(from_pattern: &str, to_pattern: &str, to_path: &str) -> String {
+    let segments = |path: &str| {
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(str::to_owned)
+            .collect::<Vec<_>>()
+    };
+    let from = segments(from_pattern);
+    let to = segments(to_pattern);
+    let shared = from
+        .iter()
+        .zip(&to)
+        .take_while(|(from, to)| from == to && !from.ends_with('?') && !from.starts_with('*'))
+        .count();
+
+    let mut parts = vec![String::from(".."); from.len() - shared];
+    parts.extend(segments(to_path).into_iter().skip(shared));
+    if parts.is_empty() {
+        return String::from(".");
+    }
+    parts.join("/")
+}
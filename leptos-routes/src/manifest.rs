@@ -0,0 +1,85 @@
+//! Validation of the navigation scope of web app manifests, used by the generated
+//! `check_manifest_scope` function.
+
+use alloc::string::String;
+use core::fmt;
+
+/// Why the `start_url` and `scope` of a web app manifest do not fit the declared routes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestScopeError {
+    /// No route matches the path of the `start_url`, so the installed app opens on a missing page.
+    StartUrlNotMatched { start_url: String },
+    /// No route matches the path of the `scope`, so it does not cover a part of the route tree.
+    ScopeNotMatched { scope: String },
+    /// The `start_url` is not within the `scope`, making browsers ignore the `scope`.
+    StartUrlOutOfScope { start_url: String, scope: String },
+}
+
+impl fmt::Display for ManifestScopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestScopeError::StartUrlNotMatched { start_url } => {
+                write!(f, "no route matches the start_url \"{start_url}\"")
+            }
+            ManifestScopeError::ScopeNotMatched { scope } => {
+                write!(f, "no route matches the scope \"{scope}\"")
+            }
+            ManifestScopeError::StartUrlOutOfScope { start_url, scope } => {
+                write!(f, "the start_url \"{start_url}\" is not within the scope \"{scope}\"")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ManifestScopeError {}
+
+/// Checks that the given `start_url` and `scope` of a web app manifest fall inside the route tree:
+/// both must match a route using `from_path`, and the `start_url` must be within the `scope`.
+/// Returns the route matching the `start_url`.
+///
+/// Both URLs must either be absolute, like "https://example.com/app/", or paths, like "/app/", as
+/// relative URLs are resolved against the URL of the manifest, which is not known here. A path is
+/// within a scope if it starts with the path of the scope, and both URLs have the same origin when
+/// both are absolute.
+pub fn check_manifest_scope<R>(
+    start_url: &str,
+    scope: &str,
+    from_path: impl Fn(&str) -> Option<R>,
+) -> Result<R, ManifestScopeError> {
+    let (start_origin, start_path) = split_url(start_url);
+    let (scope_origin, scope_path) = split_url(scope);
+    let Some(route) = from_path(start_path) else {
+        return Err(ManifestScopeError::StartUrlNotMatched { start_url: start_url.into() });
+    };
+    if from_path(scope_path).is_none() {
+        return Err(ManifestScopeError::ScopeNotMatched { scope: scope.into() });
+    }
+    let same_origin = match (start_origin, scope_origin) {
+        (Some(start_origin), Some(scope_origin)) => start_origin == scope_origin,
+        _ => true,
+    };
+    if !same_origin || !start_path.starts_with(scope_path) {
+        return Err(ManifestScopeError::StartUrlOutOfScope {
+            start_url: start_url.into(),
+            scope: scope.into(),
+        });
+    }
+    Ok(route)
+}
+
+/// Splits the given URL into its origin, like "https://example.com", if it is absolute, and its
+/// path, without any query string or hash fragment. The path of an origin alone is "/".
+fn split_url(url: &str) -> (Option<&str>, &str) {
+    let (origin, rest) = match url.find("://") {
+        Some(scheme_end) => {
+            let authority_start = scheme_end + 3;
+            let origin_end = url[authority_start..]
+                .find(['/', '?', '#'])
+                .map_or(url.len(), |i| authority_start + i);
+            (Some(&url[..origin_end]), &url[origin_end..])
+        }
+        None => (None, url),
+    };
+    let path = rest.split(['?', '#']).next().unwrap_or_default();
+    (origin, if path.is_empty() { "/" } else { path })
+}
@@ -0,0 +1,178 @@
+//! Runtime matching of concrete paths against route patterns.
+//!
+//! The `routes` macro generates `matches` functions on all route structs and a `Route::from_path`
+//! function, which are implemented using the types of this module.
+
+use alloc::borrow::{Cow, ToOwned};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A single segment of a route pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Segment {
+    /// A segment which must be matched exactly, like "users".
+    Static(&'static str),
+    /// A required parameter, like ":id".
+    Param(&'static str),
+    /// An optional parameter, like ":id?".
+    OptionalParam(&'static str),
+    /// A wildcard consuming all remaining segments, like "*rest".
+    Wildcard(&'static str),
+}
+
+/// The parameters captured while matching a path against a route pattern.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PathParams(Vec<(&'static str, String)>);
+
+impl PathParams {
+    /// The value captured for the parameter with the given name. Returns `None` when no such
+    /// parameter exists or an optional parameter was not present in the matched path.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find_map(|(k, v)| if *k == name { Some(v.as_str()) } else { None })
+    }
+
+    /// Iterates over all captured parameters in the order of their appearance in the path.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.0.iter().map(|(k, v)| (*k, v.as_str()))
+    }
+}
+
+/// Matches the given path against a route pattern, returning all captured parameters on success.
+///
+/// Any query string or hash fragment of the path is ignored.
+pub fn match_path(pattern: &[Segment], path: &str) -> Option<PathParams> {
+    match_path_with_case(pattern, path, false)
+}
+
+/// Matches the given path against a route pattern like `match_path`, but ignores the case of
+/// static segments, letting "/Users/42" match "/users/:id". Only ASCII letters are compared
+/// ignoring their case. Parameter values keep their case.
+pub fn match_path_ignore_case(pattern: &[Segment], path: &str) -> Option<PathParams> {
+    match_path_with_case(pattern, path, true)
+}
+
+/// Whether the query string of the given path holds all of the given parameters with the given
+/// values, like `tab=archived` in "/inbox?tab=archived&page=2". Used to match the query variants
+/// of routes.
+pub fn query_matches(path: &str, params: &[(&str, &str)]) -> bool {
+    let query = path
+        .split_once('?')
+        .map(|(_, query)| query.split('#').next().unwrap_or_default())
+        .unwrap_or_default();
+    params.iter().all(|(name, value)| {
+        query
+            .split('&')
+            .any(|pair| pair.split_once('=') == Some((name, value)))
+    })
+}
+
+fn match_path_with_case(pattern: &[Segment], path: &str, ignore_case: bool) -> Option<PathParams> {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let segments = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+
+    let mut params = Vec::new();
+    match_segments(pattern, &segments, ignore_case, &mut params).then_some(PathParams(params))
+}
+
+/// Normalizes a messy incoming path before matching it: percent-encoded characters are decoded,
+/// duplicate slashes collapsed and a trailing slash removed, unless `keep_trailing_slash` is set.
+/// An empty path normalizes to "/".
+///
+/// Encoded slashes ("%2F"), question marks ("%3F") and hashes ("%23") are kept encoded, as
+/// decoding them would split the segment containing them or end the path early. Paths not
+/// decoding to valid UTF-8 are only cleaned up, not decoded. Any query string or hash fragment is
+/// kept as it is.
+pub fn normalize_path(path: &str, keep_trailing_slash: bool) -> String {
+    let (path, rest) = path.split_at(path.find(['?', '#']).unwrap_or(path.len()));
+    let decoded = percent_decode(path);
+    let mut normalized = String::with_capacity(decoded.len() + rest.len() + 1);
+    for segment in decoded.split('/').filter(|s| !s.is_empty()) {
+        normalized.push('/');
+        normalized.push_str(segment);
+    }
+    if normalized.is_empty() || (keep_trailing_slash && decoded.ends_with('/')) {
+        normalized.push('/');
+    }
+    normalized.push_str(rest);
+    normalized
+}
+
+fn percent_decode(path: &str) -> Cow<'_, str> {
+    if !path.contains('%') {
+        return path.into();
+    }
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = match (bytes[i], bytes.get(i + 1..i + 3)) {
+            (b'%', Some(hex)) if hex.iter().all(u8::is_ascii_hexdigit) => {
+                core::str::from_utf8(hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .filter(|byte| !matches!(byte, b'/' | b'?' | b'#'))
+            }
+            _ => None,
+        };
+        match byte {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).map_or(path.into(), Into::into)
+}
+
+fn match_segments(
+    pattern: &[Segment],
+    segments: &[&str],
+    ignore_case: bool,
+    params: &mut Vec<(&'static str, String)>,
+) -> bool {
+    let Some((first, rest)) = pattern.split_first() else {
+        return segments.is_empty();
+    };
+    match first {
+        Segment::Static(s) => {
+            let matches = match segments.first() {
+                Some(segment) if ignore_case => segment.eq_ignore_ascii_case(s),
+                segment => segment == Some(s),
+            };
+            matches && match_segments(rest, &segments[1..], ignore_case, params)
+        }
+        Segment::Param(name) => match segments.first() {
+            Some(value) => {
+                params.push((name, (*value).to_owned()));
+                match_segments(rest, &segments[1..], ignore_case, params) || {
+                    params.pop();
+                    false
+                }
+            }
+            None => false,
+        },
+        Segment::OptionalParam(name) => {
+            if let Some(value) = segments.first() {
+                params.push((name, (*value).to_owned()));
+                if match_segments(rest, &segments[1..], ignore_case, params) {
+                    return true;
+                }
+                params.pop();
+            }
+            match_segments(rest, segments, ignore_case, params)
+        }
+        Segment::Wildcard(name) => {
+            params.push((name, segments.join("/")));
+            true
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Per-route render metrics, recorded through the `metrics` crate facade by the views of the
+//! generated routes. Install any `metrics` recorder, like a Prometheus exporter, to collect them.
+
+/// The counter incremented whenever a route is matched and its view is rendered.
+pub const ROUTE_MATCHED: &str = "leptos_routes_route_matched_total";
+
+/// The histogram of the time spent building the view of a route, in seconds.
+pub const ROUTE_RENDER_DURATION: &str = "leptos_routes_route_render_duration_seconds";
+
+/// The label carrying the `PATTERN` of the route, like "/users/:id".
+pub const ROUTE_LABEL: &str = "route";
+
+/// Increments the `ROUTE_MATCHED` counter of the route with the given pattern.
+pub fn record_matched(pattern: &'static str) {
+    ::metrics::counter!(ROUTE_MATCHED, ROUTE_LABEL => pattern).increment(1);
+}
+
+/// Records the time until it is dropped in the `ROUTE_RENDER_DURATION` histogram of the route
+/// with the given pattern. Durations are only measured where `std::time::Instant` is available,
+/// which excludes `wasm32-unknown-unknown`.
+#[derive(Debug)]
+pub struct RenderTimer {
+    pattern: &'static str,
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    start: std::time::Instant,
+}
+
+impl RenderTimer {
+    pub fn start(pattern: &'static str) -> Self {
+        Self {
+            pattern,
+            #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+impl Drop for RenderTimer {
+    fn drop(&mut self) {
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        ::metrics::histogram!(ROUTE_RENDER_DURATION, ROUTE_LABEL => self.pattern)
+            .record(self.start.elapsed());
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        let _ = self.pattern;
+    }
+}
@@ -0,0 +1,33 @@
+//! Types used by generated parameter conversions.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// The error returned when parsing a value not being one of the declared `variants(...)` of a
+/// route parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownVariant(pub String);
+
+impl fmt::Display for UnknownVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown variant \"{}\"", self.0)
+    }
+}
+
+impl core::error::Error for UnknownVariant {}
+
+/// Panics at the location of the caller if the given value of a parameter of the given route
+/// cannot be a segment of its path: if it is empty or contains a '/'. Called by `materialize` in
+/// debug builds for all parameters but wildcards.
+#[track_caller]
+pub fn assert_path_value(route: &str, param: &str, value: impl fmt::Display) {
+    let value = value.to_string();
+    if value.is_empty() {
+        panic!("The value of parameter \"{param}\" of route \"{route}\" must not be empty.");
+    }
+    if value.contains('/') {
+        panic!(
+            "The value \"{value}\" of parameter \"{param}\" of route \"{route}\" must not contain a '/'."
+        );
+    }
+}
@@ -0,0 +1,26 @@
+//! When links to routes prefetch them, returned by the generated `prefetch` function.
+
+/// When links to a route prefetch it, declared using `prefetch = "hover"`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prefetch {
+    /// Links never prefetch the route. The mode of routes not declaring (or nested below a route
+    /// declaring) `prefetch`.
+    #[default]
+    None,
+    /// Links prefetch the route when hovered or focused.
+    Hover,
+    /// Links prefetch the route as soon as they become visible.
+    Visible,
+}
+
+impl Prefetch {
+    /// The mode as declared, like "hover", e.g. for a `data-prefetch` attribute read by a
+    /// prefetching script.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Prefetch::None => "none",
+            Prefetch::Hover => "hover",
+            Prefetch::Visible => "visible",
+        }
+    }
+}
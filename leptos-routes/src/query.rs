@@ -0,0 +1,24 @@
+//! Conversions between typed query structs and query strings, used by generated
+//! `materialize_with_query` functions.
+
+/// Appends the query string serialized from `query` to `path`. Nothing is appended when `query`
+/// serializes to an empty query string.
+///
+/// # Panics
+///
+/// Panics if `query` cannot be serialized as a query string, e.g. when not being a struct or map or
+/// holding sequences or nested structs.
+pub fn append_query<Q: serde::Serialize>(path: String, query: &Q) -> String {
+    let query = serde_urlencoded::to_string(query).expect("query to be serializable as a query string");
+    if query.is_empty() {
+        path
+    } else {
+        format!("{path}?{query}")
+    }
+}
+
+/// Parses a query struct from a query string, with or without its leading '?'. Returns `None` if
+/// the query string does not represent a valid `Q`.
+pub fn parse_query<Q: serde::de::DeserializeOwned>(query: &str) -> Option<Q> {
+    serde_urlencoded::from_str(query.strip_prefix('?').unwrap_or(query)).ok()
+}
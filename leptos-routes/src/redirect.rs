@@ -0,0 +1,86 @@
+//! Redirects to typed routes, returned by the generated `redirect` function of every route struct.
+
+use alloc::string::String;
+
+/// The status code of a redirect response.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RedirectStatus {
+    /// 301: The resource moved permanently. Clients may change the method to GET.
+    MovedPermanently,
+    /// 302: The resource is temporarily found elsewhere. Clients may change the method to GET.
+    #[default]
+    Found,
+    /// 303: The result of a form submission is found elsewhere, to be retrieved using GET.
+    SeeOther,
+    /// 307: The resource is temporarily found elsewhere, keeping the method and body.
+    TemporaryRedirect,
+    /// 308: The resource moved permanently, keeping the method and body.
+    PermanentRedirect,
+}
+
+impl RedirectStatus {
+    /// The numeric HTTP status code.
+    pub fn code(&self) -> u16 {
+        match self {
+            RedirectStatus::MovedPermanently => 301,
+            RedirectStatus::Found => 302,
+            RedirectStatus::SeeOther => 303,
+            RedirectStatus::TemporaryRedirect => 307,
+            RedirectStatus::PermanentRedirect => 308,
+        }
+    }
+}
+
+/// A redirect to the materialized path of a route, independent of any server framework. Respond
+/// with its `status()` code and its `location()` as the `Location` header, e.g. by setting both on
+/// `leptos_axum::ResponseOptions`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Redirect {
+    location: String,
+    status: RedirectStatus,
+}
+
+impl Redirect {
+    /// A redirect to the given location using `RedirectStatus::Found`.
+    pub fn new(location: impl Into<String>) -> Self {
+        Self {
+            location: location.into(),
+            status: RedirectStatus::default(),
+        }
+    }
+
+    /// This redirect, using the given status code.
+    pub fn with_status(mut self, status: RedirectStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// This redirect, using `RedirectStatus::MovedPermanently`.
+    pub fn permanent(self) -> Self {
+        self.with_status(RedirectStatus::MovedPermanently)
+    }
+
+    /// This redirect, using `RedirectStatus::TemporaryRedirect`, keeping the method and body.
+    pub fn temporary(self) -> Self {
+        self.with_status(RedirectStatus::TemporaryRedirect)
+    }
+
+    /// The path redirected to, being the value of the `Location` header.
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    pub fn status(&self) -> RedirectStatus {
+        self.status
+    }
+
+    /// The numeric HTTP status code of this redirect.
+    pub fn status_code(&self) -> u16 {
+        self.status.code()
+    }
+
+    /// Consumes this redirect, returning the path redirected to.
+    pub fn into_location(self) -> String {
+        self.location
+    }
+}
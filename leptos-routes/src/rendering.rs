@@ -0,0 +1,34 @@
+//! Rendering paths like the server would, shared by the render and title checks.
+
+use leptos::prelude::{provide_context, Owner};
+use leptos::task::any_spawner::ExecutorError;
+use leptos::task::{any_spawner, CustomExecutor, Executor};
+use leptos_router::location::RequestUrl;
+
+/// Sets an executor never running the futures spawned on it, so that resources render pending.
+/// Fails if an executor was already set.
+///
+/// The executor is process-wide and cannot be unset, so any other async test of the same test
+/// binary spawns its futures on it as well. Only call this in test binaries checking renders.
+pub fn init_pending_executor() -> Result<(), ExecutorError> {
+    Executor::init_custom_executor(PendingExecutor)
+}
+
+/// An executor never running the futures spawned on it.
+struct PendingExecutor;
+
+impl CustomExecutor for PendingExecutor {
+    fn spawn(&self, _fut: any_spawner::PinnedFuture<()>) {}
+    fn spawn_local(&self, _fut: any_spawner::PinnedLocalFuture<()>) {}
+    fn poll_local(&self) {}
+}
+
+/// Runs `render` inside a fresh reactive owner, providing the given path as the request URL the
+/// router matches.
+pub(crate) fn render_at<T>(path: &str, render: impl FnOnce() -> T) -> T {
+    let owner = Owner::new_root(None);
+    owner.with(|| {
+        provide_context::<RequestUrl>(RequestUrl::new(path));
+        render()
+    })
+}
@@ -0,0 +1,132 @@
+//! Checks catching views not rendering deterministically, used by the generated `check_renders`
+//! function.
+//!
+//! Each path is rendered twice like the server would, using a fresh reactive owner each time.
+//! Both renders include the branch markers hydration relies on, so unbalanced markers and a
+//! layout, fallback or view taking a different branch on the second render are reported, together
+//! with the path and the position of the first difference.
+//!
+//! This is not a hydration test: both renders run natively in the same binary, so differences
+//! between the server and a client build of the views (e.g. through `cfg(feature = "hydrate")`)
+//! cannot be observed.
+
+use crate::rendering::render_at;
+use leptos::IntoView;
+use std::fmt;
+
+/// The amount of characters shown around the first difference of two renders.
+const CONTEXT_LEN: usize = 40;
+
+/// A structural difference found while rendering a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderMismatch {
+    /// The path whose render was checked.
+    pub path: String,
+    /// What was found to be wrong, including an excerpt of the rendered HTML.
+    pub reason: String,
+}
+
+impl fmt::Display for RenderMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "render mismatch at \"{}\": {}", self.path, self.reason)
+    }
+}
+
+impl std::error::Error for RenderMismatch {}
+
+/// Checks the renders of all given paths, stopping at the first mismatch.
+///
+/// Paths are rendered synchronously. Views spawning futures, like the resources of routes declaring
+/// a `guard`, require an executor to be set before, which this function does not do. See
+/// `rendering::init_pending_executor`.
+pub fn check_paths<V: IntoView>(
+    paths: &[&str],
+    app: impl Fn() -> V,
+) -> Result<(), RenderMismatch> {
+    paths.iter().try_for_each(|path| check_path(path, &app))
+}
+
+/// Renders `app` twice for the given path, checking that the branch markers of the first render
+/// are balanced and that both renders are identical.
+pub fn check_path<V: IntoView>(path: &str, app: impl Fn() -> V) -> Result<(), RenderMismatch> {
+    let mismatch = |reason: String| RenderMismatch {
+        path: path.to_string(),
+        reason,
+    };
+
+    let first = render(path, &app);
+    check_branch_markers(&first).map_err(mismatch)?;
+
+    let second = render(path, &app);
+    if let Some(at) = first_difference(&first, &second) {
+        return Err(mismatch(format!(
+            "the second render produced `{}` where the first produced `{}`",
+            excerpt(&second, at),
+            excerpt(&first, at),
+        )));
+    }
+    Ok(())
+}
+
+fn render<V: IntoView>(path: &str, app: impl Fn() -> V) -> String {
+    render_at(path, || app().to_html_branching())
+}
+
+/// Checks that every "<!--bo-...-->" branch marker is closed by the matching "<!--bc-...-->"
+/// marker, in nesting order.
+fn check_branch_markers(html: &str) -> Result<(), String> {
+    let mut open = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<!--b") {
+        let at = html.len() - rest.len() + start;
+        let marker = &rest[start + "<!--b".len()..];
+        let Some(end) = marker.find("-->") else {
+            break;
+        };
+        let (kind, id) = marker[..end].split_at(1);
+        match kind {
+            "o" => open.push(id),
+            "c" if open.last() == Some(&id) => {
+                open.pop();
+            }
+            "c" => {
+                return Err(format!(
+                    "the branch closed at `{}` was never opened",
+                    excerpt(html, at)
+                ))
+            }
+            _ => {}
+        }
+        rest = &marker[end..];
+    }
+    match open.last() {
+        Some(id) => Err(format!("the branch \"{}\" was never closed", &id[1..])),
+        None => Ok(()),
+    }
+}
+
+/// The byte offset of the first difference of both strings, if they differ.
+fn first_difference(a: &str, b: &str) -> Option<usize> {
+    let common = a
+        .char_indices()
+        .zip(b.chars())
+        .find(|((_, a), b)| a != b)
+        .map(|((i, _), _)| i);
+    match common {
+        Some(i) => Some(i),
+        None if a.len() != b.len() => Some(a.len().min(b.len())),
+        None => None,
+    }
+}
+
+/// Up to `CONTEXT_LEN` characters of `html` around the given byte offset.
+fn excerpt(html: &str, at: usize) -> String {
+    let at = at.min(html.len());
+    let before = html[..at].chars().rev().take(CONTEXT_LEN / 2).count();
+    let start = html[..at]
+        .char_indices()
+        .rev()
+        .nth(before.saturating_sub(1))
+        .map_or(at, |(i, _)| i);
+    html[start..].chars().take(CONTEXT_LEN).collect()
+}
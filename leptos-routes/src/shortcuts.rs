@@ -0,0 +1,71 @@
+//! Navigating to routes using keyboard shortcuts, used by the generated `use_route_shortcuts` hook.
+
+use leptos::prelude::{window, Effect, StoredValue, WithValue};
+use leptos::reactive::owner::on_cleanup;
+use leptos::wasm_bindgen::closure::Closure;
+use leptos::wasm_bindgen::JsCast;
+use leptos_router::hooks::use_navigate;
+use std::cell::RefCell;
+
+/// Navigates to the path of a shortcut when its space-separated keys, like "g u", are typed in
+/// sequence. Each key is compared to the `key` of the `keydown` events.
+///
+/// Keys typed into inputs, text areas or editable elements, and keys pressed together with Ctrl,
+/// Alt or Meta are ignored. The listener is only installed in the browser and removed when the
+/// calling component is unmounted.
+pub fn use_shortcuts(shortcuts: Vec<(&'static str, String)>) {
+    let navigate = use_navigate();
+    let shortcuts = shortcuts
+        .into_iter()
+        .map(|(keys, path)| (keys.split(' ').map(str::to_owned).collect::<Vec<_>>(), path))
+        .collect::<Vec<_>>();
+    let max_len = shortcuts.iter().map(|(keys, _)| keys.len()).max().unwrap_or(0);
+
+    Effect::new(move |_| {
+        let navigate = navigate.clone();
+        let shortcuts = shortcuts.clone();
+        let typed = RefCell::new(Vec::<String>::new());
+        let keydown = Closure::<dyn Fn(web_sys::Event)>::new(move |ev: web_sys::Event| {
+            let Some(ev) = ev.dyn_ref::<web_sys::KeyboardEvent>() else {
+                return;
+            };
+            if ev.default_prevented() || ev.ctrl_key() || ev.alt_key() || ev.meta_key() {
+                return;
+            }
+            let editing = ev
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+                .is_some_and(|element| {
+                    element.is_content_editable()
+                        || matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+                });
+            if editing {
+                return;
+            }
+
+            let mut typed = typed.borrow_mut();
+            typed.push(ev.key());
+            if typed.len() > max_len {
+                typed.remove(0);
+            }
+            let matched = shortcuts.iter().find(|(keys, _)| typed.ends_with(keys));
+            if let Some((_, path)) = matched {
+                typed.clear();
+                ev.prevent_default();
+                navigate(path, Default::default());
+            }
+        });
+        let _ = window()
+            .add_event_listener_with_callback("keydown", keydown.as_ref().unchecked_ref());
+
+        let listener = StoredValue::new_local(keydown);
+        on_cleanup(move || {
+            listener.with_value(|keydown| {
+                let _ = window().remove_event_listener_with_callback(
+                    "keydown",
+                    keydown.as_ref().unchecked_ref(),
+                );
+            });
+        });
+    });
+}
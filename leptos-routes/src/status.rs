@@ -0,0 +1,4 @@
+//! HTTP status codes of routes, returned by the generated `status_for` function.
+
+/// The status code type of the `http` crate, as used by `leptos_axum::ResponseOptions`.
+pub use http::StatusCode;
@@ -0,0 +1,75 @@
+//! Checks that the views of all routes apply a document title, used by the generated
+//! `check_titles` function.
+//!
+//! Each path is rendered like the server would, using a fresh reactive owner, before reading the
+//! applied title using a function given the rendered HTML. It runs inside the same owner, so it
+//! can also read a title recorded in context, like one set by a meta tags integration.
+
+use crate::rendering::render_at;
+use leptos::IntoView;
+use std::fmt;
+
+/// A path whose render did not apply the expected title.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TitleMismatch {
+    /// The path whose render was checked.
+    pub path: String,
+    /// What was found to be wrong.
+    pub reason: String,
+}
+
+impl fmt::Display for TitleMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "title mismatch at \"{}\": {}", self.path, self.reason)
+    }
+}
+
+impl std::error::Error for TitleMismatch {}
+
+/// Checks the titles applied by the renders of all given paths, stopping at the first mismatch.
+/// Each path is given together with the title declared on its route, which the applied title
+/// must contain, like "Users" in "Users | My App". Paths without a declared title must apply any
+/// non-empty title.
+///
+/// Paths are rendered synchronously. Views spawning futures require an executor to be set before,
+/// which this function does not do. See `rendering::init_pending_executor`.
+pub fn check_paths<V: IntoView>(
+    paths: &[(&str, Option<&str>)],
+    app: impl Fn() -> V,
+    applied_title: impl Fn(&str) -> Option<String>,
+) -> Result<(), TitleMismatch> {
+    paths
+        .iter()
+        .try_for_each(|(path, declared)| check_path(path, *declared, &app, &applied_title))
+}
+
+/// Renders `app` for the given path, checking the title read from the render by `applied_title`.
+pub fn check_path<V: IntoView>(
+    path: &str,
+    declared: Option<&str>,
+    app: impl Fn() -> V,
+    applied_title: impl Fn(&str) -> Option<String>,
+) -> Result<(), TitleMismatch> {
+    let mismatch = |reason: String| TitleMismatch {
+        path: path.to_string(),
+        reason,
+    };
+
+    let title = render_at(path, || applied_title(&app().to_html()));
+    match (title.filter(|title| !title.trim().is_empty()), declared) {
+        (None, _) => Err(mismatch("no title was applied".to_string())),
+        (Some(title), Some(declared)) if !title.contains(declared) => Err(mismatch(format!(
+            "the applied title \"{title}\" does not contain the declared title \"{declared}\""
+        ))),
+        (Some(_), _) => Ok(()),
+    }
+}
+
+/// The text of the first `<title>` element of the given HTML, like one rendered by a view.
+pub fn title_element(html: &str) -> Option<String> {
+    let start = html.find("<title")?;
+    let content = &html[start..];
+    let content = &content[content.find('>')? + 1..];
+    let end = content.find("</title>")?;
+    Some(content[..end].to_string())
+}